@@ -2,7 +2,7 @@ extern crate kitsu_io;
 extern crate reqwest;
 
 use kitsu_io::KitsuReqwestRequester;
-use reqwest::Client;
+use reqwest::blocking::Client;
 use std::io::{self, Write};
 
 fn main() {
@@ -1,18 +1,10 @@
-extern crate futures;
-extern crate hyper;
-extern crate hyper_tls;
-extern crate kitsu_io;
-extern crate tokio_core;
-
-use futures::Future;
-use futures::stream::Stream;
-use hyper::Client;
+use hyper::{Body, Client};
 use hyper_tls::HttpsConnector;
 use kitsu_io::KitsuHyperRequester;
 use std::io::{self, Write};
-use tokio_core::reactor::Core;
 
-fn main() {
+#[tokio::main]
+async fn main() {
     // Read an anime name to search for from the users input.
     let mut input = String::new();
     print!("Enter an anime name to search for:\n>");
@@ -20,25 +12,17 @@ fn main() {
     io::stdin().read_line(&mut input).expect("Error reading input");
     let input_trimmed = input.trim();
 
-    // Create the core and client which will be uesd to search.
-    let mut core = Core::new().expect("Error creating reactor core");
-
-    let connector = HttpsConnector::new(1, &core.handle())
-        .expect("Error creating connector");
-    let client = Client::configure()
-        .connector(connector)
-        .build(&core.handle());
+    // Create the client which will be used to search.
+    let client = Client::builder().build::<_, Body>(HttpsConnector::new());
 
     // Search for the anime and return the response.
-    let runner = client.search_anime(|f| f.filter("text", input_trimmed))
+    let res = client.search_anime(|f| f.filter("text", input_trimmed))
         .expect("Error making request")
-        .and_then(|res| {
-            res.body().for_each(|chunk| {
-                io::stdout().write_all(&chunk).map_err(From::from)
-            })
-        }).map(|_| {
-            println!("\n\nDone")
-        });
+        .await
+        .expect("Error getting response");
+    let body = hyper::body::to_bytes(res.into_body()).await
+        .expect("Error reading body");
 
-    core.run(runner).expect("Error running core");
+    io::stdout().write_all(&body).expect("Error writing body");
+    println!("\n\nDone");
 }
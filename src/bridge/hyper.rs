@@ -6,12 +6,27 @@
 //!
 //! [`KitsuRequester`]: trait.KitsuRequester.html
 
-use hyper::client::{Client as HyperClient, FutureResponse, HttpConnector};
-use hyper::{Body, Method, Request, Uri};
+use hyper::body;
+use hyper::client::{Client as HyperClient, HttpConnector, ResponseFuture};
+use hyper::header::{HeaderValue, ACCEPT, RETRY_AFTER};
+use hyper::{Body, Request, StatusCode, Uri};
 use hyper_tls::HttpsConnector;
+use serde::de::DeserializeOwned;
+use serde_json;
+use std::future::Future;
+use std::pin::Pin;
 use std::str::FromStr;
-use ::builder::Search;
-use ::{API_URL, Result};
+use std::time::Duration;
+use crate::builder::Search;
+use crate::model::{Anime, Category, Chapter, Episode, Manga, Response, StreamingLink, User};
+use crate::{API_URL, Error, Result};
+use super::{api_errors, retry_after, JSON_API};
+
+/// A future which resolves to a response body deserialized into a model, as
+/// returned by the `_parsed` methods of [`KitsuRequester`].
+///
+/// [`KitsuRequester`]: trait.KitsuRequester.html
+pub type FutureParsed<T> = Pin<Box<dyn Future<Output = Result<T>> + Send>>;
 
 /// Trait which defines the methods necessary to interact with the service.
 ///
@@ -32,319 +47,1023 @@ pub trait KitsuRequester {
     ///
     /// Get an anime with the id of 1:
     ///
-    /// ```rust,ignore
-    /// extern crate hyper;
-    /// extern crate hyper_tls;
-    /// extern crate kitsu_io;
-    /// extern crate tokio_core;
+    /// ```rust,no_run
+    /// use hyper::{Body, Client};
+    /// use hyper_tls::HttpsConnector;
+    /// use kitsu_io::KitsuHyperRequester;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let client = Client::builder().build::<_, Body>(HttpsConnector::new());
+    ///
+    ///     let anime_id = 1;
+    ///
+    ///     let res = client.get_anime(anime_id)
+    ///         .expect("Error making request")
+    ///         .await
+    ///         .expect("Error getting response");
+    ///     let body = hyper::body::to_bytes(res.into_body()).await
+    ///         .expect("Error reading body");
+    ///
+    ///     println!("{}", String::from_utf8_lossy(&body));
+    /// }
+    /// ```
+    fn get_anime(&self, id: u64) -> Result<ResponseFuture>;
+
+    /// Gets a manga using its id.
     ///
+    /// # Examples
+    ///
+    /// Get a manga with the id of 1:
+    ///
+    /// ```rust,no_run
+    /// use hyper::{Body, Client};
     /// use hyper_tls::HttpsConnector;
     /// use kitsu_io::KitsuHyperRequester;
-    /// use hyper::Client;
-    /// use std::env;
-    /// use tokio_core::reactor::Core;
     ///
-    /// let mut core = Core::new()?;
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let client = Client::builder().build::<_, Body>(HttpsConnector::new());
+    ///
+    ///     let manga_id = 1;
+    ///
+    ///     let res = client.get_manga(manga_id)
+    ///         .expect("Error making request")
+    ///         .await
+    ///         .expect("Error getting response");
+    ///     let body = hyper::body::to_bytes(res.into_body()).await
+    ///         .expect("Error reading body");
+    ///
+    ///     println!("{}", String::from_utf8_lossy(&body));
+    /// }
+    /// ```
+    fn get_manga(&self, id: u64) -> Result<ResponseFuture>;
+
+    /// Gets a user using their id.
+    ///
+    /// # Examples
+    ///
+    /// Get a user with the id of 1:
+    ///
+    /// ```rust,no_run
+    /// use hyper::{Body, Client};
+    /// use hyper_tls::HttpsConnector;
+    /// use kitsu_io::KitsuHyperRequester;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let client = Client::builder().build::<_, Body>(HttpsConnector::new());
+    ///
+    ///     let user_id = 1;
+    ///
+    ///     let res = client.get_user(user_id)
+    ///         .expect("Error making request")
+    ///         .await
+    ///         .expect("Error getting response");
+    ///     let body = hyper::body::to_bytes(res.into_body()).await
+    ///         .expect("Error reading body");
+    ///
+    ///     println!("{}", String::from_utf8_lossy(&body));
+    /// }
+    /// ```
+    fn get_user(&self, id: u64) -> Result<ResponseFuture>;
+
+    /// Searches for an anime using the passed [Search] builder.
+    ///
+    /// # Examples
+    ///
+    /// Search for an anime with the name "Beyond the Boundary":
+    ///
+    /// ```rust,no_run
+    /// use hyper::{Body, Client};
+    /// use hyper_tls::HttpsConnector;
+    /// use kitsu_io::KitsuHyperRequester;
     ///
-    /// let connector = HttpsConnector::new(1, &core.handle())?;
-    /// let client = Client::configure()
-    ///     .connector(connector)
-    ///     .build(&core.handle());
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let client = Client::builder().build::<_, Body>(HttpsConnector::new());
     ///
-    /// let anime_id = 1;
+    ///     let anime_name = "Beyond the Boundary";
     ///
-    /// let runner = client.get_anime(anime_id)?
-    ///     .and_then(|res| {
-    ///         res.body().for_each(|chunk| {
-    ///             io::stdout().write_all(&chunk).map_err(From::from)
-    ///         })
-    ///     }).map(|_| {
-    ///         println!("\n\nDone.");
-    ///     });
+    ///     let res = client.search_anime(|f| f.filter("text", anime_name))
+    ///         .expect("Error making request")
+    ///         .await
+    ///         .expect("Error getting response");
+    ///     let body = hyper::body::to_bytes(res.into_body()).await
+    ///         .expect("Error reading body");
     ///
-    /// core.run(runner)?;
+    ///     println!("{}", String::from_utf8_lossy(&body));
+    /// }
     /// ```
+    fn search_anime<F: FnOnce(Search) -> Search>(&self, f: F) ->
+        Result<ResponseFuture>;
+
+    /// Searches for a manga using the passed [Search] builder.
+    ///
+    /// # Examples
+    ///
+    /// Search for a manga with the name "Orange":
+    ///
+    /// ```rust,no_run
+    /// use hyper::{Body, Client};
+    /// use hyper_tls::HttpsConnector;
+    /// use kitsu_io::KitsuHyperRequester;
     ///
-    // Note: This doc example can not be tested due to the reliance on
-    // tokio_core. Instead, this is taken from example `02_hyper` and should
-    // roughly match it to ensure accuracy.
-    fn get_anime(&self, id: u64) -> Result<FutureResponse>;
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let client = Client::builder().build::<_, Body>(HttpsConnector::new());
+    ///
+    ///     let manga_name = "Orange";
+    ///
+    ///     let res = client.search_manga(|f| f.filter("text", manga_name))
+    ///         .expect("Error making request")
+    ///         .await
+    ///         .expect("Error getting response");
+    ///     let body = hyper::body::to_bytes(res.into_body()).await
+    ///         .expect("Error reading body");
+    ///
+    ///     println!("{}", String::from_utf8_lossy(&body));
+    /// }
+    /// ```
+    fn search_manga<F: FnOnce(Search) -> Search>(&self, f: F) ->
+        Result<ResponseFuture>;
 
-    /// Gets a manga using its id.
+    /// Searches for a user using the passed [`Search`] builder.
     ///
     /// # Examples
     ///
-    /// Get a manga with the id of 1:
+    /// Search for a user with the name "Bob":
     ///
-    /// ```rust,ignore
-    /// extern crate hyper;
-    /// extern crate hyper_tls;
-    /// extern crate kitsu_io;
-    /// extern crate tokio_core;
+    /// ```rust,no_run
+    /// use hyper::{Body, Client};
+    /// use hyper_tls::HttpsConnector;
+    /// use kitsu_io::KitsuHyperRequester;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let client = Client::builder().build::<_, Body>(HttpsConnector::new());
+    ///
+    ///     let user_name = "Bob";
+    ///
+    ///     let res = client.search_users(|f| f.filter("name", user_name))
+    ///         .expect("Error making request")
+    ///         .await
+    ///         .expect("Error getting response");
+    ///     let body = hyper::body::to_bytes(res.into_body()).await
+    ///         .expect("Error reading body");
+    ///
+    ///     println!("{}", String::from_utf8_lossy(&body));
+    /// }
+    /// ```
+    ///
+    /// [`Search`]: ../builder/struct.Search.html
+    fn search_users<F: FnOnce(Search) -> Search>(&self, f: F) ->
+        Result<ResponseFuture>;
+
+    /// Gets the episodes of an anime using the anime's id.
     ///
+    /// # Examples
+    ///
+    /// Get the episodes of an anime with the id of 1:
+    ///
+    /// ```rust,no_run
+    /// use hyper::{Body, Client};
     /// use hyper_tls::HttpsConnector;
     /// use kitsu_io::KitsuHyperRequester;
-    /// use hyper::Client;
-    /// use std::env;
-    /// use tokio_core::reactor::Core;
     ///
-    /// let mut core = Core::new()?;
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let client = Client::builder().build::<_, Body>(HttpsConnector::new());
+    ///
+    ///     let anime_id = 1;
+    ///
+    ///     let res = client.get_anime_episodes(anime_id)
+    ///         .expect("Error making request")
+    ///         .await
+    ///         .expect("Error getting response");
+    ///     let body = hyper::body::to_bytes(res.into_body()).await
+    ///         .expect("Error reading body");
+    ///
+    ///     println!("{}", String::from_utf8_lossy(&body));
+    /// }
+    /// ```
+    fn get_anime_episodes(&self, anime_id: u64) -> Result<ResponseFuture>;
+
+    /// Gets the chapters of a manga using the manga's id.
+    ///
+    /// # Examples
+    ///
+    /// Get the chapters of a manga with the id of 1:
+    ///
+    /// ```rust,no_run
+    /// use hyper::{Body, Client};
+    /// use hyper_tls::HttpsConnector;
+    /// use kitsu_io::KitsuHyperRequester;
     ///
-    /// let connector = HttpsConnector::new(1, &core.handle())?;
-    /// let client = Client::configure()
-    ///     .connector(connector)
-    ///     .build(&core.handle());
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let client = Client::builder().build::<_, Body>(HttpsConnector::new());
     ///
-    /// let manga_id = 1;
+    ///     let manga_id = 1;
     ///
-    /// let runner = client.get_manga(manga_id)?
-    ///     .and_then(|res| {
-    ///         res.body().for_each(|chunk| {
-    ///             io::stdout().write_all(&chunk).map_err(From::from)
-    ///         })
-    ///     }).map(|_| {
-    ///         println!("\n\nDone.");
-    ///     });
+    ///     let res = client.get_manga_chapters(manga_id)
+    ///         .expect("Error making request")
+    ///         .await
+    ///         .expect("Error getting response");
+    ///     let body = hyper::body::to_bytes(res.into_body()).await
+    ///         .expect("Error reading body");
     ///
-    /// core.run(runner)?;
+    ///     println!("{}", String::from_utf8_lossy(&body));
+    /// }
     /// ```
+    fn get_manga_chapters(&self, manga_id: u64) -> Result<ResponseFuture>;
+
+    /// Gets the links to where an anime can be legally streamed using the
+    /// anime's id.
+    ///
+    /// # Examples
+    ///
+    /// Get the streaming links of an anime with the id of 1:
+    ///
+    /// ```rust,no_run
+    /// use hyper::{Body, Client};
+    /// use hyper_tls::HttpsConnector;
+    /// use kitsu_io::KitsuHyperRequester;
     ///
-    // Note: This doc example can not be tested due to the reliance on
-    // tokio_core. Instead, this is taken from example `02_hyper` and should
-    // roughly match it to ensure accuracy.
-    fn get_manga(&self, id: u64) -> Result<FutureResponse>;
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let client = Client::builder().build::<_, Body>(HttpsConnector::new());
+    ///
+    ///     let anime_id = 1;
+    ///
+    ///     let res = client.get_anime_streaming_links(anime_id)
+    ///         .expect("Error making request")
+    ///         .await
+    ///         .expect("Error getting response");
+    ///     let body = hyper::body::to_bytes(res.into_body()).await
+    ///         .expect("Error reading body");
+    ///
+    ///     println!("{}", String::from_utf8_lossy(&body));
+    /// }
+    /// ```
+    fn get_anime_streaming_links(&self, anime_id: u64) -> Result<ResponseFuture>;
 
-    /// Gets a user using their id.
+    /// Gets the categories of an anime using the anime's id.
     ///
     /// # Examples
     ///
-    /// Get a user with the id of 1:
+    /// Get the categories of an anime with the id of 1:
+    ///
+    /// ```rust,no_run
+    /// use hyper::{Body, Client};
+    /// use hyper_tls::HttpsConnector;
+    /// use kitsu_io::KitsuHyperRequester;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let client = Client::builder().build::<_, Body>(HttpsConnector::new());
     ///
-    /// ```rust,ignore
-    /// extern crate hyper;
-    /// extern crate hyper_tls;
-    /// extern crate kitsu_io;
-    /// extern crate tokio_core;
+    ///     let anime_id = 1;
     ///
+    ///     let res = client.get_anime_categories(anime_id)
+    ///         .expect("Error making request")
+    ///         .await
+    ///         .expect("Error getting response");
+    ///     let body = hyper::body::to_bytes(res.into_body()).await
+    ///         .expect("Error reading body");
+    ///
+    ///     println!("{}", String::from_utf8_lossy(&body));
+    /// }
+    /// ```
+    fn get_anime_categories(&self, anime_id: u64) -> Result<ResponseFuture>;
+
+    /// Gets a list of categories using the search builder.
+    ///
+    /// # Examples
+    ///
+    /// Get the top-level categories, sorted by title:
+    ///
+    /// ```rust,no_run
+    /// use hyper::{Body, Client};
     /// use hyper_tls::HttpsConnector;
     /// use kitsu_io::KitsuHyperRequester;
-    /// use hyper::Client;
-    /// use std::env;
-    /// use tokio_core::reactor::Core;
     ///
-    /// let mut core = Core::new()?;
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let client = Client::builder().build::<_, Body>(HttpsConnector::new());
+    ///
+    ///     let res = client.get_categories(|f| f.filter("parent_id", "_none").sort("title"))
+    ///         .expect("Error making request")
+    ///         .await
+    ///         .expect("Error getting response");
+    ///     let body = hyper::body::to_bytes(res.into_body()).await
+    ///         .expect("Error reading body");
+    ///
+    ///     println!("{}", String::from_utf8_lossy(&body));
+    /// }
+    /// ```
+    fn get_categories<F: FnOnce(Search) -> Search>(&self, f: F) ->
+        Result<ResponseFuture>;
+
+    /// Gets the anime which are currently trending on Kitsu.
+    ///
+    /// # Examples
     ///
-    /// let connector = HttpsConnector::new(1, &core.handle())?;
-    /// let client = Client::configure()
-    ///     .connector(connector)
-    ///     .build(&core.handle());
+    /// Get the trending anime:
+    ///
+    /// ```rust,no_run
+    /// use hyper::{Body, Client};
+    /// use hyper_tls::HttpsConnector;
+    /// use kitsu_io::KitsuHyperRequester;
     ///
-    /// let user_id = 1;
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let client = Client::builder().build::<_, Body>(HttpsConnector::new());
     ///
-    /// let runner = client.get_user(user_id)?
-    ///     .and_then(|res| {
-    ///         res.body().for_each(|chunk| {
-    ///             io::stdout().write_all(&chunk).map_err(From::from)
-    ///         })
-    ///     }).map(|_| {
-    ///         println!("\n\nDone.");
-    ///     });
+    ///     let res = client.get_trending_anime()
+    ///         .expect("Error making request")
+    ///         .await
+    ///         .expect("Error getting response");
+    ///     let body = hyper::body::to_bytes(res.into_body()).await
+    ///         .expect("Error reading body");
     ///
-    /// core.run(runner)?;
+    ///     println!("{}", String::from_utf8_lossy(&body));
+    /// }
     /// ```
+    fn get_trending_anime(&self) -> Result<ResponseFuture>;
+
+    /// Gets the manga which are currently trending on Kitsu.
+    ///
+    /// # Examples
+    ///
+    /// Get the trending manga:
     ///
-    // Note: This doc example can not be tested due to the reliance on
-    // tokio_core. Instead, this is taken from example `02_hyper` and should
-    // roughly match it to ensure accuracy.
-    fn get_user(&self, id: u64) -> Result<FutureResponse>;
+    /// ```rust,no_run
+    /// use hyper::{Body, Client};
+    /// use hyper_tls::HttpsConnector;
+    /// use kitsu_io::KitsuHyperRequester;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let client = Client::builder().build::<_, Body>(HttpsConnector::new());
+    ///
+    ///     let res = client.get_trending_manga()
+    ///         .expect("Error making request")
+    ///         .await
+    ///         .expect("Error getting response");
+    ///     let body = hyper::body::to_bytes(res.into_body()).await
+    ///         .expect("Error reading body");
+    ///
+    ///     println!("{}", String::from_utf8_lossy(&body));
+    /// }
+    /// ```
+    fn get_trending_manga(&self) -> Result<ResponseFuture>;
 
-    /// Searches for an anime using the passed [Search] builder.
+    /// Gets an anime using its id, deserializing the response body.
+    ///
+    /// This is the same as [`get_anime`], but collects the body of the response
+    /// and deserializes it into the model, rather than resolving to the raw
+    /// response. A non-OK response resolves to an error without deserializing
+    /// the body, such as [`Error::RateLimited`] when rate limited.
     ///
     /// # Examples
     ///
-    /// Search for an anime with the name "Beyond the Boundary":
+    /// ```rust,no_run
+    /// use hyper::{Body, Client};
+    /// use hyper_tls::HttpsConnector;
+    /// use kitsu_io::KitsuHyperRequester;
     ///
-    /// ```rust,ignore
-    /// extern crate hyper;
-    /// extern crate hyper_tls;
-    /// extern crate kitsu_io;
-    /// extern crate tokio_core;
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let client = Client::builder().build::<_, Body>(HttpsConnector::new());
     ///
+    ///     let anime_id = 1;
+    ///
+    ///     let anime = client.get_anime_parsed(anime_id).await
+    ///         .expect("Error getting response");
+    ///
+    ///     println!("{}", anime.data.attributes.canonical_title);
+    /// }
+    /// ```
+    ///
+    /// [`Error::RateLimited`]: ../../enum.Error.html#variant.RateLimited
+    /// [`get_anime`]: #tymethod.get_anime
+    fn get_anime_parsed(&self, id: u64) -> FutureParsed<Response<Anime>>;
+
+    /// Gets a manga using its id, deserializing the response body.
+    ///
+    /// This is the same as [`get_manga`], but collects the body of the response
+    /// and deserializes it into the model, rather than resolving to the raw
+    /// response. A non-OK response resolves to an error without deserializing
+    /// the body, such as [`Error::RateLimited`] when rate limited.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use hyper::{Body, Client};
     /// use hyper_tls::HttpsConnector;
     /// use kitsu_io::KitsuHyperRequester;
-    /// use hyper::Client;
-    /// use std::env;
-    /// use tokio_core::reactor::Core;
     ///
-    /// let mut core = Core::new()?;
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let client = Client::builder().build::<_, Body>(HttpsConnector::new());
     ///
-    /// let connector = HttpsConnector::new(1, &core.handle())?;
-    /// let client = Client::configure()
-    ///     .connector(connector)
-    ///     .build(&core.handle());
+    ///     let manga_id = 1;
     ///
-    /// let anime_name = "Beyond the Boundary";
+    ///     let manga = client.get_manga_parsed(manga_id).await
+    ///         .expect("Error getting response");
     ///
-    /// let runner = client.search_anime(|f| f.filter("text", anime_name))?
-    ///     .and_then(|res| {
-    ///         res.body().for_each(|chunk| {
-    ///             io::stdout().write_all(&chunk).map_err(From::from)
-    ///         })
-    ///     }).map(|_| {
-    ///         println!("\n\nDone.");
-    ///     });
+    ///     println!("{}", manga.data.attributes.canonical_title);
+    /// }
+    /// ```
     ///
-    /// core.run(runner)?;
+    /// [`Error::RateLimited`]: ../../enum.Error.html#variant.RateLimited
+    /// [`get_manga`]: #tymethod.get_manga
+    fn get_manga_parsed(&self, id: u64) -> FutureParsed<Response<Manga>>;
+
+    /// Gets a user using their id, deserializing the response body.
+    ///
+    /// This is the same as [`get_user`], but collects the body of the response
+    /// and deserializes it into the model, rather than resolving to the raw
+    /// response. A non-OK response resolves to an error without deserializing
+    /// the body, such as [`Error::RateLimited`] when rate limited.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use hyper::{Body, Client};
+    /// use hyper_tls::HttpsConnector;
+    /// use kitsu_io::KitsuHyperRequester;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let client = Client::builder().build::<_, Body>(HttpsConnector::new());
+    ///
+    ///     let user_id = 1;
+    ///
+    ///     let user = client.get_user_parsed(user_id).await
+    ///         .expect("Error getting response");
+    ///
+    ///     println!("{}", user.data.attributes.name);
+    /// }
     /// ```
     ///
-    // Note: This doc example can not be tested due to the reliance on
-    // tokio_core. Instead, this is taken from example `02_hyper` and should
-    // roughly match it to ensure accuracy.
-    fn search_anime<F: FnOnce(Search) -> Search>(&self, f: F) ->
-        Result<FutureResponse>;
+    /// [`Error::RateLimited`]: ../../enum.Error.html#variant.RateLimited
+    /// [`get_user`]: #tymethod.get_user
+    fn get_user_parsed(&self, id: u64) -> FutureParsed<Response<User>>;
 
-    /// Searches for a manga using the passed [Search] builder.
+    /// Searches for an anime using the passed [`Search`] builder, deserializing
+    /// the response body.
+    ///
+    /// This is the same as [`search_anime`], but collects the body of the
+    /// response and deserializes it into the model, rather than resolving to
+    /// the raw response. A non-OK response resolves to an error without
+    /// deserializing the body, such as [`Error::RateLimited`] when rate
+    /// limited.
     ///
     /// # Examples
     ///
-    /// Search for a manga with the name "Orange":
+    /// ```rust,no_run
+    /// use hyper::{Body, Client};
+    /// use hyper_tls::HttpsConnector;
+    /// use kitsu_io::KitsuHyperRequester;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let client = Client::builder().build::<_, Body>(HttpsConnector::new());
+    ///
+    ///     let anime_name = "Your Lie in April";
+    ///
+    ///     let anime = client.search_anime_parsed(|f| f.filter("text", anime_name)).await
+    ///         .expect("Error getting response");
     ///
-    /// ```rust,ignore
-    /// extern crate hyper;
-    /// extern crate hyper_tls;
-    /// extern crate kitsu_io;
-    /// extern crate tokio_core;
+    ///     println!("Found {} anime", anime.data.len());
+    /// }
+    /// ```
+    ///
+    /// [`Error::RateLimited`]: ../../enum.Error.html#variant.RateLimited
+    /// [`search_anime`]: #tymethod.search_anime
+    /// [`Search`]: ../../builder/struct.Search.html
+    fn search_anime_parsed<F: FnOnce(Search) -> Search>(&self, f: F)
+        -> FutureParsed<Response<Vec<Anime>>>;
+
+    /// Searches for a manga using the passed [`Search`] builder, deserializing
+    /// the response body.
+    ///
+    /// This is the same as [`search_manga`], but collects the body of the
+    /// response and deserializes it into the model, rather than resolving to
+    /// the raw response. A non-OK response resolves to an error without
+    /// deserializing the body, such as [`Error::RateLimited`] when rate
+    /// limited.
+    ///
+    /// # Examples
     ///
+    /// ```rust,no_run
+    /// use hyper::{Body, Client};
     /// use hyper_tls::HttpsConnector;
     /// use kitsu_io::KitsuHyperRequester;
-    /// use hyper::Client;
-    /// use std::env;
-    /// use tokio_core::reactor::Core;
     ///
-    /// let mut core = Core::new()?;
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let client = Client::builder().build::<_, Body>(HttpsConnector::new());
     ///
-    /// let connector = HttpsConnector::new(1, &core.handle())?;
-    /// let client = Client::configure()
-    ///     .connector(connector)
-    ///     .build(&core.handle());
+    ///     let manga_name = "Orange";
     ///
-    /// let manga_name = "Orange";
+    ///     let manga = client.search_manga_parsed(|f| f.filter("text", manga_name)).await
+    ///         .expect("Error getting response");
     ///
-    /// let runner = client.search_manga(|f| f.filter("text", manga_name))?
-    ///     .and_then(|res| {
-    ///         res.body().for_each(|chunk| {
-    ///             io::stdout().write_all(&chunk).map_err(From::from)
-    ///         })
-    ///     }).map(|_| {
-    ///         println!("\n\nDone.");
-    ///     });
+    ///     println!("Found {} manga", manga.data.len());
+    /// }
+    /// ```
+    ///
+    /// [`Error::RateLimited`]: ../../enum.Error.html#variant.RateLimited
+    /// [`search_manga`]: #tymethod.search_manga
+    /// [`Search`]: ../../builder/struct.Search.html
+    fn search_manga_parsed<F: FnOnce(Search) -> Search>(&self, f: F)
+        -> FutureParsed<Response<Vec<Manga>>>;
+
+    /// Searches for a user using the passed [`Search`] builder, deserializing
+    /// the response body.
     ///
-    /// core.run(runner)?;
+    /// This is the same as [`search_users`], but collects the body of the
+    /// response and deserializes it into the model, rather than resolving to
+    /// the raw response. A non-OK response resolves to an error without
+    /// deserializing the body, such as [`Error::RateLimited`] when rate
+    /// limited.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use hyper::{Body, Client};
+    /// use hyper_tls::HttpsConnector;
+    /// use kitsu_io::KitsuHyperRequester;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let client = Client::builder().build::<_, Body>(HttpsConnector::new());
+    ///
+    ///     let user_name = "Bob";
+    ///
+    ///     let users = client.search_users_parsed(|f| f.filter("name", user_name)).await
+    ///         .expect("Error getting response");
+    ///
+    ///     println!("Found {} users", users.data.len());
+    /// }
     /// ```
     ///
-    // Note: This doc example can not be tested due to the reliance on
-    // tokio_core. Instead, this is taken from example `02_hyper` and should
-    // roughly match it to ensure accuracy.
-    fn search_manga<F: FnOnce(Search) -> Search>(&self, f: F) ->
-        Result<FutureResponse>;
+    /// [`Error::RateLimited`]: ../../enum.Error.html#variant.RateLimited
+    /// [`search_users`]: #tymethod.search_users
+    /// [`Search`]: ../../builder/struct.Search.html
+    fn search_users_parsed<F: FnOnce(Search) -> Search>(&self, f: F)
+        -> FutureParsed<Response<Vec<User>>>;
 
-    /// Searches for a user using the passed [`Search`] builder.
+    /// Gets the episodes of an anime using the anime's id, deserializing the
+    /// response body.
+    ///
+    /// This is the same as [`get_anime_episodes`], but collects the body of the
+    /// response and deserializes it into the model, rather than resolving to
+    /// the raw response. A non-OK response resolves to an error without
+    /// deserializing the body, such as [`Error::RateLimited`] when rate
+    /// limited.
     ///
     /// # Examples
     ///
-    /// Search for a user with the name "Bob":
+    /// ```rust,no_run
+    /// use hyper::{Body, Client};
+    /// use hyper_tls::HttpsConnector;
+    /// use kitsu_io::KitsuHyperRequester;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let client = Client::builder().build::<_, Body>(HttpsConnector::new());
+    ///
+    ///     let anime_id = 1;
+    ///
+    ///     let episodes = client.get_anime_episodes_parsed(anime_id).await
+    ///         .expect("Error getting response");
+    ///
+    ///     println!("Found {} episodes", episodes.data.len());
+    /// }
+    /// ```
+    ///
+    /// [`Error::RateLimited`]: ../../enum.Error.html#variant.RateLimited
+    /// [`get_anime_episodes`]: #tymethod.get_anime_episodes
+    fn get_anime_episodes_parsed(&self, anime_id: u64) -> FutureParsed<Response<Vec<Episode>>>;
+
+    /// Gets the chapters of a manga using the manga's id, deserializing the
+    /// response body.
+    ///
+    /// This is the same as [`get_manga_chapters`], but collects the body of the
+    /// response and deserializes it into the model, rather than resolving to
+    /// the raw response. A non-OK response resolves to an error without
+    /// deserializing the body, such as [`Error::RateLimited`] when rate
+    /// limited.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use hyper::{Body, Client};
+    /// use hyper_tls::HttpsConnector;
+    /// use kitsu_io::KitsuHyperRequester;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let client = Client::builder().build::<_, Body>(HttpsConnector::new());
+    ///
+    ///     let manga_id = 1;
+    ///
+    ///     let chapters = client.get_manga_chapters_parsed(manga_id).await
+    ///         .expect("Error getting response");
+    ///
+    ///     println!("Found {} chapters", chapters.data.len());
+    /// }
+    /// ```
+    ///
+    /// [`Error::RateLimited`]: ../../enum.Error.html#variant.RateLimited
+    /// [`get_manga_chapters`]: #tymethod.get_manga_chapters
+    fn get_manga_chapters_parsed(&self, manga_id: u64) -> FutureParsed<Response<Vec<Chapter>>>;
+
+    /// Gets where an anime can be legally streamed using the anime's id,
+    /// deserializing the response body.
     ///
-    /// ```rust,ignore
-    /// extern crate hyper;
-    /// extern crate hyper_tls;
-    /// extern crate kitsu_io;
-    /// extern crate tokio_core;
+    /// This is the same as [`get_anime_streaming_links`], but collects the body
+    /// of the response and deserializes it into the model, rather than
+    /// resolving to the raw response. A non-OK response resolves to an error
+    /// without deserializing the body, such as [`Error::RateLimited`] when rate
+    /// limited.
     ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use hyper::{Body, Client};
     /// use hyper_tls::HttpsConnector;
     /// use kitsu_io::KitsuHyperRequester;
-    /// use hyper::Client;
-    /// use std::env;
-    /// use tokio_core::reactor::Core;
     ///
-    /// let mut core = Core::new()?;
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let client = Client::builder().build::<_, Body>(HttpsConnector::new());
     ///
-    /// let connector = HttpsConnector::new(1, &core.handle())?;
-    /// let client = Client::configure()
-    ///     .connector(connector)
-    ///     .build(&core.handle());
+    ///     let anime_id = 1;
     ///
-    /// let user_name = "Bob";
+    ///     let links = client.get_anime_streaming_links_parsed(anime_id).await
+    ///         .expect("Error getting response");
     ///
-    /// let runner = client.search_users(|f| f.filter("name", user_name))?
-    ///     .and_then(|res| {
-    ///         res.body().for_each(|chunk| {
-    ///             io::stdout().write_all(&chunk).map_err(From::from)
-    ///         })
-    ///     }).map(|_| {
-    ///         println!("\n\nDone.");
-    ///     });
+    ///     println!("Found {} streaming links", links.data.len());
+    /// }
+    /// ```
+    ///
+    /// [`Error::RateLimited`]: ../../enum.Error.html#variant.RateLimited
+    /// [`get_anime_streaming_links`]: #tymethod.get_anime_streaming_links
+    fn get_anime_streaming_links_parsed(&self, anime_id: u64)
+        -> FutureParsed<Response<Vec<StreamingLink>>>;
+
+    /// Gets the categories of an anime using the anime's id, deserializing the
+    /// response body.
+    ///
+    /// This is the same as [`get_anime_categories`], but collects the body of
+    /// the response and deserializes it into the model, rather than resolving
+    /// to the raw response. A non-OK response resolves to an error without
+    /// deserializing the body, such as [`Error::RateLimited`] when rate
+    /// limited.
+    ///
+    /// # Examples
     ///
-    /// core.run(runner)?;
+    /// ```rust,no_run
+    /// use hyper::{Body, Client};
+    /// use hyper_tls::HttpsConnector;
+    /// use kitsu_io::KitsuHyperRequester;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let client = Client::builder().build::<_, Body>(HttpsConnector::new());
+    ///
+    ///     let anime_id = 1;
+    ///
+    ///     let categories = client.get_anime_categories_parsed(anime_id).await
+    ///         .expect("Error getting response");
+    ///
+    ///     println!("Found {} categories", categories.data.len());
+    /// }
     /// ```
     ///
-    /// [`Search`]: ../builder/struct.Search.html
+    /// [`Error::RateLimited`]: ../../enum.Error.html#variant.RateLimited
+    /// [`get_anime_categories`]: #tymethod.get_anime_categories
+    fn get_anime_categories_parsed(&self, anime_id: u64) -> FutureParsed<Response<Vec<Category>>>;
+
+    /// Gets categories using the passed [`Search`] builder, deserializing the
+    /// response body.
     ///
-    // Note: This doc example can not be tested due to the reliance on
-    // tokio_core. Instead, this is taken from example `02_hyper` and should
-    // roughly match it to ensure accuracy.
-    fn search_users<F: FnOnce(Search) -> Search>(&self, f: F) ->
-        Result<FutureResponse>;
+    /// This is the same as [`get_categories`], but collects the body of the
+    /// response and deserializes it into the model, rather than resolving to
+    /// the raw response. A non-OK response resolves to an error without
+    /// deserializing the body, such as [`Error::RateLimited`] when rate
+    /// limited.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use hyper::{Body, Client};
+    /// use hyper_tls::HttpsConnector;
+    /// use kitsu_io::KitsuHyperRequester;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let client = Client::builder().build::<_, Body>(HttpsConnector::new());
+    ///
+    ///     let categories = client.get_categories_parsed(|f| f.filter("slug", "space")).await
+    ///         .expect("Error getting response");
+    ///
+    ///     println!("Found {} categories", categories.data.len());
+    /// }
+    /// ```
+    ///
+    /// [`Error::RateLimited`]: ../../enum.Error.html#variant.RateLimited
+    /// [`get_categories`]: #tymethod.get_categories
+    /// [`Search`]: ../../builder/struct.Search.html
+    fn get_categories_parsed<F: FnOnce(Search) -> Search>(&self, f: F)
+        -> FutureParsed<Response<Vec<Category>>>;
+
+    /// Gets the anime which are currently trending, deserializing the response
+    /// body.
+    ///
+    /// This is the same as [`get_trending_anime`], but collects the body of the
+    /// response and deserializes it into the model, rather than resolving to
+    /// the raw response. A non-OK response resolves to an error without
+    /// deserializing the body, such as [`Error::RateLimited`] when rate
+    /// limited.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use hyper::{Body, Client};
+    /// use hyper_tls::HttpsConnector;
+    /// use kitsu_io::KitsuHyperRequester;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let client = Client::builder().build::<_, Body>(HttpsConnector::new());
+    ///
+    ///     let anime = client.get_trending_anime_parsed().await
+    ///         .expect("Error getting response");
+    ///
+    ///     println!("Found {} trending anime", anime.data.len());
+    /// }
+    /// ```
+    ///
+    /// [`Error::RateLimited`]: ../../enum.Error.html#variant.RateLimited
+    /// [`get_trending_anime`]: #tymethod.get_trending_anime
+    fn get_trending_anime_parsed(&self) -> FutureParsed<Response<Vec<Anime>>>;
+
+    /// Gets the manga which are currently trending, deserializing the response
+    /// body.
+    ///
+    /// This is the same as [`get_trending_manga`], but collects the body of the
+    /// response and deserializes it into the model, rather than resolving to
+    /// the raw response. A non-OK response resolves to an error without
+    /// deserializing the body, such as [`Error::RateLimited`] when rate
+    /// limited.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use hyper::{Body, Client};
+    /// use hyper_tls::HttpsConnector;
+    /// use kitsu_io::KitsuHyperRequester;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let client = Client::builder().build::<_, Body>(HttpsConnector::new());
+    ///
+    ///     let manga = client.get_trending_manga_parsed().await
+    ///         .expect("Error getting response");
+    ///
+    ///     println!("Found {} trending manga", manga.data.len());
+    /// }
+    /// ```
+    ///
+    /// [`Error::RateLimited`]: ../../enum.Error.html#variant.RateLimited
+    /// [`get_trending_manga`]: #tymethod.get_trending_manga
+    fn get_trending_manga_parsed(&self) -> FutureParsed<Response<Vec<Manga>>>;
 }
 
 impl KitsuRequester for HyperClient<HttpsConnector<HttpConnector>, Body> {
-    fn get_anime(&self, id: u64) -> Result<FutureResponse> {
+    fn get_anime(&self, id: u64) -> Result<ResponseFuture> {
         let uri = Uri::from_str(&format!("{}/anime/{}", API_URL, id))?;
-        let request = Request::new(Method::Get, uri);
+        let request = get(uri);
 
         Ok(self.request(request))
     }
 
-    fn get_manga(&self, id: u64) -> Result<FutureResponse> {
+    fn get_manga(&self, id: u64) -> Result<ResponseFuture> {
         let uri = Uri::from_str(&format!("{}/manga/{}", API_URL, id))?;
-        let request = Request::new(Method::Get, uri);
+        let request = get(uri);
 
         Ok(self.request(request))
     }
 
-    fn get_user(&self, id: u64) -> Result<FutureResponse> {
+    fn get_user(&self, id: u64) -> Result<ResponseFuture> {
         let uri = Uri::from_str(&format!("{}/users/{}", API_URL, id))?;
-        let request = Request::new(Method::Get, uri);
+        let request = get(uri);
 
         Ok(self.request(request))
     }
 
     fn search_anime<F: FnOnce(Search) -> Search>(&self, f: F) ->
-        Result<FutureResponse> {
-        let params = f(Search::default()).0;
+        Result<ResponseFuture> {
+        let search = f(Search::default());
+        search.validate()?;
 
-        let uri = Uri::from_str(&format!("{}/anime?{}", API_URL, params))?;
-        let request = Request::new(Method::Get, uri);
+        let uri = Uri::from_str(&format!("{}/anime?{}", API_URL, search.as_query()))?;
+        let request = get(uri);
 
         Ok(self.request(request))
     }
 
     fn search_manga<F: FnOnce(Search) -> Search>(&self, f: F) ->
-        Result<FutureResponse> {
-        let params = f(Search::default()).0;
+        Result<ResponseFuture> {
+        let search = f(Search::default());
+        search.validate()?;
 
-        let uri = Uri::from_str(&format!("{}/manga?{}", API_URL, params))?;
-        let request = Request::new(Method::Get, uri);
+        let uri = Uri::from_str(&format!("{}/manga?{}", API_URL, search.as_query()))?;
+        let request = get(uri);
 
         Ok(self.request(request))
     }
 
     fn search_users<F: FnOnce(Search) -> Search>(&self, f: F) ->
-        Result<FutureResponse> {
-        let params = f(Search::default()).0;
+        Result<ResponseFuture> {
+        let search = f(Search::default());
+        search.validate()?;
+
+        let uri = Uri::from_str(&format!("{}/users?{}", API_URL, search.as_query()))?;
+        let request = get(uri);
+
+        Ok(self.request(request))
+    }
+
+    fn get_anime_episodes(&self, anime_id: u64) -> Result<ResponseFuture> {
+        let uri = Uri::from_str(&format!("{}/anime/{}/episodes", API_URL, anime_id))?;
+        let request = get(uri);
+
+        Ok(self.request(request))
+    }
+
+    fn get_manga_chapters(&self, manga_id: u64) -> Result<ResponseFuture> {
+        let uri = Uri::from_str(&format!("{}/manga/{}/chapters", API_URL, manga_id))?;
+        let request = get(uri);
+
+        Ok(self.request(request))
+    }
+
+    fn get_anime_streaming_links(&self, anime_id: u64) -> Result<ResponseFuture> {
+        let uri = Uri::from_str(&format!("{}/anime/{}/streaming-links", API_URL, anime_id))?;
+        let request = get(uri);
+
+        Ok(self.request(request))
+    }
+
+    fn get_anime_categories(&self, anime_id: u64) -> Result<ResponseFuture> {
+        let uri = Uri::from_str(&format!("{}/anime/{}/categories", API_URL, anime_id))?;
+        let request = get(uri);
+
+        Ok(self.request(request))
+    }
+
+    fn get_categories<F: FnOnce(Search) -> Search>(&self, f: F) ->
+        Result<ResponseFuture> {
+        let search = f(Search::default());
+        search.validate()?;
+
+        let uri = Uri::from_str(&format!("{}/categories?{}", API_URL, search.as_query()))?;
+        let request = get(uri);
+
+        Ok(self.request(request))
+    }
+
+    fn get_trending_anime(&self) -> Result<ResponseFuture> {
+        let uri = Uri::from_str(&format!("{}/trending/anime", API_URL))?;
+        let request = get(uri);
+
+        Ok(self.request(request))
+    }
 
-        let uri = Uri::from_str(&format!("{}/users?{}", API_URL, params))?;
-        let request = Request::new(Method::Get, uri);
+    fn get_trending_manga(&self) -> Result<ResponseFuture> {
+        let uri = Uri::from_str(&format!("{}/trending/manga", API_URL))?;
+        let request = get(uri);
 
         Ok(self.request(request))
     }
+
+    fn get_anime_parsed(&self, id: u64) -> FutureParsed<Response<Anime>> {
+        parse(self.get_anime(id))
+    }
+
+    fn get_manga_parsed(&self, id: u64) -> FutureParsed<Response<Manga>> {
+        parse(self.get_manga(id))
+    }
+
+    fn get_user_parsed(&self, id: u64) -> FutureParsed<Response<User>> {
+        parse(self.get_user(id))
+    }
+
+    fn search_anime_parsed<F: FnOnce(Search) -> Search>(&self, f: F)
+        -> FutureParsed<Response<Vec<Anime>>> {
+        parse(self.search_anime(f))
+    }
+
+    fn search_manga_parsed<F: FnOnce(Search) -> Search>(&self, f: F)
+        -> FutureParsed<Response<Vec<Manga>>> {
+        parse(self.search_manga(f))
+    }
+
+    fn search_users_parsed<F: FnOnce(Search) -> Search>(&self, f: F)
+        -> FutureParsed<Response<Vec<User>>> {
+        parse(self.search_users(f))
+    }
+
+    fn get_anime_episodes_parsed(&self, anime_id: u64) -> FutureParsed<Response<Vec<Episode>>> {
+        parse(self.get_anime_episodes(anime_id))
+    }
+
+    fn get_manga_chapters_parsed(&self, manga_id: u64) -> FutureParsed<Response<Vec<Chapter>>> {
+        parse(self.get_manga_chapters(manga_id))
+    }
+
+    fn get_anime_streaming_links_parsed(&self, anime_id: u64)
+        -> FutureParsed<Response<Vec<StreamingLink>>> {
+        parse(self.get_anime_streaming_links(anime_id))
+    }
+
+    fn get_anime_categories_parsed(&self, anime_id: u64) -> FutureParsed<Response<Vec<Category>>> {
+        parse(self.get_anime_categories(anime_id))
+    }
+
+    fn get_categories_parsed<F: FnOnce(Search) -> Search>(&self, f: F)
+        -> FutureParsed<Response<Vec<Category>>> {
+        parse(self.get_categories(f))
+    }
+
+    fn get_trending_anime_parsed(&self) -> FutureParsed<Response<Vec<Anime>>> {
+        parse(self.get_trending_anime())
+    }
+
+    fn get_trending_manga_parsed(&self) -> FutureParsed<Response<Vec<Manga>>> {
+        parse(self.get_trending_manga())
+    }
+}
+
+/// Collects the body of a requested response and deserializes it, resolving to
+/// the error if the request could not be made.
+///
+/// Non-OK responses resolve to an error without deserializing the body into the
+/// model, in the same way as the `reqwest` bridge: [`Error::RateLimited`] for a
+/// `429`, [`Error::Api`] if the body is a JSON:API error document, and
+/// otherwise [`Error::HyperInvalid`].
+///
+/// [`Error::Api`]: ../../enum.Error.html#variant.Api
+/// [`Error::HyperInvalid`]: ../../enum.Error.html#variant.HyperInvalid
+/// [`Error::RateLimited`]: ../../enum.Error.html#variant.RateLimited
+fn parse<T: DeserializeOwned + 'static>(response: Result<ResponseFuture>) -> FutureParsed<T> {
+    Box::pin(async move {
+        let response = response?.await?;
+        let status = response.status();
+        let retry_after = response.headers().get(RETRY_AFTER)
+            .and_then(|value| value.to_str().ok())
+            .and_then(retry_after);
+        let body = body::to_bytes(response.into_body()).await?;
+
+        if status != StatusCode::OK {
+            return Err(error_from_parts(status, retry_after, &body));
+        }
+
+        serde_json::from_slice(&body).map_err(|source| Error::Json {
+            source,
+            body: String::from_utf8_lossy(&body).into_owned(),
+            request_id: None,
+        })
+    })
+}
+
+/// Creates an error from the parts of a non-OK response.
+fn error_from_parts(status: StatusCode, retry_after: Option<Duration>, body: &[u8]) -> Error {
+    if status == StatusCode::TOO_MANY_REQUESTS {
+        return Error::RateLimited {
+            retry_after,
+            request_id: None,
+        };
+    }
+
+    match api_errors(body) {
+        Some(errors) => Error::Api(errors, None),
+        None => Error::HyperInvalid(status),
+    }
+}
+
+/// Creates a `GET` request to a URI which accepts JSON:API documents.
+fn get(uri: Uri) -> Request<Body> {
+    let mut request = Request::new(Body::empty());
+    *request.uri_mut() = uri;
+    request.headers_mut().insert(ACCEPT, HeaderValue::from_static(JSON_API));
+
+    request
 }
@@ -4,3 +4,39 @@
 pub mod hyper;
 #[cfg(feature = "reqwest")]
 pub mod reqwest;
+#[cfg(feature = "reqwest-async")]
+pub mod reqwest_async;
+
+/// The media type of JSON:API documents, which is sent in the `Accept` header
+/// of requests and should be the `Content-Type` of any request bodies.
+#[cfg(any(feature = "hyper", feature = "reqwest"))]
+pub(crate) const JSON_API: &str = "application/vnd.api+json";
+
+#[cfg(any(feature = "hyper", feature = "reqwest"))]
+use crate::ApiError;
+#[cfg(any(feature = "hyper", feature = "reqwest"))]
+use std::time::Duration;
+
+/// A JSON:API error document.
+#[cfg(any(feature = "hyper", feature = "reqwest"))]
+#[derive(Deserialize)]
+struct ApiErrors {
+    errors: Vec<ApiError>,
+}
+
+/// Parses the errors of a JSON:API error document from a response body,
+/// returning `None` if the body is not an error document or has no errors.
+#[cfg(any(feature = "hyper", feature = "reqwest"))]
+pub(crate) fn api_errors(body: &[u8]) -> Option<Vec<ApiError>> {
+    match serde_json::from_slice::<ApiErrors>(body) {
+        Ok(ApiErrors { errors }) if !errors.is_empty() => Some(errors),
+        _ => None,
+    }
+}
+
+/// Parses the value of a `Retry-After` header, if it is given in seconds
+/// rather than as a date.
+#[cfg(any(feature = "hyper", feature = "reqwest"))]
+pub(crate) fn retry_after(value: &str) -> Option<Duration> {
+    value.trim().parse::<u64>().ok().map(Duration::from_secs)
+}
@@ -6,14 +6,31 @@
 //!
 //! [`KitsuRequester`]: trait.KitsuRequester.html
 
-use ::builder::Search;
-use ::model::{Anime, Manga, Response, User};
-use reqwest::blocking::{Client as ReqwestClient, RequestBuilder};
-use reqwest::StatusCode;
+use crate::builder::Search;
+use crate::model::{Anime, AnimeProduction, Casting, Category, Chapter, Character, Comment, Episode, Follow, FollowRelationships, Genre, Group, Included, Installment, LibraryEntry, LibraryEvent, Manga, Mapping, MediaReaction, MediaRelationship, Meta, Person, Post, Response, Review, StreamingLink, ToOneRelationship, User};
+use reqwest::blocking::Client as ReqwestClient;
+use reqwest::header::{HeaderMap, HeaderValue, ACCEPT, AUTHORIZATION, ETAG, IF_NONE_MATCH, RETRY_AFTER};
+use reqwest::{Method, StatusCode};
 use serde::de::DeserializeOwned;
 use serde_json;
-use std::io::Read;
-use ::{Error, Result, API_URL};
+use std::collections::hash_map::RandomState;
+use std::collections::HashMap;
+use std::hash::{BuildHasher, Hasher};
+use std::str;
+use std::thread;
+use std::time::Duration;
+use std::vec::IntoIter;
+use crate::{Error, Result, API_URL};
+use self::sealed::{Base, RawRequest, RawResponse};
+use super::{api_errors, retry_after, JSON_API};
+
+/// The maximum number of results Kitsu returns in a page, which bounds how many
+/// resources can be requested by id at once.
+const MAX_PAGE_LIMIT: usize = 20;
+
+/// The header used to send a unique id with each request, which can be quoted
+/// when reporting an issue to Kitsu.
+pub(crate) const X_REQUEST_ID: &str = "X-Request-Id";
 
 /// Trait which defines the methods necessary to interact with the service.
 ///
@@ -27,7 +44,69 @@ use ::{Error, Result, API_URL};
 /// ```
 ///
 /// At this point, the methods will be on your Reqwest Client.
+///
+/// The methods are also on a [`KitsuClient`], which can be configured to make
+/// requests to a different base URL, such as a mock server.
+///
+/// The Client pools its connections, so create it once and reuse it, such as
+/// when making many requests in a loop, rather than creating a new Client for
+/// each request.
+///
+/// The trait can be used as a trait object, such as a
+/// `Box<dyn KitsuRequester>`, to choose between a client and the
+/// `MockRequester` of the `mock` feature at runtime. The methods which are generic over a closure
+/// building a [`Search`] are not available on a trait object, so use the
+/// methods taking a prebuilt [`Search`] instead, such as
+/// [`search_anime_with`].
+///
+/// [`KitsuClient`]: ../../client/struct.KitsuClient.html
+/// [`Search`]: ../../builder/struct.Search.html
+/// [`search_anime_with`]: #tymethod.search_anime_with
 pub trait KitsuRequester {
+    /// Checks whether an anime with the given id exists.
+    ///
+    /// This issues a `HEAD` request, so the body of the anime is not
+    /// downloaded. This is useful for validating a large number of ids.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// extern crate kitsu_io;
+    /// extern crate reqwest;
+    ///
+    /// use kitsu_io::KitsuReqwestRequester;
+    /// use reqwest::blocking::Client;
+    ///
+    /// fn main() {
+    ///     // Create the reqwest Client.
+    ///     let client = Client::new();
+    ///
+    ///     let anime_id = 1;
+    ///
+    ///     // Check whether the anime exists.
+    ///     let exists = client.anime_exists(anime_id)
+    ///         .expect("Error checking anime");
+    ///
+    ///     println!("Anime {} exists: {}", anime_id, exists);
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::ReqwestBad`] if the request was otherwise bad for some
+    /// reason, containing the response.
+    ///
+    /// Returns [`Error::ReqwestInvalid`] if the response was neither an OK
+    /// (status code 200) nor a Not Found (status code 404) response.
+    ///
+    /// Returns [`Error::ReqwestUnauthorized`] if the authorization token was
+    /// invalid.
+    ///
+    /// [`Error::ReqwestBad`]: ../enum.Error.html#variant.ReqwestBad
+    /// [`Error::ReqwestInvalid`]: ../enum.Error.html#variant.ReqwestInvalid
+    /// [`Error::ReqwestUnauthorized`]: ../enum.Error.html#variant.ReqwestUnauthorized
+    fn anime_exists(&self, id: u64) -> Result<bool>;
+
     /// Gets an anime using its id.
     ///
     /// # Examples
@@ -39,7 +118,7 @@ pub trait KitsuRequester {
     /// extern crate reqwest;
     ///
     /// use kitsu_io::KitsuReqwestRequester;
-    /// use reqwest::Client;
+    /// use reqwest::blocking::Client;
     ///
     /// fn main() {
     ///     // Create the reqwest Client.
@@ -91,7 +170,7 @@ pub trait KitsuRequester {
     /// extern crate reqwest;
     ///
     /// use kitsu_io::KitsuReqwestRequester;
-    /// use reqwest::Client;
+    /// use reqwest::blocking::Client;
     ///
     /// fn main() {
     ///     // Create the reqwest Client.
@@ -143,7 +222,7 @@ pub trait KitsuRequester {
     /// extern crate reqwest;
     ///
     /// use kitsu_io::KitsuReqwestRequester;
-    /// use reqwest::Client;
+    /// use reqwest::blocking::Client;
     ///
     /// fn main() {
     ///     // Create the reqwest Client.
@@ -184,7 +263,102 @@ pub trait KitsuRequester {
     /// [`Error::ReqwestUnauthorized`]: ../enum.Error.html#variant.ReqwestUnauthorized
     fn get_user(&self, id: u64) -> Result<Response<User>>;
 
-    /// Gets an anime using its id.
+    /// Gets a random anime.
+    ///
+    /// This reads the total number of anime from the response metadata, and
+    /// then fetches the anime at a random offset within that count. As such,
+    /// two requests are made.
+    ///
+    /// Returns `None` if there are no anime.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// extern crate kitsu_io;
+    /// extern crate reqwest;
+    ///
+    /// use kitsu_io::KitsuReqwestRequester;
+    /// use reqwest::blocking::Client;
+    ///
+    /// fn main() {
+    ///     // Create the reqwest Client.
+    ///     let client = Client::new();
+    ///
+    ///     // Get a random anime.
+    ///     let anime = client.random_anime()
+    ///         .expect("Error getting anime");
+    ///
+    ///     // Do something with anime
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Json`] if there was an error parsing the response
+    /// body.
+    ///
+    /// Returns [`Error::ReqwestBad`] if the request was otherwise bad for some
+    /// reason, containing the response.
+    ///
+    /// Returns [`Error::ReqwestInvalid`] if the response was a non-OK (status
+    /// code 200) response, containing the response.
+    ///
+    /// Returns [`Error::ReqwestUnauthorized`] if the authorization token was
+    /// invalid.
+    ///
+    /// [`Error::Json`]: ../enum.Error.html#variant.Json
+    /// [`Error::ReqwestBad`]: ../enum.Error.html#variant.ReqwestBad
+    /// [`Error::ReqwestInvalid`]: ../enum.Error.html#variant.ReqwestInvalid
+    /// [`Error::ReqwestUnauthorized`]: ../enum.Error.html#variant.ReqwestUnauthorized
+    fn random_anime(&self) -> Result<Option<Anime>>;
+
+    /// Gets the most recently updated anime, limited to the given number of
+    /// results.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// extern crate kitsu_io;
+    /// extern crate reqwest;
+    ///
+    /// use kitsu_io::KitsuReqwestRequester;
+    /// use reqwest::blocking::Client;
+    ///
+    /// fn main() {
+    ///     // Create the reqwest Client.
+    ///     let client = Client::new();
+    ///
+    ///     // Get the 10 most recently updated anime.
+    ///     let anime = client.recently_updated_anime(10)
+    ///         .expect("Error getting anime");
+    ///
+    ///     // Do something with anime
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Json`] if there was an error parsing the response
+    /// body.
+    ///
+    /// Returns [`Error::ReqwestBad`] if the request was otherwise bad for some
+    /// reason, containing the response.
+    ///
+    /// Returns [`Error::ReqwestInvalid`] if the response was a non-OK (status
+    /// code 200) response, containing the response.
+    ///
+    /// Returns [`Error::ReqwestUnauthorized`] if the authorization token was
+    /// invalid.
+    ///
+    /// [`Error::Json`]: ../enum.Error.html#variant.Json
+    /// [`Error::ReqwestBad`]: ../enum.Error.html#variant.ReqwestBad
+    /// [`Error::ReqwestInvalid`]: ../enum.Error.html#variant.ReqwestInvalid
+    /// [`Error::ReqwestUnauthorized`]: ../enum.Error.html#variant.ReqwestUnauthorized
+    fn recently_updated_anime(&self, limit: u64) -> Result<Response<Vec<Anime>>>;
+
+    /// Searches for anime using the passed [`Search`] builder.
+    ///
+    /// [`Search`]: ../../builder/struct.Search.html
     ///
     /// # Examples
     ///
@@ -195,7 +369,7 @@ pub trait KitsuRequester {
     /// extern crate reqwest;
     ///
     /// use kitsu_io::KitsuReqwestRequester;
-    /// use reqwest::Client;
+    /// use reqwest::blocking::Client;
     ///
     /// fn main() {
     ///     // Create the reqwest Client.
@@ -234,9 +408,12 @@ pub trait KitsuRequester {
     /// [`Error::ReqwestInvalid`]: ../enum.Error.html#variant.ReqwestInvalid
     /// [`Error::ReqwestParse`]: ../enum.Error.html#variant.ReqwestParse
     /// [`Error::ReqwestUnauthorized`]: ../enum.Error.html#variant.ReqwestUnauthorized
-    fn search_anime<F: FnOnce(Search) -> Search>(&self, f: F) -> Result<Response<Vec<Anime>>>;
+    fn search_anime<F: FnOnce(Search) -> Search>(&self, f: F) -> Result<Response<Vec<Anime>>>
+        where Self: Sized;
 
-    /// Gets an anime using its id.
+    /// Searches for manga using the passed [`Search`] builder.
+    ///
+    /// [`Search`]: ../../builder/struct.Search.html
     ///
     /// # Examples
     ///
@@ -247,7 +424,7 @@ pub trait KitsuRequester {
     /// extern crate reqwest;
     ///
     /// use kitsu_io::KitsuReqwestRequester;
-    /// use reqwest::Client;
+    /// use reqwest::blocking::Client;
     ///
     /// fn main() {
     ///     // Create the reqwest Client.
@@ -286,9 +463,12 @@ pub trait KitsuRequester {
     /// [`Error::ReqwestInvalid`]: ../enum.Error.html#variant.ReqwestInvalid
     /// [`Error::ReqwestParse`]: ../enum.Error.html#variant.ReqwestParse
     /// [`Error::ReqwestUnauthorized`]: ../enum.Error.html#variant.ReqwestUnauthorized
-    fn search_manga<F: FnOnce(Search) -> Search>(&self, f: F) -> Result<Response<Vec<Manga>>>;
+    fn search_manga<F: FnOnce(Search) -> Search>(&self, f: F) -> Result<Response<Vec<Manga>>>
+        where Self: Sized;
 
-    /// Gets an anime using its id.
+    /// Searches for users using the passed [`Search`] builder.
+    ///
+    /// [`Search`]: ../../builder/struct.Search.html
     ///
     /// # Examples
     ///
@@ -299,7 +479,7 @@ pub trait KitsuRequester {
     /// extern crate reqwest;
     ///
     /// use kitsu_io::KitsuReqwestRequester;
-    /// use reqwest::Client;
+    /// use reqwest::blocking::Client;
     ///
     /// fn main() {
     ///     // Create the reqwest Client.
@@ -338,68 +518,3211 @@ pub trait KitsuRequester {
     /// [`Error::ReqwestInvalid`]: ../enum.Error.html#variant.ReqwestInvalid
     /// [`Error::ReqwestParse`]: ../enum.Error.html#variant.ReqwestParse
     /// [`Error::ReqwestUnauthorized`]: ../enum.Error.html#variant.ReqwestUnauthorized
-    fn search_users<F: FnOnce(Search) -> Search>(&self, f: F) -> Result<Response<Vec<User>>>;
-}
-
-impl KitsuRequester for ReqwestClient {
-    fn get_anime(&self, id: u64) -> Result<Response<Anime>> {
-        let uri = url::Url::parse(&format!("{}/anime/{}", API_URL, id.to_string()))?;
-
-        handle_request::<Response<Anime>>(self.get(uri))
-    }
+    fn search_users<F: FnOnce(Search) -> Search>(&self, f: F) -> Result<Response<Vec<User>>>
+        where Self: Sized;
 
-    fn get_manga(&self, id: u64) -> Result<Response<Manga>> {
-        let uri = url::Url::parse(&format!("{}/manga/{}", API_URL, id.to_string()))?;
-
-        handle_request::<Response<Manga>>(self.get(uri))
-    }
-
-    fn get_user(&self, id: u64) -> Result<Response<User>> {
-        let uri = url::Url::parse(&format!("{}/users/{}", API_URL, id.to_string()))?;
-
-        handle_request::<Response<User>>(self.get(uri))
-    }
-
-    fn search_anime<F: FnOnce(Search) -> Search>(&self, f: F) -> Result<Response<Vec<Anime>>> {
-        let params = f(Search::default()).0;
-        let uri = url::Url::parse(&format!("{}/anime?{}", API_URL, params))?;
-
-        handle_request::<Response<Vec<Anime>>>(self.get(uri))
-    }
+    /// Gets the names of the genres of an anime using the anime's id.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// extern crate kitsu_io;
+    /// extern crate reqwest;
+    ///
+    /// use kitsu_io::KitsuReqwestRequester;
+    /// use reqwest::blocking::Client;
+    ///
+    /// fn main() {
+    ///     // Create the reqwest Client.
+    ///     let client = Client::new();
+    ///
+    ///     let anime_id = 1;
+    ///
+    ///     // Get the anime's genre names.
+    ///     let genres = client.get_anime_genre_names(anime_id)
+    ///         .expect("Error getting genres");
+    ///
+    ///     println!("Genres: {}", genres.join(", "));
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Json`] if there was an error parsing the response
+    /// body.
+    ///
+    /// Returns [`Error::ReqwestBad`] if the request was otherwise bad for some
+    /// reason, containing the response.
+    ///
+    /// Returns [`Error::ReqwestInvalid`] if the response was a non-OK (status
+    /// code 200) response, containing the response.
+    ///
+    /// Returns [`Error::ReqwestUnauthorized`] if the authorization token was
+    /// invalid.
+    ///
+    /// [`Error::Json`]: ../enum.Error.html#variant.Json
+    /// [`Error::ReqwestBad`]: ../enum.Error.html#variant.ReqwestBad
+    /// [`Error::ReqwestInvalid`]: ../enum.Error.html#variant.ReqwestInvalid
+    /// [`Error::ReqwestUnauthorized`]: ../enum.Error.html#variant.ReqwestUnauthorized
+    fn get_anime_genre_names(&self, anime_id: u64) -> Result<Vec<String>>;
 
-    fn search_manga<F: FnOnce(Search) -> Search>(&self, f: F) -> Result<Response<Vec<Manga>>> {
-        let params = f(Search::default()).0;
-        let uri = url::Url::parse(&format!("{}/manga?{}", API_URL, params))?;
+    /// Searches for anime by text, falling back to matching on the canonical
+    /// title.
+    ///
+    /// Kitsu's text filter does not transliterate, so a query typed in romaji may
+    /// not match. The results of the text search are followed by those of a
+    /// search on the `canonicalTitle`, which is usually the romaji title of an
+    /// anime, with anime found by both searches only listed once.
+    ///
+    /// **Note**: This issues two requests, each returning the first page of its
+    /// search.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// extern crate kitsu_io;
+    /// extern crate reqwest;
+    ///
+    /// use kitsu_io::KitsuReqwestRequester;
+    /// use reqwest::blocking::Client;
+    ///
+    /// fn main() {
+    ///     // Create the reqwest Client.
+    ///     let client = Client::new();
+    ///
+    ///     let anime_name = "shingeki no kyojin";
+    ///
+    ///     // Search for the anime.
+    ///     let anime = client.search_anime_fuzzy(anime_name)
+    ///         .expect("Error searching for anime");
+    ///
+    ///     // Do something with anime
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Json`] if there was an error parsing the response
+    /// body.
+    ///
+    /// Returns [`Error::ReqwestBad`] if the request was otherwise bad for some
+    /// reason, containing the response.
+    ///
+    /// Returns [`Error::ReqwestInvalid`] if the response was a non-OK (status
+    /// code 200) response, containing the response.
+    ///
+    /// Returns [`Error::ReqwestUnauthorized`] if the authorization token was
+    /// invalid.
+    ///
+    /// [`Error::Json`]: ../enum.Error.html#variant.Json
+    /// [`Error::ReqwestBad`]: ../enum.Error.html#variant.ReqwestBad
+    /// [`Error::ReqwestInvalid`]: ../enum.Error.html#variant.ReqwestInvalid
+    /// [`Error::ReqwestUnauthorized`]: ../enum.Error.html#variant.ReqwestUnauthorized
+    fn search_anime_fuzzy(&self, query: &str) -> Result<Vec<Anime>>;
 
-        handle_request::<Response<Vec<Manga>>>(self.get(uri))
-    }
+    /// Gets the user that an OAuth access token belongs to.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// extern crate kitsu_io;
+    /// extern crate reqwest;
+    ///
+    /// use kitsu_io::KitsuReqwestRequester;
+    /// use reqwest::blocking::Client;
+    ///
+    /// fn main() {
+    ///     // Create the reqwest Client.
+    ///     let client = Client::new();
+    ///
+    ///     let token = "access token";
+    ///
+    ///     // Get the user that owns the token.
+    ///     let user = client.get_self(token)
+    ///         .expect("Error getting user");
+    ///
+    ///     println!("Logged in as {}", user.data.attributes.name);
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Json`] if there was an error parsing the response
+    /// body.
+    ///
+    /// Returns [`Error::ReqwestBad`] if the request was otherwise bad for some
+    /// reason, containing the response.
+    ///
+    /// Returns [`Error::ReqwestInvalid`] if the response was a non-OK (status
+    /// code 200) response, containing the response.
+    ///
+    /// Returns [`Error::ReqwestUnauthorized`] if the authorization token was
+    /// invalid, or did not belong to a user.
+    ///
+    /// [`Error::Json`]: ../enum.Error.html#variant.Json
+    /// [`Error::ReqwestBad`]: ../enum.Error.html#variant.ReqwestBad
+    /// [`Error::ReqwestInvalid`]: ../enum.Error.html#variant.ReqwestInvalid
+    /// [`Error::ReqwestUnauthorized`]: ../enum.Error.html#variant.ReqwestUnauthorized
+    fn get_self(&self, token: &str) -> Result<Response<User>>;
 
-    fn search_users<F: FnOnce(Search) -> Search>(&self, f: F) -> Result<Response<Vec<User>>> {
-        let params = f(Search::default()).0;
-        let uri = url::Url::parse(&format!("{}/users?{}", API_URL, params))?;
+    /// Gets the episodes of an anime using the anime's id.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// extern crate kitsu_io;
+    /// extern crate reqwest;
+    ///
+    /// use kitsu_io::KitsuReqwestRequester;
+    /// use reqwest::blocking::Client;
+    ///
+    /// fn main() {
+    ///     // Create the reqwest Client.
+    ///     let client = Client::new();
+    ///
+    ///     let anime_id = 1;
+    ///
+    ///     // Get the anime's episodes.
+    ///     let episodes = client.get_anime_episodes(anime_id)
+    ///         .expect("Error getting episodes");
+    ///
+    ///     // Do something with episodes
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Json`] if there was an error parsing the response
+    /// body.
+    ///
+    /// Returns [`Error::ReqwestBad`] if the request was otherwise bad for some
+    /// reason, containing the response.
+    ///
+    /// Returns [`Error::ReqwestInvalid`] if the response was a non-OK (status
+    /// code 200) response, containing the response.
+    ///
+    /// Returns [`Error::ReqwestUnauthorized`] if the authorization token was
+    /// invalid.
+    ///
+    /// [`Error::Json`]: ../enum.Error.html#variant.Json
+    /// [`Error::ReqwestBad`]: ../enum.Error.html#variant.ReqwestBad
+    /// [`Error::ReqwestInvalid`]: ../enum.Error.html#variant.ReqwestInvalid
+    /// [`Error::ReqwestUnauthorized`]: ../enum.Error.html#variant.ReqwestUnauthorized
+    fn get_anime_episodes(&self, anime_id: u64) -> Result<Response<Vec<Episode>>>;
+
+    /// Gets the chapters of a manga using the manga's id.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// extern crate kitsu_io;
+    /// extern crate reqwest;
+    ///
+    /// use kitsu_io::KitsuReqwestRequester;
+    /// use reqwest::blocking::Client;
+    ///
+    /// fn main() {
+    ///     // Create the reqwest Client.
+    ///     let client = Client::new();
+    ///
+    ///     let manga_id = 1;
+    ///
+    ///     // Get the manga's chapters.
+    ///     let chapters = client.get_manga_chapters(manga_id)
+    ///         .expect("Error getting chapters");
+    ///
+    ///     // Do something with chapters
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Json`] if there was an error parsing the response
+    /// body.
+    ///
+    /// Returns [`Error::ReqwestBad`] if the request was otherwise bad for some
+    /// reason, containing the response.
+    ///
+    /// Returns [`Error::ReqwestInvalid`] if the response was a non-OK (status
+    /// code 200) response, containing the response.
+    ///
+    /// Returns [`Error::ReqwestUnauthorized`] if the authorization token was
+    /// invalid.
+    ///
+    /// [`Error::Json`]: ../enum.Error.html#variant.Json
+    /// [`Error::ReqwestBad`]: ../enum.Error.html#variant.ReqwestBad
+    /// [`Error::ReqwestInvalid`]: ../enum.Error.html#variant.ReqwestInvalid
+    /// [`Error::ReqwestUnauthorized`]: ../enum.Error.html#variant.ReqwestUnauthorized
+    fn get_manga_chapters(&self, manga_id: u64) -> Result<Response<Vec<Chapter>>>;
+
+    /// Searches for anime using the passed [`Search`] builder, returning an
+    /// iterator over the results of every page.
+    ///
+    /// Pages are fetched lazily by following the `next` link of each response.
+    /// Iteration stops after the first error, which is yielded.
+    ///
+    /// [`Search`]: ../builder/struct.Search.html
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// extern crate kitsu_io;
+    /// extern crate reqwest;
+    ///
+    /// use kitsu_io::KitsuReqwestRequester;
+    /// use reqwest::blocking::Client;
+    ///
+    /// fn main() {
+    ///     // Create the reqwest Client.
+    ///     let client = Client::new();
+    ///
+    ///     // Iterate over every anime airing this season.
+    ///     for anime in client.search_anime_all(|f| f.filter("season", "winter")) {
+    ///         let anime = anime.expect("Error searching for anime");
+    ///
+    ///         println!("{}", anime.attributes.canonical_title);
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Json`] if there was an error parsing the response
+    /// body.
+    ///
+    /// Returns [`Error::ReqwestBad`] if the request was otherwise bad for some
+    /// reason, containing the response.
+    ///
+    /// Returns [`Error::ReqwestInvalid`] if the response was a non-OK (status
+    /// code 200) response, containing the response.
+    ///
+    /// Returns [`Error::ReqwestUnauthorized`] if the authorization token was
+    /// invalid.
+    ///
+    /// Returns [`Error::ForeignUrl`] if the link to the next page was not on
+    /// the API.
+    ///
+    /// [`Error::ForeignUrl`]: ../enum.Error.html#variant.ForeignUrl
+    /// [`Error::Json`]: ../enum.Error.html#variant.Json
+    /// [`Error::ReqwestBad`]: ../enum.Error.html#variant.ReqwestBad
+    /// [`Error::ReqwestInvalid`]: ../enum.Error.html#variant.ReqwestInvalid
+    /// [`Error::ReqwestUnauthorized`]: ../enum.Error.html#variant.ReqwestUnauthorized
+    fn search_anime_all<F: FnOnce(Search) -> Search>(&self, f: F) -> Paginated<'_, Anime>
+        where Self: Sized;
+
+    /// Gets the links to where an anime can be legally streamed using the
+    /// anime's id.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// extern crate kitsu_io;
+    /// extern crate reqwest;
+    ///
+    /// use kitsu_io::KitsuReqwestRequester;
+    /// use reqwest::blocking::Client;
+    ///
+    /// fn main() {
+    ///     // Create the reqwest Client.
+    ///     let client = Client::new();
+    ///
+    ///     let anime_id = 1;
+    ///
+    ///     // Get the anime's streaming links.
+    ///     let links = client.get_anime_streaming_links(anime_id)
+    ///         .expect("Error getting streaming links");
+    ///
+    ///     // Do something with links
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Json`] if there was an error parsing the response
+    /// body.
+    ///
+    /// Returns [`Error::ReqwestBad`] if the request was otherwise bad for some
+    /// reason, containing the response.
+    ///
+    /// Returns [`Error::ReqwestInvalid`] if the response was a non-OK (status
+    /// code 200) response, containing the response.
+    ///
+    /// Returns [`Error::ReqwestUnauthorized`] if the authorization token was
+    /// invalid.
+    ///
+    /// [`Error::Json`]: ../enum.Error.html#variant.Json
+    /// [`Error::ReqwestBad`]: ../enum.Error.html#variant.ReqwestBad
+    /// [`Error::ReqwestInvalid`]: ../enum.Error.html#variant.ReqwestInvalid
+    /// [`Error::ReqwestUnauthorized`]: ../enum.Error.html#variant.ReqwestUnauthorized
+    fn get_anime_streaming_links(&self, anime_id: u64) -> Result<Response<Vec<StreamingLink>>>;
+
+    /// Gets the categories of an anime using the anime's id.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// extern crate kitsu_io;
+    /// extern crate reqwest;
+    ///
+    /// use kitsu_io::KitsuReqwestRequester;
+    /// use reqwest::blocking::Client;
+    ///
+    /// fn main() {
+    ///     // Create the reqwest Client.
+    ///     let client = Client::new();
+    ///
+    ///     let anime_id = 1;
+    ///
+    ///     // Get the anime's categories.
+    ///     let categories = client.get_anime_categories(anime_id)
+    ///         .expect("Error getting categories");
+    ///
+    ///     // Do something with categories
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Json`] if there was an error parsing the response
+    /// body.
+    ///
+    /// Returns [`Error::ReqwestBad`] if the request was otherwise bad for some
+    /// reason, containing the response.
+    ///
+    /// Returns [`Error::ReqwestInvalid`] if the response was a non-OK (status
+    /// code 200) response, containing the response.
+    ///
+    /// Returns [`Error::ReqwestUnauthorized`] if the authorization token was
+    /// invalid.
+    ///
+    /// [`Error::Json`]: ../enum.Error.html#variant.Json
+    /// [`Error::ReqwestBad`]: ../enum.Error.html#variant.ReqwestBad
+    /// [`Error::ReqwestInvalid`]: ../enum.Error.html#variant.ReqwestInvalid
+    /// [`Error::ReqwestUnauthorized`]: ../enum.Error.html#variant.ReqwestUnauthorized
+    fn get_anime_categories(&self, anime_id: u64) -> Result<Response<Vec<Category>>>;
+
+    /// Gets a list of categories using the search builder.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// extern crate kitsu_io;
+    /// extern crate reqwest;
+    ///
+    /// use kitsu_io::KitsuReqwestRequester;
+    /// use reqwest::blocking::Client;
+    ///
+    /// fn main() {
+    ///     // Create the reqwest Client.
+    ///     let client = Client::new();
+    ///
+    ///     // Get the top-level categories, sorted by title.
+    ///     let categories = client.get_categories(|f| f.filter("parent_id", "_none").sort("title"))
+    ///         .expect("Error getting categories");
+    ///
+    ///     // Do something with categories
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Json`] if there was an error parsing the response
+    /// body.
+    ///
+    /// Returns [`Error::ReqwestBad`] if the request was otherwise bad for some
+    /// reason, containing the response.
+    ///
+    /// Returns [`Error::ReqwestInvalid`] if the response was a non-OK (status
+    /// code 200) response, containing the response.
+    ///
+    /// Returns [`Error::ReqwestUnauthorized`] if the authorization token was
+    /// invalid.
+    ///
+    /// [`Error::Json`]: ../enum.Error.html#variant.Json
+    /// [`Error::ReqwestBad`]: ../enum.Error.html#variant.ReqwestBad
+    /// [`Error::ReqwestInvalid`]: ../enum.Error.html#variant.ReqwestInvalid
+    /// [`Error::ReqwestUnauthorized`]: ../enum.Error.html#variant.ReqwestUnauthorized
+    fn get_categories<F: FnOnce(Search) -> Search>(&self, f: F) -> Result<Response<Vec<Category>>>
+        where Self: Sized;
+
+    /// Gets a resource from a URL on the Kitsu API, such as the link to a
+    /// relationship of another resource.
+    ///
+    /// To guard against requesting arbitrary hosts, the URL must be on the API,
+    /// starting with [`API_URL`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// extern crate kitsu_io;
+    /// extern crate reqwest;
+    ///
+    /// use kitsu_io::KitsuReqwestRequester;
+    /// use kitsu_io::model::Genre;
+    /// use reqwest::blocking::Client;
+    ///
+    /// fn main() {
+    ///     // Create the reqwest Client.
+    ///     let client = Client::new();
+    ///
+    ///     // Get an anime, then its genres by following the relationship link.
+    ///     let anime = client.get_anime(1).expect("Error getting anime");
+    ///     let url = &anime.data.relationships.genres.links.related;
+    ///
+    ///     let genres = client.fetch::<Vec<Genre>>(url)
+    ///         .expect("Error getting genres");
+    ///
+    ///     // Do something with genres
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::ForeignUrl`] if the URL is not on the Kitsu API.
+    ///
+    /// Returns [`Error::Json`] if there was an error parsing the response
+    /// body.
+    ///
+    /// Returns [`Error::ReqwestBad`] if the request was otherwise bad for some
+    /// reason, containing the response.
+    ///
+    /// Returns [`Error::ReqwestInvalid`] if the response was a non-OK (status
+    /// code 200) response, containing the response.
+    ///
+    /// Returns [`Error::ReqwestUnauthorized`] if the authorization token was
+    /// invalid.
+    ///
+    /// [`Error::ForeignUrl`]: ../enum.Error.html#variant.ForeignUrl
+    /// [`Error::Json`]: ../enum.Error.html#variant.Json
+    /// [`Error::ReqwestBad`]: ../enum.Error.html#variant.ReqwestBad
+    /// [`Error::ReqwestInvalid`]: ../enum.Error.html#variant.ReqwestInvalid
+    /// [`Error::ReqwestUnauthorized`]: ../enum.Error.html#variant.ReqwestUnauthorized
+    ///
+    /// [`API_URL`]: ../constant.API_URL.html
+    fn fetch<T: DeserializeOwned>(&self, url: &str) -> Result<Response<T>>
+        where Self: Sized;
+
+    /// Gets the anime which are currently trending on Kitsu.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// extern crate kitsu_io;
+    /// extern crate reqwest;
+    ///
+    /// use kitsu_io::KitsuReqwestRequester;
+    /// use reqwest::blocking::Client;
+    ///
+    /// fn main() {
+    ///     // Create the reqwest Client.
+    ///     let client = Client::new();
+    ///
+    ///     // Get the trending anime.
+    ///     let anime = client.get_trending_anime()
+    ///         .expect("Error getting trending anime");
+    ///
+    ///     // Do something with anime
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Json`] if there was an error parsing the response
+    /// body.
+    ///
+    /// Returns [`Error::ReqwestBad`] if the request was otherwise bad for some
+    /// reason, containing the response.
+    ///
+    /// Returns [`Error::ReqwestInvalid`] if the response was a non-OK (status
+    /// code 200) response, containing the response.
+    ///
+    /// Returns [`Error::ReqwestUnauthorized`] if the authorization token was
+    /// invalid.
+    ///
+    /// [`Error::Json`]: ../enum.Error.html#variant.Json
+    /// [`Error::ReqwestBad`]: ../enum.Error.html#variant.ReqwestBad
+    /// [`Error::ReqwestInvalid`]: ../enum.Error.html#variant.ReqwestInvalid
+    /// [`Error::ReqwestUnauthorized`]: ../enum.Error.html#variant.ReqwestUnauthorized
+    fn get_trending_anime(&self) -> Result<Response<Vec<Anime>>>;
+
+    /// Gets the manga which are currently trending on Kitsu.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// extern crate kitsu_io;
+    /// extern crate reqwest;
+    ///
+    /// use kitsu_io::KitsuReqwestRequester;
+    /// use reqwest::blocking::Client;
+    ///
+    /// fn main() {
+    ///     // Create the reqwest Client.
+    ///     let client = Client::new();
+    ///
+    ///     // Get the trending manga.
+    ///     let manga = client.get_trending_manga()
+    ///         .expect("Error getting trending manga");
+    ///
+    ///     // Do something with manga
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Json`] if there was an error parsing the response
+    /// body.
+    ///
+    /// Returns [`Error::ReqwestBad`] if the request was otherwise bad for some
+    /// reason, containing the response.
+    ///
+    /// Returns [`Error::ReqwestInvalid`] if the response was a non-OK (status
+    /// code 200) response, containing the response.
+    ///
+    /// Returns [`Error::ReqwestUnauthorized`] if the authorization token was
+    /// invalid.
+    ///
+    /// [`Error::Json`]: ../enum.Error.html#variant.Json
+    /// [`Error::ReqwestBad`]: ../enum.Error.html#variant.ReqwestBad
+    /// [`Error::ReqwestInvalid`]: ../enum.Error.html#variant.ReqwestInvalid
+    /// [`Error::ReqwestUnauthorized`]: ../enum.Error.html#variant.ReqwestUnauthorized
+    fn get_trending_manga(&self) -> Result<Response<Vec<Manga>>>;
+
+    /// Gets the reviews of an anime using the anime's id.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// extern crate kitsu_io;
+    /// extern crate reqwest;
+    ///
+    /// use kitsu_io::KitsuReqwestRequester;
+    /// use reqwest::blocking::Client;
+    ///
+    /// fn main() {
+    ///     // Create the reqwest Client.
+    ///     let client = Client::new();
+    ///
+    ///     let anime_id = 1;
+    ///
+    ///     // Get the anime's reviews.
+    ///     let reviews = client.get_anime_reviews(anime_id)
+    ///         .expect("Error getting reviews");
+    ///
+    ///     // Do something with reviews
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Json`] if there was an error parsing the response
+    /// body.
+    ///
+    /// Returns [`Error::ReqwestBad`] if the request was otherwise bad for some
+    /// reason, containing the response.
+    ///
+    /// Returns [`Error::ReqwestInvalid`] if the response was a non-OK (status
+    /// code 200) response, containing the response.
+    ///
+    /// Returns [`Error::ReqwestUnauthorized`] if the authorization token was
+    /// invalid.
+    ///
+    /// [`Error::Json`]: ../enum.Error.html#variant.Json
+    /// [`Error::ReqwestBad`]: ../enum.Error.html#variant.ReqwestBad
+    /// [`Error::ReqwestInvalid`]: ../enum.Error.html#variant.ReqwestInvalid
+    /// [`Error::ReqwestUnauthorized`]: ../enum.Error.html#variant.ReqwestUnauthorized
+    fn get_anime_reviews(&self, anime_id: u64) -> Result<Response<Vec<Review>>>;
+
+    /// Gets the entries in a user's library using the user's id.
+    ///
+    /// The search builder can be used to filter the entries, such as by the kind
+    /// of media or by status.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// extern crate kitsu_io;
+    /// extern crate reqwest;
+    ///
+    /// use kitsu_io::KitsuReqwestRequester;
+    /// use kitsu_io::model::LibraryEntryStatus;
+    /// use reqwest::blocking::Client;
+    ///
+    /// fn main() {
+    ///     // Create the reqwest Client.
+    ///     let client = Client::new();
+    ///
+    ///     let user_id = 1;
+    ///
+    ///     // Get the anime that the user is currently watching.
+    ///     let entries = client.get_user_library_entries(user_id, |f| {
+    ///         f.filter("kind", "anime").filter("status", LibraryEntryStatus::Current.name())
+    ///     }).expect("Error getting library entries");
+    ///
+    ///     // Do something with entries
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Json`] if there was an error parsing the response
+    /// body.
+    ///
+    /// Returns [`Error::ReqwestBad`] if the request was otherwise bad for some
+    /// reason, containing the response.
+    ///
+    /// Returns [`Error::ReqwestInvalid`] if the response was a non-OK (status
+    /// code 200) response, containing the response.
+    ///
+    /// Returns [`Error::ReqwestUnauthorized`] if the authorization token was
+    /// invalid.
+    ///
+    /// [`Error::Json`]: ../enum.Error.html#variant.Json
+    /// [`Error::ReqwestBad`]: ../enum.Error.html#variant.ReqwestBad
+    /// [`Error::ReqwestInvalid`]: ../enum.Error.html#variant.ReqwestInvalid
+    /// [`Error::ReqwestUnauthorized`]: ../enum.Error.html#variant.ReqwestUnauthorized
+    fn get_user_library_entries<F: FnOnce(Search) -> Search>(&self, user_id: u64, f: F)
+        -> Result<Response<Vec<LibraryEntry>>>
+        where Self: Sized;
+
+    /// Gets the castings of an anime using the anime's id.
+    ///
+    /// The characters of the castings are sideloaded into the response's
+    /// [`included`] resources.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// extern crate kitsu_io;
+    /// extern crate reqwest;
+    ///
+    /// use kitsu_io::KitsuReqwestRequester;
+    /// use reqwest::blocking::Client;
+    ///
+    /// fn main() {
+    ///     // Create the reqwest Client.
+    ///     let client = Client::new();
+    ///
+    ///     let anime_id = 1;
+    ///
+    ///     // Get the anime's castings.
+    ///     let castings = client.get_anime_castings(anime_id)
+    ///         .expect("Error getting castings");
+    ///
+    ///     // Do something with castings
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Json`] if there was an error parsing the response
+    /// body.
+    ///
+    /// Returns [`Error::ReqwestBad`] if the request was otherwise bad for some
+    /// reason, containing the response.
+    ///
+    /// Returns [`Error::ReqwestInvalid`] if the response was a non-OK (status
+    /// code 200) response, containing the response.
+    ///
+    /// Returns [`Error::ReqwestUnauthorized`] if the authorization token was
+    /// invalid.
+    ///
+    /// [`Error::Json`]: ../enum.Error.html#variant.Json
+    /// [`Error::ReqwestBad`]: ../enum.Error.html#variant.ReqwestBad
+    /// [`Error::ReqwestInvalid`]: ../enum.Error.html#variant.ReqwestInvalid
+    /// [`Error::ReqwestUnauthorized`]: ../enum.Error.html#variant.ReqwestUnauthorized
+    ///
+    /// [`included`]: ../../model/struct.Response.html#structfield.included
+    fn get_anime_castings(&self, anime_id: u64) -> Result<Response<Vec<Casting>>>;
+
+    /// Gets an anime using its slug, such as `attack-on-titan`.
+    ///
+    /// Returns `None` if there is no anime with the slug.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// extern crate kitsu_io;
+    /// extern crate reqwest;
+    ///
+    /// use kitsu_io::KitsuReqwestRequester;
+    /// use reqwest::blocking::Client;
+    ///
+    /// fn main() {
+    ///     // Create the reqwest Client.
+    ///     let client = Client::new();
+    ///
+    ///     let slug = "attack-on-titan";
+    ///
+    ///     // Get the anime.
+    ///     let anime = client.get_anime_by_slug(slug)
+    ///         .expect("Error getting anime");
+    ///
+    ///     // Do something with anime
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Json`] if there was an error parsing the response
+    /// body.
+    ///
+    /// Returns [`Error::ReqwestBad`] if the request was otherwise bad for some
+    /// reason, containing the response.
+    ///
+    /// Returns [`Error::ReqwestInvalid`] if the response was a non-OK (status
+    /// code 200) response, containing the response.
+    ///
+    /// Returns [`Error::ReqwestUnauthorized`] if the authorization token was
+    /// invalid.
+    ///
+    /// [`Error::Json`]: ../enum.Error.html#variant.Json
+    /// [`Error::ReqwestBad`]: ../enum.Error.html#variant.ReqwestBad
+    /// [`Error::ReqwestInvalid`]: ../enum.Error.html#variant.ReqwestInvalid
+    /// [`Error::ReqwestUnauthorized`]: ../enum.Error.html#variant.ReqwestUnauthorized
+    fn get_anime_by_slug(&self, slug: &str) -> Result<Option<Anime>>;
+
+    /// Gets a manga using its slug, such as `berserk`.
+    ///
+    /// Returns `None` if there is no manga with the slug.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// extern crate kitsu_io;
+    /// extern crate reqwest;
+    ///
+    /// use kitsu_io::KitsuReqwestRequester;
+    /// use reqwest::blocking::Client;
+    ///
+    /// fn main() {
+    ///     // Create the reqwest Client.
+    ///     let client = Client::new();
+    ///
+    ///     let slug = "berserk";
+    ///
+    ///     // Get the manga.
+    ///     let manga = client.get_manga_by_slug(slug)
+    ///         .expect("Error getting manga");
+    ///
+    ///     // Do something with manga
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Json`] if there was an error parsing the response
+    /// body.
+    ///
+    /// Returns [`Error::ReqwestBad`] if the request was otherwise bad for some
+    /// reason, containing the response.
+    ///
+    /// Returns [`Error::ReqwestInvalid`] if the response was a non-OK (status
+    /// code 200) response, containing the response.
+    ///
+    /// Returns [`Error::ReqwestUnauthorized`] if the authorization token was
+    /// invalid.
+    ///
+    /// [`Error::Json`]: ../enum.Error.html#variant.Json
+    /// [`Error::ReqwestBad`]: ../enum.Error.html#variant.ReqwestBad
+    /// [`Error::ReqwestInvalid`]: ../enum.Error.html#variant.ReqwestInvalid
+    /// [`Error::ReqwestUnauthorized`]: ../enum.Error.html#variant.ReqwestUnauthorized
+    fn get_manga_by_slug(&self, slug: &str) -> Result<Option<Manga>>;
+
+    /// Gets a person, such as a voice actor or a member of staff, using their
+    /// id.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// extern crate kitsu_io;
+    /// extern crate reqwest;
+    ///
+    /// use kitsu_io::KitsuReqwestRequester;
+    /// use reqwest::blocking::Client;
+    ///
+    /// fn main() {
+    ///     // Create the reqwest Client.
+    ///     let client = Client::new();
+    ///
+    ///     let person_id = 1;
+    ///
+    ///     // Get the person.
+    ///     let person = client.get_person(person_id).expect("Error getting person");
+    ///
+    ///     // Do something with person
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Json`] if there was an error parsing the response
+    /// body.
+    ///
+    /// Returns [`Error::ReqwestBad`] if the request was otherwise bad for some
+    /// reason, containing the response.
+    ///
+    /// Returns [`Error::ReqwestInvalid`] if the response was a non-OK (status
+    /// code 200) response, containing the response.
+    ///
+    /// Returns [`Error::ReqwestUnauthorized`] if the authorization token was
+    /// invalid.
+    ///
+    /// [`Error::Json`]: ../enum.Error.html#variant.Json
+    /// [`Error::ReqwestBad`]: ../enum.Error.html#variant.ReqwestBad
+    /// [`Error::ReqwestInvalid`]: ../enum.Error.html#variant.ReqwestInvalid
+    /// [`Error::ReqwestUnauthorized`]: ../enum.Error.html#variant.ReqwestUnauthorized
+    fn get_person(&self, id: u64) -> Result<Response<Person>>;
+
+    /// Gets a list of people using the search builder.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// extern crate kitsu_io;
+    /// extern crate reqwest;
+    ///
+    /// use kitsu_io::KitsuReqwestRequester;
+    /// use reqwest::blocking::Client;
+    ///
+    /// fn main() {
+    ///     // Create the reqwest Client.
+    ///     let client = Client::new();
+    ///
+    ///     let name = "Kouichi Yamadera";
+    ///
+    ///     // Search for the person.
+    ///     let people = client.search_people(|f| f.filter("name", name))
+    ///         .expect("Error searching for people");
+    ///
+    ///     // Do something with people
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Json`] if there was an error parsing the response
+    /// body.
+    ///
+    /// Returns [`Error::ReqwestBad`] if the request was otherwise bad for some
+    /// reason, containing the response.
+    ///
+    /// Returns [`Error::ReqwestInvalid`] if the response was a non-OK (status
+    /// code 200) response, containing the response.
+    ///
+    /// Returns [`Error::ReqwestUnauthorized`] if the authorization token was
+    /// invalid.
+    ///
+    /// [`Error::Json`]: ../enum.Error.html#variant.Json
+    /// [`Error::ReqwestBad`]: ../enum.Error.html#variant.ReqwestBad
+    /// [`Error::ReqwestInvalid`]: ../enum.Error.html#variant.ReqwestInvalid
+    /// [`Error::ReqwestUnauthorized`]: ../enum.Error.html#variant.ReqwestUnauthorized
+    fn search_people<F: FnOnce(Search) -> Search>(&self, f: F) -> Result<Response<Vec<Person>>>
+        where Self: Sized;
+
+    /// Gets an anime using its id, returning `None` if there is no anime with
+    /// the id.
+    ///
+    /// Unlike [`get_anime`], a not found response is not returned as an error.
+    ///
+    /// [`get_anime`]: #tymethod.get_anime
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// extern crate kitsu_io;
+    /// extern crate reqwest;
+    ///
+    /// use kitsu_io::KitsuReqwestRequester;
+    /// use reqwest::blocking::Client;
+    ///
+    /// fn main() {
+    ///     // Create the reqwest Client.
+    ///     let client = Client::new();
+    ///
+    ///     let anime_id = 1;
+    ///
+    ///     // Get the anime, if it exists.
+    ///     match client.try_get_anime(anime_id).expect("Error getting anime") {
+    ///         Some(anime) => println!("Found anime {}", anime.data.id),
+    ///         None => println!("No anime with id {}", anime_id),
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Json`] if there was an error parsing the response
+    /// body.
+    ///
+    /// Returns [`Error::ReqwestBad`] if the request was otherwise bad for some
+    /// reason, containing the response.
+    ///
+    /// Returns [`Error::ReqwestInvalid`] if the response was a non-OK (status
+    /// code 200) response, containing the response.
+    ///
+    /// Returns [`Error::ReqwestUnauthorized`] if the authorization token was
+    /// invalid.
+    ///
+    /// [`Error::Json`]: ../enum.Error.html#variant.Json
+    /// [`Error::ReqwestBad`]: ../enum.Error.html#variant.ReqwestBad
+    /// [`Error::ReqwestInvalid`]: ../enum.Error.html#variant.ReqwestInvalid
+    /// [`Error::ReqwestUnauthorized`]: ../enum.Error.html#variant.ReqwestUnauthorized
+    fn try_get_anime(&self, id: u64) -> Result<Option<Response<Anime>>>;
+
+    /// Gets a manga using its id, returning `None` if there is no manga with
+    /// the id.
+    ///
+    /// Unlike [`get_manga`], a not found response is not returned as an error.
+    ///
+    /// [`get_manga`]: #tymethod.get_manga
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// extern crate kitsu_io;
+    /// extern crate reqwest;
+    ///
+    /// use kitsu_io::KitsuReqwestRequester;
+    /// use reqwest::blocking::Client;
+    ///
+    /// fn main() {
+    ///     // Create the reqwest Client.
+    ///     let client = Client::new();
+    ///
+    ///     let manga_id = 1;
+    ///
+    ///     // Get the manga, if it exists.
+    ///     match client.try_get_manga(manga_id).expect("Error getting manga") {
+    ///         Some(manga) => println!("Found manga {}", manga.data.id),
+    ///         None => println!("No manga with id {}", manga_id),
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Json`] if there was an error parsing the response
+    /// body.
+    ///
+    /// Returns [`Error::ReqwestBad`] if the request was otherwise bad for some
+    /// reason, containing the response.
+    ///
+    /// Returns [`Error::ReqwestInvalid`] if the response was a non-OK (status
+    /// code 200) response, containing the response.
+    ///
+    /// Returns [`Error::ReqwestUnauthorized`] if the authorization token was
+    /// invalid.
+    ///
+    /// [`Error::Json`]: ../enum.Error.html#variant.Json
+    /// [`Error::ReqwestBad`]: ../enum.Error.html#variant.ReqwestBad
+    /// [`Error::ReqwestInvalid`]: ../enum.Error.html#variant.ReqwestInvalid
+    /// [`Error::ReqwestUnauthorized`]: ../enum.Error.html#variant.ReqwestUnauthorized
+    fn try_get_manga(&self, id: u64) -> Result<Option<Response<Manga>>>;
+
+    /// Gets a user using its id, returning `None` if there is no user with
+    /// the id.
+    ///
+    /// Unlike [`get_user`], a not found response is not returned as an error.
+    ///
+    /// [`get_user`]: #tymethod.get_user
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// extern crate kitsu_io;
+    /// extern crate reqwest;
+    ///
+    /// use kitsu_io::KitsuReqwestRequester;
+    /// use reqwest::blocking::Client;
+    ///
+    /// fn main() {
+    ///     // Create the reqwest Client.
+    ///     let client = Client::new();
+    ///
+    ///     let user_id = 1;
+    ///
+    ///     // Get the user, if it exists.
+    ///     match client.try_get_user(user_id).expect("Error getting user") {
+    ///         Some(user) => println!("Found user {}", user.data.id),
+    ///         None => println!("No user with id {}", user_id),
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Json`] if there was an error parsing the response
+    /// body.
+    ///
+    /// Returns [`Error::ReqwestBad`] if the request was otherwise bad for some
+    /// reason, containing the response.
+    ///
+    /// Returns [`Error::ReqwestInvalid`] if the response was a non-OK (status
+    /// code 200) response, containing the response.
+    ///
+    /// Returns [`Error::ReqwestUnauthorized`] if the authorization token was
+    /// invalid.
+    ///
+    /// [`Error::Json`]: ../enum.Error.html#variant.Json
+    /// [`Error::ReqwestBad`]: ../enum.Error.html#variant.ReqwestBad
+    /// [`Error::ReqwestInvalid`]: ../enum.Error.html#variant.ReqwestInvalid
+    /// [`Error::ReqwestUnauthorized`]: ../enum.Error.html#variant.ReqwestUnauthorized
+    fn try_get_user(&self, id: u64) -> Result<Option<Response<User>>>;
+
+    /// Gets the mappings of an anime to its ids on external sites, such as
+    /// MyAnimeList or AniList.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// extern crate kitsu_io;
+    /// extern crate reqwest;
+    ///
+    /// use kitsu_io::KitsuReqwestRequester;
+    /// use reqwest::blocking::Client;
+    ///
+    /// fn main() {
+    ///     // Create the reqwest Client.
+    ///     let client = Client::new();
+    ///
+    ///     let anime_id = 1;
+    ///
+    ///     // Get the anime's mappings.
+    ///     let mappings = client.get_anime_mappings(anime_id)
+    ///         .expect("Error getting mappings");
+    ///
+    ///     for mapping in mappings.data {
+    ///         let attributes = mapping.attributes;
+    ///
+    ///         println!("{}: {}", attributes.external_site.name(), attributes.external_id);
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Json`] if there was an error parsing the response
+    /// body.
+    ///
+    /// Returns [`Error::ReqwestBad`] if the request was otherwise bad for some
+    /// reason, containing the response.
+    ///
+    /// Returns [`Error::ReqwestInvalid`] if the response was a non-OK (status
+    /// code 200) response, containing the response.
+    ///
+    /// Returns [`Error::ReqwestUnauthorized`] if the authorization token was
+    /// invalid.
+    ///
+    /// [`Error::Json`]: ../enum.Error.html#variant.Json
+    /// [`Error::ReqwestBad`]: ../enum.Error.html#variant.ReqwestBad
+    /// [`Error::ReqwestInvalid`]: ../enum.Error.html#variant.ReqwestInvalid
+    /// [`Error::ReqwestUnauthorized`]: ../enum.Error.html#variant.ReqwestUnauthorized
+    fn get_anime_mappings(&self, anime_id: u64) -> Result<Response<Vec<Mapping>>>;
+
+    /// Searches for anime using a prebuilt [`Search`].
+    ///
+    /// This is the same as [`search_anime`], but is useful when the search is built
+    /// conditionally, such as in a loop.
+    ///
+    /// [`Search`]: ../../builder/struct.Search.html
+    /// [`search_anime`]: #tymethod.search_anime
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// extern crate kitsu_io;
+    /// extern crate reqwest;
+    ///
+    /// use kitsu_io::KitsuReqwestRequester;
+    /// use kitsu_io::builder::Search;
+    /// use reqwest::blocking::Client;
+    ///
+    /// fn main() {
+    ///     // Create the reqwest Client.
+    ///     let client = Client::new();
+    ///
+    ///     let genre = Some("adventure");
+    ///
+    ///     // Build the search, only filtering by genre if one was selected.
+    ///     let mut search = Search::default().filter("text", "Cowboy Bebop");
+    ///
+    ///     if let Some(genre) = genre {
+    ///         search = search.filter("genres", genre);
+    ///     }
+    ///
+    ///     let anime = client.search_anime_with(search)
+    ///         .expect("Error searching for anime");
+    ///
+    ///     // Do something with anime
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Json`] if there was an error parsing the response
+    /// body.
+    ///
+    /// Returns [`Error::ReqwestBad`] if the request was otherwise bad for some
+    /// reason, containing the response.
+    ///
+    /// Returns [`Error::ReqwestInvalid`] if the response was a non-OK (status
+    /// code 200) response, containing the response.
+    ///
+    /// Returns [`Error::ReqwestUnauthorized`] if the authorization token was
+    /// invalid.
+    ///
+    /// [`Error::Json`]: ../enum.Error.html#variant.Json
+    /// [`Error::ReqwestBad`]: ../enum.Error.html#variant.ReqwestBad
+    /// [`Error::ReqwestInvalid`]: ../enum.Error.html#variant.ReqwestInvalid
+    /// [`Error::ReqwestUnauthorized`]: ../enum.Error.html#variant.ReqwestUnauthorized
+    fn search_anime_with(&self, search: Search) -> Result<Response<Vec<Anime>>>;
+
+    /// Searches for manga using a prebuilt [`Search`].
+    ///
+    /// This is the same as [`search_manga`], but is useful when the search is built
+    /// conditionally, such as in a loop.
+    ///
+    /// [`Search`]: ../../builder/struct.Search.html
+    /// [`search_manga`]: #tymethod.search_manga
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// extern crate kitsu_io;
+    /// extern crate reqwest;
+    ///
+    /// use kitsu_io::KitsuReqwestRequester;
+    /// use kitsu_io::builder::Search;
+    /// use reqwest::blocking::Client;
+    ///
+    /// fn main() {
+    ///     // Create the reqwest Client.
+    ///     let client = Client::new();
+    ///
+    ///     let search = Search::default().filter("text", "Monster");
+    ///
+    ///     let manga = client.search_manga_with(search)
+    ///         .expect("Error searching for manga");
+    ///
+    ///     // Do something with manga
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Json`] if there was an error parsing the response
+    /// body.
+    ///
+    /// Returns [`Error::ReqwestBad`] if the request was otherwise bad for some
+    /// reason, containing the response.
+    ///
+    /// Returns [`Error::ReqwestInvalid`] if the response was a non-OK (status
+    /// code 200) response, containing the response.
+    ///
+    /// Returns [`Error::ReqwestUnauthorized`] if the authorization token was
+    /// invalid.
+    ///
+    /// [`Error::Json`]: ../enum.Error.html#variant.Json
+    /// [`Error::ReqwestBad`]: ../enum.Error.html#variant.ReqwestBad
+    /// [`Error::ReqwestInvalid`]: ../enum.Error.html#variant.ReqwestInvalid
+    /// [`Error::ReqwestUnauthorized`]: ../enum.Error.html#variant.ReqwestUnauthorized
+    fn search_manga_with(&self, search: Search) -> Result<Response<Vec<Manga>>>;
+
+    /// Searches for users using a prebuilt [`Search`].
+    ///
+    /// This is the same as [`search_users`], but is useful when the search is built
+    /// conditionally, such as in a loop.
+    ///
+    /// [`Search`]: ../../builder/struct.Search.html
+    /// [`search_users`]: #tymethod.search_users
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// extern crate kitsu_io;
+    /// extern crate reqwest;
+    ///
+    /// use kitsu_io::KitsuReqwestRequester;
+    /// use kitsu_io::builder::Search;
+    /// use reqwest::blocking::Client;
+    ///
+    /// fn main() {
+    ///     // Create the reqwest Client.
+    ///     let client = Client::new();
+    ///
+    ///     let search = Search::default().filter("name", "vikhyat");
+    ///
+    ///     let users = client.search_users_with(search)
+    ///         .expect("Error searching for users");
+    ///
+    ///     // Do something with users
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Json`] if there was an error parsing the response
+    /// body.
+    ///
+    /// Returns [`Error::ReqwestBad`] if the request was otherwise bad for some
+    /// reason, containing the response.
+    ///
+    /// Returns [`Error::ReqwestInvalid`] if the response was a non-OK (status
+    /// code 200) response, containing the response.
+    ///
+    /// Returns [`Error::ReqwestUnauthorized`] if the authorization token was
+    /// invalid.
+    ///
+    /// [`Error::Json`]: ../enum.Error.html#variant.Json
+    /// [`Error::ReqwestBad`]: ../enum.Error.html#variant.ReqwestBad
+    /// [`Error::ReqwestInvalid`]: ../enum.Error.html#variant.ReqwestInvalid
+    /// [`Error::ReqwestUnauthorized`]: ../enum.Error.html#variant.ReqwestUnauthorized
+    fn search_users_with(&self, search: Search) -> Result<Response<Vec<User>>>;
+
+    /// Gets the relationships of an anime to its related media, such as its
+    /// sequels, prequels, and adaptations, using the anime's id.
+    ///
+    /// The related anime and manga are sideloaded into the response's
+    /// [`included`] resources.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// extern crate kitsu_io;
+    /// extern crate reqwest;
+    ///
+    /// use kitsu_io::KitsuReqwestRequester;
+    /// use reqwest::blocking::Client;
+    ///
+    /// fn main() {
+    ///     // Create the reqwest Client.
+    ///     let client = Client::new();
+    ///
+    ///     let anime_id = 1;
+    ///
+    ///     // Get the anime's related media.
+    ///     let relationships = client.get_anime_media_relationships(anime_id)
+    ///         .expect("Error getting media relationships");
+    ///
+    ///     for relationship in relationships.data {
+    ///         println!("{}", relationship.attributes.role.name());
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Json`] if there was an error parsing the response
+    /// body.
+    ///
+    /// Returns [`Error::ReqwestBad`] if the request was otherwise bad for some
+    /// reason, containing the response.
+    ///
+    /// Returns [`Error::ReqwestInvalid`] if the response was a non-OK (status
+    /// code 200) response, containing the response.
+    ///
+    /// Returns [`Error::ReqwestUnauthorized`] if the authorization token was
+    /// invalid.
+    ///
+    /// [`Error::Json`]: ../enum.Error.html#variant.Json
+    /// [`Error::ReqwestBad`]: ../enum.Error.html#variant.ReqwestBad
+    /// [`Error::ReqwestInvalid`]: ../enum.Error.html#variant.ReqwestInvalid
+    /// [`Error::ReqwestUnauthorized`]: ../enum.Error.html#variant.ReqwestUnauthorized
+    ///
+    /// [`included`]: ../../model/struct.Response.html#structfield.included
+    fn get_anime_media_relationships(&self, anime_id: u64)
+        -> Result<Response<Vec<MediaRelationship>>>;
+
+    /// Gets an anime using its id, retrying the request according to a
+    /// [`RetryPolicy`] if it fails due to being rate limited or a server error.
+    ///
+    /// Other errors, such as the anime not being found, are returned without
+    /// retrying.
+    ///
+    /// [`RetryPolicy`]: struct.RetryPolicy.html
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// extern crate kitsu_io;
+    /// extern crate reqwest;
+    ///
+    /// use kitsu_io::KitsuReqwestRequester;
+    /// use kitsu_io::bridge::reqwest::RetryPolicy;
+    /// use reqwest::blocking::Client;
+    /// use std::time::Duration;
+    ///
+    /// fn main() {
+    ///     // Create the reqwest Client.
+    ///     let client = Client::new();
+    ///
+    ///     let anime_id = 1;
+    ///
+    ///     // Make up to 5 attempts, waiting 1 second before the first retry.
+    ///     let policy = RetryPolicy::new(5, Duration::from_secs(1));
+    ///
+    ///     // Get the anime.
+    ///     let anime = client.get_anime_retrying(anime_id, policy)
+    ///         .expect("Error getting anime");
+    ///
+    ///     // Do something with anime
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns the error of the last attempt if every attempt failed.
+    ///
+    /// Returns [`Error::Json`] if there was an error parsing the response
+    /// body.
+    ///
+    /// Returns [`Error::ReqwestBad`] if the request was otherwise bad for some
+    /// reason, containing the response.
+    ///
+    /// Returns [`Error::ReqwestInvalid`] if the response was a non-OK (status
+    /// code 200) response, containing the response.
+    ///
+    /// Returns [`Error::ReqwestUnauthorized`] if the authorization token was
+    /// invalid.
+    ///
+    /// [`Error::Json`]: ../enum.Error.html#variant.Json
+    /// [`Error::ReqwestBad`]: ../enum.Error.html#variant.ReqwestBad
+    /// [`Error::ReqwestInvalid`]: ../enum.Error.html#variant.ReqwestInvalid
+    /// [`Error::ReqwestUnauthorized`]: ../enum.Error.html#variant.ReqwestUnauthorized
+    fn get_anime_retrying(&self, id: u64, policy: RetryPolicy) -> Result<Response<Anime>>;
+
+    /// Gets the installments of an anime in its franchises using the anime's id.
+    ///
+    /// The franchises of the installments are sideloaded into the response's
+    /// [`included`] resources.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// extern crate kitsu_io;
+    /// extern crate reqwest;
+    ///
+    /// use kitsu_io::KitsuReqwestRequester;
+    /// use kitsu_io::model::Included;
+    /// use reqwest::blocking::Client;
+    ///
+    /// fn main() {
+    ///     // Create the reqwest Client.
+    ///     let client = Client::new();
+    ///
+    ///     let anime_id = 1;
+    ///
+    ///     // Get the anime's installments.
+    ///     let installments = client.get_anime_installments(anime_id)
+    ///         .expect("Error getting installments");
+    ///
+    ///     for included in installments.included {
+    ///         if let Included::Franchise(franchise) = included {
+    ///             println!("{}", franchise.attributes.canonical_title);
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Json`] if there was an error parsing the response
+    /// body.
+    ///
+    /// Returns [`Error::ReqwestBad`] if the request was otherwise bad for some
+    /// reason, containing the response.
+    ///
+    /// Returns [`Error::ReqwestInvalid`] if the response was a non-OK (status
+    /// code 200) response, containing the response.
+    ///
+    /// Returns [`Error::ReqwestUnauthorized`] if the authorization token was
+    /// invalid.
+    ///
+    /// [`Error::Json`]: ../enum.Error.html#variant.Json
+    /// [`Error::ReqwestBad`]: ../enum.Error.html#variant.ReqwestBad
+    /// [`Error::ReqwestInvalid`]: ../enum.Error.html#variant.ReqwestInvalid
+    /// [`Error::ReqwestUnauthorized`]: ../enum.Error.html#variant.ReqwestUnauthorized
+    ///
+    /// [`included`]: ../../model/struct.Response.html#structfield.included
+    fn get_anime_installments(&self, anime_id: u64) -> Result<Response<Vec<Installment>>>;
+
+    /// Gets the production credits of an anime, such as its studios and
+    /// licensors, using the anime's id.
+    ///
+    /// The producers of the productions are sideloaded into the response's
+    /// [`included`] resources.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// extern crate kitsu_io;
+    /// extern crate reqwest;
+    ///
+    /// use kitsu_io::KitsuReqwestRequester;
+    /// use kitsu_io::model::Included;
+    /// use reqwest::blocking::Client;
+    ///
+    /// fn main() {
+    ///     // Create the reqwest Client.
+    ///     let client = Client::new();
+    ///
+    ///     let anime_id = 1;
+    ///
+    ///     // Get the anime's productions.
+    ///     let productions = client.get_anime_productions(anime_id)
+    ///         .expect("Error getting productions");
+    ///
+    ///     for included in productions.included {
+    ///         if let Included::Producer(producer) = included {
+    ///             println!("{}", producer.attributes.name);
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Json`] if there was an error parsing the response
+    /// body.
+    ///
+    /// Returns [`Error::ReqwestBad`] if the request was otherwise bad for some
+    /// reason, containing the response.
+    ///
+    /// Returns [`Error::ReqwestInvalid`] if the response was a non-OK (status
+    /// code 200) response, containing the response.
+    ///
+    /// Returns [`Error::ReqwestUnauthorized`] if the authorization token was
+    /// invalid.
+    ///
+    /// [`Error::Json`]: ../enum.Error.html#variant.Json
+    /// [`Error::ReqwestBad`]: ../enum.Error.html#variant.ReqwestBad
+    /// [`Error::ReqwestInvalid`]: ../enum.Error.html#variant.ReqwestInvalid
+    /// [`Error::ReqwestUnauthorized`]: ../enum.Error.html#variant.ReqwestUnauthorized
+    ///
+    /// [`included`]: ../../model/struct.Response.html#structfield.included
+    fn get_anime_productions(&self, anime_id: u64) -> Result<Response<Vec<AnimeProduction>>>;
+
+    /// Gets the short reactions of users to an anime using the anime's id.
+    ///
+    /// This is a lighter alternative to [`get_anime_reviews`].
+    ///
+    /// [`get_anime_reviews`]: #tymethod.get_anime_reviews
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// extern crate kitsu_io;
+    /// extern crate reqwest;
+    ///
+    /// use kitsu_io::KitsuReqwestRequester;
+    /// use reqwest::blocking::Client;
+    ///
+    /// fn main() {
+    ///     // Create the reqwest Client.
+    ///     let client = Client::new();
+    ///
+    ///     let anime_id = 1;
+    ///
+    ///     // Get the anime's reactions.
+    ///     let reactions = client.get_anime_reactions(anime_id)
+    ///         .expect("Error getting reactions");
+    ///
+    ///     for reaction in reactions.data {
+    ///         println!("{}", reaction.attributes.reaction);
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Json`] if there was an error parsing the response
+    /// body.
+    ///
+    /// Returns [`Error::ReqwestBad`] if the request was otherwise bad for some
+    /// reason, containing the response.
+    ///
+    /// Returns [`Error::ReqwestInvalid`] if the response was a non-OK (status
+    /// code 200) response, containing the response.
+    ///
+    /// Returns [`Error::ReqwestUnauthorized`] if the authorization token was
+    /// invalid.
+    ///
+    /// [`Error::Json`]: ../enum.Error.html#variant.Json
+    /// [`Error::ReqwestBad`]: ../enum.Error.html#variant.ReqwestBad
+    /// [`Error::ReqwestInvalid`]: ../enum.Error.html#variant.ReqwestInvalid
+    /// [`Error::ReqwestUnauthorized`]: ../enum.Error.html#variant.ReqwestUnauthorized
+    fn get_anime_reactions(&self, anime_id: u64) -> Result<Response<Vec<MediaReaction>>>;
+
+    /// Gets a group using its id.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// extern crate kitsu_io;
+    /// extern crate reqwest;
+    ///
+    /// use kitsu_io::KitsuReqwestRequester;
+    /// use reqwest::blocking::Client;
+    ///
+    /// fn main() {
+    ///     // Create the reqwest Client.
+    ///     let client = Client::new();
+    ///
+    ///     let group_id = 1;
+    ///
+    ///     // Get the group.
+    ///     let group = client.get_group(group_id)
+    ///         .expect("Error getting group");
+    ///
+    ///     // Do something with group
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Json`] if there was an error parsing the response
+    /// body.
+    ///
+    /// Returns [`Error::ReqwestBad`] if the request was otherwise bad for some
+    /// reason, containing the response.
+    ///
+    /// Returns [`Error::ReqwestInvalid`] if the response was a non-OK (status
+    /// code 200) response, containing the response.
+    ///
+    /// Returns [`Error::ReqwestUnauthorized`] if the authorization token was
+    /// invalid.
+    ///
+    /// [`Error::Json`]: ../enum.Error.html#variant.Json
+    /// [`Error::ReqwestBad`]: ../enum.Error.html#variant.ReqwestBad
+    /// [`Error::ReqwestInvalid`]: ../enum.Error.html#variant.ReqwestInvalid
+    /// [`Error::ReqwestUnauthorized`]: ../enum.Error.html#variant.ReqwestUnauthorized
+    fn get_group(&self, id: u64) -> Result<Response<Group>>;
+
+    /// Searches for groups using the passed [Search] builder.
+    ///
+    /// [Search]: ../../builder/struct.Search.html
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// extern crate kitsu_io;
+    /// extern crate reqwest;
+    ///
+    /// use kitsu_io::KitsuReqwestRequester;
+    /// use reqwest::blocking::Client;
+    ///
+    /// fn main() {
+    ///     // Create the reqwest Client.
+    ///     let client = Client::new();
+    ///
+    ///     let groups = client.search_groups(|f| f.filter("query", "mecha"))
+    ///         .expect("Error searching for groups");
+    ///
+    ///     // Do something with groups
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Json`] if there was an error parsing the response
+    /// body.
+    ///
+    /// Returns [`Error::ReqwestBad`] if the request was otherwise bad for some
+    /// reason, containing the response.
+    ///
+    /// Returns [`Error::ReqwestInvalid`] if the response was a non-OK (status
+    /// code 200) response, containing the response.
+    ///
+    /// Returns [`Error::ReqwestUnauthorized`] if the authorization token was
+    /// invalid.
+    ///
+    /// [`Error::Json`]: ../enum.Error.html#variant.Json
+    /// [`Error::ReqwestBad`]: ../enum.Error.html#variant.ReqwestBad
+    /// [`Error::ReqwestInvalid`]: ../enum.Error.html#variant.ReqwestInvalid
+    /// [`Error::ReqwestUnauthorized`]: ../enum.Error.html#variant.ReqwestUnauthorized
+    fn search_groups<F: FnOnce(Search) -> Search>(&self, f: F) -> Result<Response<Vec<Group>>>
+        where Self: Sized;
+
+    /// Searches for groups using a prepared [Search] builder.
+    ///
+    /// This is the same as [`search_groups`], but takes the search by value.
+    ///
+    /// [Search]: ../../builder/struct.Search.html
+    /// [`search_groups`]: #tymethod.search_groups
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// extern crate kitsu_io;
+    /// extern crate reqwest;
+    ///
+    /// use kitsu_io::KitsuReqwestRequester;
+    /// use kitsu_io::builder::Search;
+    /// use reqwest::blocking::Client;
+    ///
+    /// fn main() {
+    ///     // Create the reqwest Client.
+    ///     let client = Client::new();
+    ///
+    ///     let search = Search::default().filter("query", "mecha");
+    ///
+    ///     let groups = client.search_groups_with(search)
+    ///         .expect("Error searching for groups");
+    ///
+    ///     // Do something with groups
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Json`] if there was an error parsing the response
+    /// body.
+    ///
+    /// Returns [`Error::ReqwestBad`] if the request was otherwise bad for some
+    /// reason, containing the response.
+    ///
+    /// Returns [`Error::ReqwestInvalid`] if the response was a non-OK (status
+    /// code 200) response, containing the response.
+    ///
+    /// Returns [`Error::ReqwestUnauthorized`] if the authorization token was
+    /// invalid.
+    ///
+    /// [`Error::Json`]: ../enum.Error.html#variant.Json
+    /// [`Error::ReqwestBad`]: ../enum.Error.html#variant.ReqwestBad
+    /// [`Error::ReqwestInvalid`]: ../enum.Error.html#variant.ReqwestInvalid
+    /// [`Error::ReqwestUnauthorized`]: ../enum.Error.html#variant.ReqwestUnauthorized
+    fn search_groups_with(&self, search: Search) -> Result<Response<Vec<Group>>>;
+
+    /// Gets the waifu or husbando character of a user using the user's id, by
+    /// following the user's [`waifu`] relationship.
+    ///
+    /// The `data` of the response is `None` if the user has not chosen a waifu or
+    /// husbando. Whether the character is a waifu or husbando is given by the
+    /// user's [`waifu_or_husbando`].
+    ///
+    /// [`waifu`]: ../../model/struct.UserRelationships.html#structfield.waifu
+    /// [`waifu_or_husbando`]: ../../model/struct.UserAttributes.html#structfield.waifu_or_husbando
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// extern crate kitsu_io;
+    /// extern crate reqwest;
+    ///
+    /// use kitsu_io::KitsuReqwestRequester;
+    /// use reqwest::blocking::Client;
+    ///
+    /// fn main() {
+    ///     // Create the reqwest Client.
+    ///     let client = Client::new();
+    ///
+    ///     let user_id = 1;
+    ///
+    ///     // Get the user's waifu or husbando.
+    ///     let waifu = client.get_user_waifu(user_id)
+    ///         .expect("Error getting waifu");
+    ///
+    ///     match waifu.data {
+    ///         Some(character) => println!("{}", character.attributes.name),
+    ///         None => println!("The user has no waifu or husbando"),
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Json`] if there was an error parsing the response
+    /// body.
+    ///
+    /// Returns [`Error::ReqwestBad`] if the request was otherwise bad for some
+    /// reason, containing the response.
+    ///
+    /// Returns [`Error::ReqwestInvalid`] if the response was a non-OK (status
+    /// code 200) response, containing the response.
+    ///
+    /// Returns [`Error::ReqwestUnauthorized`] if the authorization token was
+    /// invalid.
+    ///
+    /// [`Error::Json`]: ../enum.Error.html#variant.Json
+    /// [`Error::ReqwestBad`]: ../enum.Error.html#variant.ReqwestBad
+    /// [`Error::ReqwestInvalid`]: ../enum.Error.html#variant.ReqwestInvalid
+    /// [`Error::ReqwestUnauthorized`]: ../enum.Error.html#variant.ReqwestUnauthorized
+    fn get_user_waifu(&self, user_id: u64) -> Result<Response<Option<Character>>>;
+
+    /// Gets the events in a user's library using the user's id, such as when
+    /// they progressed through a media item or changed its status.
+    ///
+    /// This is a feed of changes to the user's [library entries], which can be
+    /// paginated using the search builder.
+    ///
+    /// [library entries]: #tymethod.get_user_library_entries
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// extern crate kitsu_io;
+    /// extern crate reqwest;
+    ///
+    /// use kitsu_io::KitsuReqwestRequester;
+    /// use reqwest::blocking::Client;
+    ///
+    /// fn main() {
+    ///     // Create the reqwest Client.
+    ///     let client = Client::new();
+    ///
+    ///     let user_id = 1;
+    ///
+    ///     // Get the user's most recent library events.
+    ///     let events = client.get_user_library_events(user_id, |f| {
+    ///         f.sort("-createdAt").limit(10)
+    ///     }).expect("Error getting library events");
+    ///
+    ///     for event in events.data {
+    ///         println!("{}", event.attributes.kind.name());
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Json`] if there was an error parsing the response
+    /// body.
+    ///
+    /// Returns [`Error::ReqwestBad`] if the request was otherwise bad for some
+    /// reason, containing the response.
+    ///
+    /// Returns [`Error::ReqwestInvalid`] if the response was a non-OK (status
+    /// code 200) response, containing the response.
+    ///
+    /// Returns [`Error::ReqwestUnauthorized`] if the authorization token was
+    /// invalid.
+    ///
+    /// [`Error::Json`]: ../enum.Error.html#variant.Json
+    /// [`Error::ReqwestBad`]: ../enum.Error.html#variant.ReqwestBad
+    /// [`Error::ReqwestInvalid`]: ../enum.Error.html#variant.ReqwestInvalid
+    /// [`Error::ReqwestUnauthorized`]: ../enum.Error.html#variant.ReqwestUnauthorized
+    fn get_user_library_events<F: FnOnce(Search) -> Search>(&self, user_id: u64, f: F)
+        -> Result<Response<Vec<LibraryEvent>>>
+        where Self: Sized;
+
+    /// Gets multiple anime using their ids, such as those in a user's library.
+    ///
+    /// Rather than a request per anime, this filters by the ids, making a
+    /// request for each page of up to 20 ids, as Kitsu caps the size of a
+    /// page. The results of each request are merged into one response, whose
+    /// `links` are empty rather than those of any one request.
+    ///
+    /// Repeated ids are only requested once, and the anime are in the order
+    /// that their ids were first given. Ids which do not belong to an anime
+    /// are skipped.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// extern crate kitsu_io;
+    /// extern crate reqwest;
+    ///
+    /// use kitsu_io::KitsuReqwestRequester;
+    /// use reqwest::blocking::Client;
+    ///
+    /// fn main() {
+    ///     // Create the reqwest Client.
+    ///     let client = Client::new();
+    ///
+    ///     let anime_ids = [1, 6, 11];
+    ///
+    ///     // Get all of the anime at once.
+    ///     let anime = client.get_anime_many(&anime_ids)
+    ///         .expect("Error getting anime");
+    ///
+    ///     for anime in anime.data {
+    ///         println!("{}", anime.attributes.canonical_title);
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Json`] if there was an error parsing the response
+    /// body.
+    ///
+    /// Returns [`Error::ReqwestBad`] if the request was otherwise bad for some
+    /// reason, containing the response.
+    ///
+    /// Returns [`Error::ReqwestInvalid`] if the response was a non-OK (status
+    /// code 200) response, containing the response.
+    ///
+    /// Returns [`Error::ReqwestUnauthorized`] if the authorization token was
+    /// invalid.
+    ///
+    /// [`Error::Json`]: ../enum.Error.html#variant.Json
+    /// [`Error::ReqwestBad`]: ../enum.Error.html#variant.ReqwestBad
+    /// [`Error::ReqwestInvalid`]: ../enum.Error.html#variant.ReqwestInvalid
+    /// [`Error::ReqwestUnauthorized`]: ../enum.Error.html#variant.ReqwestUnauthorized
+    fn get_anime_many(&self, ids: &[u64]) -> Result<Response<Vec<Anime>>>;
+
+    /// Gets the users following a user using the user's id.
+    ///
+    /// The followers are paginated using the search builder, and can be
+    /// iterated over across every page using [`get_user_followers_all`].
+    ///
+    /// [`get_user_followers_all`]: #tymethod.get_user_followers_all
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// extern crate kitsu_io;
+    /// extern crate reqwest;
+    ///
+    /// use kitsu_io::KitsuReqwestRequester;
+    /// use reqwest::blocking::Client;
+    ///
+    /// fn main() {
+    ///     // Create the reqwest Client.
+    ///     let client = Client::new();
+    ///
+    ///     let user_id = 1;
+    ///
+    ///     // Get the first page of the user's followers.
+    ///     let users = client.get_user_followers(user_id, |f| f.limit(20))
+    ///         .expect("Error getting users");
+    ///
+    ///     for user in users.data {
+    ///         println!("{}", user.attributes.name);
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Json`] if there was an error parsing the response
+    /// body.
+    ///
+    /// Returns [`Error::ReqwestBad`] if the request was otherwise bad for some
+    /// reason, containing the response.
+    ///
+    /// Returns [`Error::ReqwestInvalid`] if the response was a non-OK (status
+    /// code 200) response, containing the response.
+    ///
+    /// Returns [`Error::ReqwestUnauthorized`] if the authorization token was
+    /// invalid.
+    ///
+    /// [`Error::Json`]: ../enum.Error.html#variant.Json
+    /// [`Error::ReqwestBad`]: ../enum.Error.html#variant.ReqwestBad
+    /// [`Error::ReqwestInvalid`]: ../enum.Error.html#variant.ReqwestInvalid
+    /// [`Error::ReqwestUnauthorized`]: ../enum.Error.html#variant.ReqwestUnauthorized
+    fn get_user_followers<F: FnOnce(Search) -> Search>(&self, user_id: u64, f: F)
+        -> Result<Response<Vec<User>>>
+        where Self: Sized;
+
+    /// Gets the users which a user is following using the user's id.
+    ///
+    /// The users are paginated using the search builder, and can be iterated
+    /// over across every page using [`get_user_following_all`].
+    ///
+    /// [`get_user_following_all`]: #tymethod.get_user_following_all
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// extern crate kitsu_io;
+    /// extern crate reqwest;
+    ///
+    /// use kitsu_io::KitsuReqwestRequester;
+    /// use reqwest::blocking::Client;
+    ///
+    /// fn main() {
+    ///     // Create the reqwest Client.
+    ///     let client = Client::new();
+    ///
+    ///     let user_id = 1;
+    ///
+    ///     // Get the first page of the users the user is following.
+    ///     let users = client.get_user_following(user_id, |f| f.limit(20))
+    ///         .expect("Error getting users");
+    ///
+    ///     for user in users.data {
+    ///         println!("{}", user.attributes.name);
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Json`] if there was an error parsing the response
+    /// body.
+    ///
+    /// Returns [`Error::ReqwestBad`] if the request was otherwise bad for some
+    /// reason, containing the response.
+    ///
+    /// Returns [`Error::ReqwestInvalid`] if the response was a non-OK (status
+    /// code 200) response, containing the response.
+    ///
+    /// Returns [`Error::ReqwestUnauthorized`] if the authorization token was
+    /// invalid.
+    ///
+    /// [`Error::Json`]: ../enum.Error.html#variant.Json
+    /// [`Error::ReqwestBad`]: ../enum.Error.html#variant.ReqwestBad
+    /// [`Error::ReqwestInvalid`]: ../enum.Error.html#variant.ReqwestInvalid
+    /// [`Error::ReqwestUnauthorized`]: ../enum.Error.html#variant.ReqwestUnauthorized
+    fn get_user_following<F: FnOnce(Search) -> Search>(&self, user_id: u64, f: F)
+        -> Result<Response<Vec<User>>>
+        where Self: Sized;
+
+    /// Gets the users following a user using the user's id, returning an
+    /// iterator over the followers of every page.
+    ///
+    /// Pages are fetched lazily by following the `next` link of each response,
+    /// as with [`search_anime_all`]. Iteration stops after the first error,
+    /// which is yielded.
+    ///
+    /// [`search_anime_all`]: #tymethod.search_anime_all
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// extern crate kitsu_io;
+    /// extern crate reqwest;
+    ///
+    /// use kitsu_io::KitsuReqwestRequester;
+    /// use reqwest::blocking::Client;
+    ///
+    /// fn main() {
+    ///     // Create the reqwest Client.
+    ///     let client = Client::new();
+    ///
+    ///     let user_id = 1;
+    ///
+    ///     // Iterate over every follower of the user.
+    ///     for user in client.get_user_followers_all(user_id, |f| f) {
+    ///         let user = user.expect("Error getting users");
+    ///
+    ///         println!("{}", user.attributes.name);
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Json`] if there was an error parsing the response
+    /// body.
+    ///
+    /// Returns [`Error::ReqwestBad`] if the request was otherwise bad for some
+    /// reason, containing the response.
+    ///
+    /// Returns [`Error::ReqwestInvalid`] if the response was a non-OK (status
+    /// code 200) response, containing the response.
+    ///
+    /// Returns [`Error::ReqwestUnauthorized`] if the authorization token was
+    /// invalid.
+    ///
+    /// [`Error::Json`]: ../enum.Error.html#variant.Json
+    /// [`Error::ReqwestBad`]: ../enum.Error.html#variant.ReqwestBad
+    /// [`Error::ReqwestInvalid`]: ../enum.Error.html#variant.ReqwestInvalid
+    /// [`Error::ReqwestUnauthorized`]: ../enum.Error.html#variant.ReqwestUnauthorized
+    fn get_user_followers_all<F: FnOnce(Search) -> Search>(&self, user_id: u64, f: F)
+        -> Paginated<'_, User>
+        where Self: Sized;
+
+    /// Gets the users which a user is following using the user's id, returning
+    /// an iterator over the users of every page.
+    ///
+    /// Pages are fetched lazily by following the `next` link of each response,
+    /// as with [`search_anime_all`]. Iteration stops after the first error,
+    /// which is yielded.
+    ///
+    /// [`search_anime_all`]: #tymethod.search_anime_all
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// extern crate kitsu_io;
+    /// extern crate reqwest;
+    ///
+    /// use kitsu_io::KitsuReqwestRequester;
+    /// use reqwest::blocking::Client;
+    ///
+    /// fn main() {
+    ///     // Create the reqwest Client.
+    ///     let client = Client::new();
+    ///
+    ///     let user_id = 1;
+    ///
+    ///     // Iterate over every user the user is following.
+    ///     for user in client.get_user_following_all(user_id, |f| f) {
+    ///         let user = user.expect("Error getting users");
+    ///
+    ///         println!("{}", user.attributes.name);
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Json`] if there was an error parsing the response
+    /// body.
+    ///
+    /// Returns [`Error::ReqwestBad`] if the request was otherwise bad for some
+    /// reason, containing the response.
+    ///
+    /// Returns [`Error::ReqwestInvalid`] if the response was a non-OK (status
+    /// code 200) response, containing the response.
+    ///
+    /// Returns [`Error::ReqwestUnauthorized`] if the authorization token was
+    /// invalid.
+    ///
+    /// [`Error::Json`]: ../enum.Error.html#variant.Json
+    /// [`Error::ReqwestBad`]: ../enum.Error.html#variant.ReqwestBad
+    /// [`Error::ReqwestInvalid`]: ../enum.Error.html#variant.ReqwestInvalid
+    /// [`Error::ReqwestUnauthorized`]: ../enum.Error.html#variant.ReqwestUnauthorized
+    fn get_user_following_all<F: FnOnce(Search) -> Search>(&self, user_id: u64, f: F)
+        -> Paginated<'_, User>
+        where Self: Sized;
+
+    /// Gets the posts made by a user using the user's id, such as to mirror
+    /// their timeline.
+    ///
+    /// The posts can be sorted and paginated using the search builder.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// extern crate kitsu_io;
+    /// extern crate reqwest;
+    ///
+    /// use kitsu_io::KitsuReqwestRequester;
+    /// use reqwest::blocking::Client;
+    ///
+    /// fn main() {
+    ///     // Create the reqwest Client.
+    ///     let client = Client::new();
+    ///
+    ///     let user_id = 1;
+    ///
+    ///     // Get the user's most recent posts.
+    ///     let posts = client.get_user_posts(user_id, |f| {
+    ///         f.sort("-createdAt").limit(10)
+    ///     }).expect("Error getting posts");
+    ///
+    ///     for post in posts.data {
+    ///         println!("{}", post.attributes.content);
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Json`] if there was an error parsing the response
+    /// body.
+    ///
+    /// Returns [`Error::ReqwestBad`] if the request was otherwise bad for some
+    /// reason, containing the response.
+    ///
+    /// Returns [`Error::ReqwestInvalid`] if the response was a non-OK (status
+    /// code 200) response, containing the response.
+    ///
+    /// Returns [`Error::ReqwestUnauthorized`] if the authorization token was
+    /// invalid.
+    ///
+    /// [`Error::Json`]: ../enum.Error.html#variant.Json
+    /// [`Error::ReqwestBad`]: ../enum.Error.html#variant.ReqwestBad
+    /// [`Error::ReqwestInvalid`]: ../enum.Error.html#variant.ReqwestInvalid
+    /// [`Error::ReqwestUnauthorized`]: ../enum.Error.html#variant.ReqwestUnauthorized
+    fn get_user_posts<F: FnOnce(Search) -> Search>(&self, user_id: u64, f: F)
+        -> Result<Response<Vec<Post>>>
+        where Self: Sized;
+
+    /// Gets the comments on a post using the post's id.
+    ///
+    /// The comments can be sorted and paginated using the search builder.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// extern crate kitsu_io;
+    /// extern crate reqwest;
+    ///
+    /// use kitsu_io::KitsuReqwestRequester;
+    /// use reqwest::blocking::Client;
+    ///
+    /// fn main() {
+    ///     // Create the reqwest Client.
+    ///     let client = Client::new();
+    ///
+    ///     let post_id = 1;
+    ///
+    ///     // Get the oldest comments on the post.
+    ///     let comments = client.get_post_comments(post_id, |f| {
+    ///         f.sort("createdAt").limit(10)
+    ///     }).expect("Error getting comments");
+    ///
+    ///     for comment in comments.data {
+    ///         println!("{}", comment.attributes.content);
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Json`] if there was an error parsing the response
+    /// body.
+    ///
+    /// Returns [`Error::ReqwestBad`] if the request was otherwise bad for some
+    /// reason, containing the response.
+    ///
+    /// Returns [`Error::ReqwestInvalid`] if the response was a non-OK (status
+    /// code 200) response, containing the response.
+    ///
+    /// Returns [`Error::ReqwestUnauthorized`] if the authorization token was
+    /// invalid.
+    ///
+    /// [`Error::Json`]: ../enum.Error.html#variant.Json
+    /// [`Error::ReqwestBad`]: ../enum.Error.html#variant.ReqwestBad
+    /// [`Error::ReqwestInvalid`]: ../enum.Error.html#variant.ReqwestInvalid
+    /// [`Error::ReqwestUnauthorized`]: ../enum.Error.html#variant.ReqwestUnauthorized
+    fn get_post_comments<F: FnOnce(Search) -> Search>(&self, post_id: u64, f: F)
+        -> Result<Response<Vec<Comment>>>
+        where Self: Sized;
+}
+
+impl<B: Base> KitsuRequester for B {
+    fn anime_exists(&self, id: u64) -> Result<bool> {
+        let uri = url::Url::parse(&format!("{}/anime/{}", self.base_url(), id))?;
+
+        handle_exists(self, uri)
+    }
+
+    fn get_anime(&self, id: u64) -> Result<Response<Anime>> {
+        let uri = url::Url::parse(&format!("{}/anime/{}", self.base_url(), id))?;
+
+        handle_get::<Response<Anime>>(self, uri)
+    }
+
+    fn get_manga(&self, id: u64) -> Result<Response<Manga>> {
+        let uri = url::Url::parse(&format!("{}/manga/{}", self.base_url(), id))?;
+
+        handle_get::<Response<Manga>>(self, uri)
+    }
+
+    fn get_user(&self, id: u64) -> Result<Response<User>> {
+        let uri = url::Url::parse(&format!("{}/users/{}", self.base_url(), id))?;
+
+        handle_get::<Response<User>>(self, uri)
+    }
+
+    fn random_anime(&self) -> Result<Option<Anime>> {
+        let count = self.search_anime(|f| f.limit(1))?.meta.count.unwrap_or(0);
+
+        if count == 0 {
+            return Ok(None);
+        }
+
+        let offset = random() % count;
+        let anime = self.search_anime(|f| f.limit(1).offset(offset))?;
+
+        Ok(anime.data.into_iter().next())
+    }
+
+    fn recently_updated_anime(&self, limit: u64) -> Result<Response<Vec<Anime>>> {
+        self.search_anime(|f| f.sort("-updatedAt").limit(limit))
+    }
+
+    fn search_anime<F: FnOnce(Search) -> Search>(&self, f: F) -> Result<Response<Vec<Anime>>> {
+        self.search_anime_with(f(Search::default()))
+    }
+
+    fn search_manga<F: FnOnce(Search) -> Search>(&self, f: F) -> Result<Response<Vec<Manga>>> {
+        self.search_manga_with(f(Search::default()))
+    }
+
+    fn search_users<F: FnOnce(Search) -> Search>(&self, f: F) -> Result<Response<Vec<User>>> {
+        self.search_users_with(f(Search::default()))
+    }
+
+    fn get_anime_genre_names(&self, anime_id: u64) -> Result<Vec<String>> {
+        let uri = url::Url::parse(&format!("{}/anime/{}/genres", self.base_url(), anime_id))?;
+        let genres = handle_get::<Response<Vec<Genre>>>(self, uri)?;
+
+        Ok(genres.data.into_iter().map(|genre| genre.attributes.name).collect())
+    }
+
+    fn search_anime_fuzzy(&self, query: &str) -> Result<Vec<Anime>> {
+        let mut anime = self.search_anime(|f| f.filter("text", query))?.data;
+        let titled = self.search_anime(|f| f.filter("canonicalTitle", query))?.data;
+
+        for item in titled {
+            if anime.iter().all(|found| found.id != item.id) {
+                anime.push(item);
+            }
+        }
+
+        Ok(anime)
+    }
+
+    fn get_self(&self, token: &str) -> Result<Response<User>> {
+        let uri = url::Url::parse(&format!("{}/users?filter[self]=true", self.base_url()))?;
+        let mut headers = HeaderMap::new();
+
+        match HeaderValue::from_str(&format!("Bearer {}", token)) {
+            Ok(value) => headers.insert(AUTHORIZATION, value),
+            Err(_) => return Err(Error::ReqwestUnauthorized(request_id())),
+        };
+
+        let (request_id, response) = send(self, Method::GET, uri, headers)?;
+
+        if response.status != StatusCode::OK {
+            return Err(response_error(response, request_id));
+        }
+
+        let users = from_bytes::<Response<Vec<User>>>(&response.body, &request_id)?;
+
+        if users.data.is_empty() {
+            return Err(Error::ReqwestUnauthorized(request_id));
+        }
+
+        Ok(users.map(|mut data| data.remove(0)))
+    }
+
+    fn get_anime_episodes(&self, anime_id: u64) -> Result<Response<Vec<Episode>>> {
+        let uri = url::Url::parse(&format!("{}/anime/{}/episodes", self.base_url(), anime_id))?;
+
+        handle_get::<Response<Vec<Episode>>>(self, uri)
+    }
+
+    fn get_manga_chapters(&self, manga_id: u64) -> Result<Response<Vec<Chapter>>> {
+        let uri = url::Url::parse(&format!("{}/manga/{}/chapters", self.base_url(), manga_id))?;
+
+        handle_get::<Response<Vec<Chapter>>>(self, uri)
+    }
+
+    fn search_anime_all<F: FnOnce(Search) -> Search>(&self, f: F) -> Paginated<'_, Anime> {
+        let search = f(Search::default());
+
+        if let Err(why) = search.validate() {
+            return Paginated::failed(self, why);
+        }
+
+        Paginated::new(self, format!("{}/anime?{}", self.base_url(), search.as_query()))
+    }
+
+    fn get_anime_streaming_links(&self, anime_id: u64) -> Result<Response<Vec<StreamingLink>>> {
+        let uri = url::Url::parse(&format!("{}/anime/{}/streaming-links", self.base_url(), anime_id))?;
+
+        handle_get::<Response<Vec<StreamingLink>>>(self, uri)
+    }
+
+    fn get_anime_categories(&self, anime_id: u64) -> Result<Response<Vec<Category>>> {
+        let uri = url::Url::parse(&format!("{}/anime/{}/categories", self.base_url(), anime_id))?;
+
+        handle_get::<Response<Vec<Category>>>(self, uri)
+    }
+
+    fn get_categories<F: FnOnce(Search) -> Search>(&self, f: F) -> Result<Response<Vec<Category>>> {
+        let search = f(Search::default());
+        search.validate()?;
+
+        let uri = url::Url::parse(&format!("{}/categories?{}", self.base_url(), search.as_query()))?;
+
+        handle_get::<Response<Vec<Category>>>(self, uri)
+    }
+
+    fn fetch<T: DeserializeOwned>(&self, url: &str) -> Result<Response<T>> {
+        let uri = api_url(self.base_url(), url)?;
+
+        handle_get::<Response<T>>(self, uri)
+    }
+
+    fn get_trending_anime(&self) -> Result<Response<Vec<Anime>>> {
+        let uri = url::Url::parse(&format!("{}/trending/anime", self.base_url()))?;
+
+        handle_get::<Response<Vec<Anime>>>(self, uri)
+    }
+
+    fn get_trending_manga(&self) -> Result<Response<Vec<Manga>>> {
+        let uri = url::Url::parse(&format!("{}/trending/manga", self.base_url()))?;
+
+        handle_get::<Response<Vec<Manga>>>(self, uri)
+    }
+
+    fn get_anime_reviews(&self, anime_id: u64) -> Result<Response<Vec<Review>>> {
+        let uri = url::Url::parse(&format!("{}/anime/{}/reviews", self.base_url(), anime_id))?;
+
+        handle_get::<Response<Vec<Review>>>(self, uri)
+    }
+
+    fn get_user_library_entries<F: FnOnce(Search) -> Search>(&self, user_id: u64, f: F)
+        -> Result<Response<Vec<LibraryEntry>>> {
+        let search = f(Search::default());
+        search.validate()?;
+
+        let uri = url::Url::parse(&format!(
+            "{}/users/{}/library-entries?{}",
+            self.base_url(),
+            user_id,
+            search.as_query(),
+        ))?;
+
+        handle_get::<Response<Vec<LibraryEntry>>>(self, uri)
+    }
+
+    fn get_anime_castings(&self, anime_id: u64) -> Result<Response<Vec<Casting>>> {
+        let uri = url::Url::parse(&format!(
+            "{}/anime/{}/castings?include=character",
+            self.base_url(),
+            anime_id,
+        ))?;
+
+        handle_get::<Response<Vec<Casting>>>(self, uri)
+    }
+
+    fn get_anime_by_slug(&self, slug: &str) -> Result<Option<Anime>> {
+        let anime = self.search_anime(|f| f.filter("slug", slug).limit(1))?;
+
+        Ok(anime.data.into_iter().next())
+    }
+
+    fn get_manga_by_slug(&self, slug: &str) -> Result<Option<Manga>> {
+        let manga = self.search_manga(|f| f.filter("slug", slug).limit(1))?;
+
+        Ok(manga.data.into_iter().next())
+    }
+
+    fn get_person(&self, id: u64) -> Result<Response<Person>> {
+        let uri = url::Url::parse(&format!("{}/people/{}", self.base_url(), id))?;
+
+        handle_get::<Response<Person>>(self, uri)
+    }
+
+    fn search_people<F: FnOnce(Search) -> Search>(&self, f: F) -> Result<Response<Vec<Person>>> {
+        let search = f(Search::default());
+        search.validate()?;
+
+        let uri = url::Url::parse(&format!("{}/people?{}", self.base_url(), search.as_query()))?;
+
+        handle_get::<Response<Vec<Person>>>(self, uri)
+    }
+
+    fn try_get_anime(&self, id: u64) -> Result<Option<Response<Anime>>> {
+        let uri = url::Url::parse(&format!("{}/anime/{}", self.base_url(), id))?;
+
+        handle_optional::<Response<Anime>>(self, uri)
+    }
+
+    fn try_get_manga(&self, id: u64) -> Result<Option<Response<Manga>>> {
+        let uri = url::Url::parse(&format!("{}/manga/{}", self.base_url(), id))?;
+
+        handle_optional::<Response<Manga>>(self, uri)
+    }
+
+    fn try_get_user(&self, id: u64) -> Result<Option<Response<User>>> {
+        let uri = url::Url::parse(&format!("{}/users/{}", self.base_url(), id))?;
+
+        handle_optional::<Response<User>>(self, uri)
+    }
+
+    fn get_anime_mappings(&self, anime_id: u64) -> Result<Response<Vec<Mapping>>> {
+        let uri = url::Url::parse(&format!("{}/anime/{}/mappings", self.base_url(), anime_id))?;
+
+        handle_get::<Response<Vec<Mapping>>>(self, uri)
+    }
+
+    fn search_anime_with(&self, search: Search) -> Result<Response<Vec<Anime>>> {
+        search.validate()?;
+
+        let uri = url::Url::parse(&format!("{}/anime?{}", self.base_url(), search.as_query()))?;
+
+        handle_get::<Response<Vec<Anime>>>(self, uri)
+    }
+
+    fn search_manga_with(&self, search: Search) -> Result<Response<Vec<Manga>>> {
+        search.validate()?;
+
+        let uri = url::Url::parse(&format!("{}/manga?{}", self.base_url(), search.as_query()))?;
+
+        handle_get::<Response<Vec<Manga>>>(self, uri)
+    }
+
+    fn search_users_with(&self, search: Search) -> Result<Response<Vec<User>>> {
+        search.validate()?;
+
+        let uri = url::Url::parse(&format!("{}/users?{}", self.base_url(), search.as_query()))?;
+
+        handle_get::<Response<Vec<User>>>(self, uri)
+    }
+
+    fn get_anime_media_relationships(&self, anime_id: u64)
+        -> Result<Response<Vec<MediaRelationship>>> {
+        let uri = url::Url::parse(&format!(
+            "{}/anime/{}/media-relationships?include=destination",
+            self.base_url(),
+            anime_id,
+        ))?;
+
+        handle_get::<Response<Vec<MediaRelationship>>>(self, uri)
+    }
+
+    fn get_anime_retrying(&self, id: u64, policy: RetryPolicy) -> Result<Response<Anime>> {
+        retry_with_backoff(policy, || self.get_anime(id))
+    }
+
+    fn get_anime_installments(&self, anime_id: u64) -> Result<Response<Vec<Installment>>> {
+        let uri = url::Url::parse(&format!(
+            "{}/anime/{}/installments?include=franchise",
+            self.base_url(),
+            anime_id,
+        ))?;
+
+        handle_get::<Response<Vec<Installment>>>(self, uri)
+    }
+
+    fn get_anime_productions(&self, anime_id: u64) -> Result<Response<Vec<AnimeProduction>>> {
+        let uri = url::Url::parse(&format!(
+            "{}/anime/{}/anime-productions?include=producer",
+            self.base_url(),
+            anime_id,
+        ))?;
+
+        handle_get::<Response<Vec<AnimeProduction>>>(self, uri)
+    }
+
+    fn get_anime_reactions(&self, anime_id: u64) -> Result<Response<Vec<MediaReaction>>> {
+        let uri = url::Url::parse(&format!(
+            "{}/anime/{}/media-reactions",
+            self.base_url(),
+            anime_id,
+        ))?;
+
+        handle_get::<Response<Vec<MediaReaction>>>(self, uri)
+    }
+
+    fn get_group(&self, id: u64) -> Result<Response<Group>> {
+        let uri = url::Url::parse(&format!("{}/groups/{}", self.base_url(), id))?;
+
+        handle_get::<Response<Group>>(self, uri)
+    }
+
+    fn search_groups<F: FnOnce(Search) -> Search>(&self, f: F) -> Result<Response<Vec<Group>>> {
+        self.search_groups_with(f(Search::default()))
+    }
+
+    fn search_groups_with(&self, search: Search) -> Result<Response<Vec<Group>>> {
+        search.validate()?;
+
+        let uri = url::Url::parse(&format!("{}/groups?{}", self.base_url(), search.as_query()))?;
+
+        handle_get::<Response<Vec<Group>>>(self, uri)
+    }
+
+    fn get_user_waifu(&self, user_id: u64) -> Result<Response<Option<Character>>> {
+        let uri = url::Url::parse(&format!("{}/users/{}/waifu", self.base_url(), user_id))?;
+
+        handle_get::<Response<Option<Character>>>(self, uri)
+    }
+
+    fn get_user_library_events<F: FnOnce(Search) -> Search>(&self, user_id: u64, f: F)
+        -> Result<Response<Vec<LibraryEvent>>> {
+        let search = f(Search::default());
+        search.validate()?;
+
+        let uri = url::Url::parse(&format!(
+            "{}/users/{}/library-events?{}",
+            self.base_url(),
+            user_id,
+            search.as_query(),
+        ))?;
+
+        handle_get::<Response<Vec<LibraryEvent>>>(self, uri)
+    }
+
+    fn get_anime_many(&self, ids: &[u64]) -> Result<Response<Vec<Anime>>> {
+        let mut unique = Vec::with_capacity(ids.len());
+
+        for id in ids {
+            if !unique.contains(id) {
+                unique.push(*id);
+            }
+        }
+
+        let mut merged = Response {
+            data: Vec::with_capacity(unique.len()),
+            included: Vec::new(),
+            links: Default::default(),
+            meta: Meta::default(),
+        };
+
+        for chunk in unique.chunks(MAX_PAGE_LIMIT) {
+            let chunk = chunk.iter().map(u64::to_string).collect::<Vec<_>>();
+            let chunk = chunk.iter().map(String::as_str).collect::<Vec<_>>();
+            let search = Search::new().filter_in("id", &chunk).limit(chunk.len() as u64);
+
+            let response = self.search_anime_with(search)?;
+            merged.data.extend(response.data);
+            merged.included.extend(response.included);
+        }
+
+        let positions = unique.iter()
+            .enumerate()
+            .map(|(position, id)| (id.to_string(), position))
+            .collect::<HashMap<_, _>>();
+        merged.data.sort_by_key(|anime| positions.get(&anime.id).copied().unwrap_or(usize::MAX));
+        merged.meta.count = Some(merged.data.len() as u64);
+
+        Ok(merged)
+    }
+
+    fn get_user_followers<F: FnOnce(Search) -> Search>(&self, user_id: u64, f: F)
+        -> Result<Response<Vec<User>>> {
+        let uri = url::Url::parse(&follows_url(self.base_url(), "followed", user_id, "follower", f)?)?;
+
+        fetch_followers(self, uri)
+    }
+
+    fn get_user_following<F: FnOnce(Search) -> Search>(&self, user_id: u64, f: F)
+        -> Result<Response<Vec<User>>> {
+        let uri = url::Url::parse(&follows_url(self.base_url(), "follower", user_id, "followed", f)?)?;
+
+        fetch_followed(self, uri)
+    }
+
+    fn get_user_followers_all<F: FnOnce(Search) -> Search>(&self, user_id: u64, f: F)
+        -> Paginated<'_, User> {
+        match follows_url(self.base_url(), "followed", user_id, "follower", f) {
+            Ok(url) => Paginated::with_fetch(self, url, fetch_followers),
+            Err(why) => Paginated::failed(self, why),
+        }
+    }
+
+    fn get_user_following_all<F: FnOnce(Search) -> Search>(&self, user_id: u64, f: F)
+        -> Paginated<'_, User> {
+        match follows_url(self.base_url(), "follower", user_id, "followed", f) {
+            Ok(url) => Paginated::with_fetch(self, url, fetch_followed),
+            Err(why) => Paginated::failed(self, why),
+        }
+    }
+
+    fn get_user_posts<F: FnOnce(Search) -> Search>(&self, user_id: u64, f: F)
+        -> Result<Response<Vec<Post>>> {
+        let search = f(Search::default());
+        search.validate()?;
+
+        let uri = url::Url::parse(&format!(
+            "{}/users/{}/posts?{}",
+            self.base_url(),
+            user_id,
+            search.as_query(),
+        ))?;
+
+        handle_get::<Response<Vec<Post>>>(self, uri)
+    }
+
+    fn get_post_comments<F: FnOnce(Search) -> Search>(&self, post_id: u64, f: F)
+        -> Result<Response<Vec<Comment>>> {
+        let search = f(Search::default());
+        search.validate()?;
+
+        let uri = url::Url::parse(&format!(
+            "{}/posts/{}/comments?{}",
+            self.base_url(),
+            post_id,
+            search.as_query(),
+        ))?;
+
+        handle_get::<Response<Vec<Comment>>>(self, uri)
+    }
+}
+
+/// Access to the parts of a client which requests are made with.
+///
+/// This is sealed, and is implemented for the `reqwest` Client using the
+/// `API_URL`, for a `KitsuClient` using its configured base URL, and for a
+/// `MockRequester` which serves requests from memory.
+pub(crate) mod sealed {
+    use reqwest::blocking::Client as ReqwestClient;
+    use reqwest::header::HeaderMap;
+    use reqwest::{Method, StatusCode};
+    use std::collections::HashMap;
+    use std::sync::{Mutex, MutexGuard};
+    use crate::client::ETAG_CACHE_CAPACITY;
+    use crate::Result;
+
+    /// The base URL and transport which requests are made with.
+    pub trait Base {
+        /// The base URL of the API, without a trailing slash.
+        fn base_url(&self) -> &str;
+
+        /// Sends a request, returning the response with its body read.
+        fn send(&self, request: RawRequest) -> Result<RawResponse>;
+
+        /// The cache of response bodies by their `ETag`, if requests should be
+        /// made conditionally.
+        fn etag_cache(&self) -> Option<&EtagCache> {
+            None
+        }
+    }
+
+    /// A request to send, with every header which should be sent.
+    #[derive(Debug)]
+    pub struct RawRequest {
+        pub headers: HeaderMap,
+        pub method: Method,
+        pub url: url::Url,
+    }
+
+    /// A response whose body has been read.
+    #[derive(Debug)]
+    pub struct RawResponse {
+        pub body: Vec<u8>,
+        pub headers: HeaderMap,
+        pub status: StatusCode,
+    }
+
+    /// Sends a request using a `reqwest` Client.
+    ///
+    /// The body of a non-OK response is only read if possible, as it is only
+    /// used to describe the error.
+    pub fn send_reqwest(client: &ReqwestClient, request: RawRequest) -> Result<RawResponse> {
+        let response = client.request(request.method, request.url)
+            .headers(request.headers)
+            .send()?;
+        let status = response.status();
+        let headers = response.headers().clone();
+        let body = if status == StatusCode::OK {
+            response.bytes()?
+        } else {
+            response.bytes().unwrap_or_default()
+        };
+
+        Ok(RawResponse {
+            body: body.to_vec(),
+            headers,
+            status,
+        })
+    }
+
+    /// An in-memory cache of the most recent `ETag` and body of the responses
+    /// to requests, keyed by the URL requested.
+    ///
+    /// At most [`ETAG_CACHE_CAPACITY`] responses are kept, evicting the least
+    /// recently used response when full.
+    ///
+    /// [`ETAG_CACHE_CAPACITY`]: ../../../client/constant.ETAG_CACHE_CAPACITY.html
+    #[derive(Debug, Default)]
+    pub struct EtagCache {
+        entries: Mutex<Entries>,
+    }
+
+    #[derive(Debug, Default)]
+    struct Entries {
+        /// The cached `ETag` and body of each URL, alongside the tick at which
+        /// it was last used.
+        map: HashMap<String, (String, Vec<u8>, u64)>,
+        tick: u64,
+    }
+
+    impl EtagCache {
+        /// Removes every cached response.
+        pub fn clear(&self) {
+            self.entries().map.clear();
+        }
+
+        /// Retrieves the `ETag` and body of the cached response to a URL,
+        /// marking it as recently used.
+        pub fn get(&self, url: &str) -> Option<(String, Vec<u8>)> {
+            let mut entries = self.entries();
+            entries.tick += 1;
+            let tick = entries.tick;
+
+            entries.map.get_mut(url).map(|(etag, body, used)| {
+                *used = tick;
+
+                (etag.clone(), body.clone())
+            })
+        }
+
+        /// Caches the response to a URL, replacing any previous response and
+        /// evicting the least recently used response if the cache is full.
+        pub fn insert(&self, url: String, etag: String, body: Vec<u8>) {
+            let mut entries = self.entries();
+            entries.tick += 1;
+            let tick = entries.tick;
+
+            if !entries.map.contains_key(&url) && entries.map.len() >= ETAG_CACHE_CAPACITY {
+                let oldest = entries.map.iter()
+                    .min_by_key(|(_, (_, _, used))| *used)
+                    .map(|(url, _)| url.clone());
+
+                if let Some(oldest) = oldest {
+                    entries.map.remove(&oldest);
+                }
+            }
+
+            entries.map.insert(url, (etag, body, tick));
+        }
+
+        fn entries(&self) -> MutexGuard<'_, Entries> {
+            self.entries.lock().unwrap_or_else(|why| why.into_inner())
+        }
+    }
+}
+
+impl Base for ReqwestClient {
+    fn base_url(&self) -> &str {
+        API_URL
+    }
+
+    fn send(&self, request: RawRequest) -> Result<RawResponse> {
+        sealed::send_reqwest(self, request)
+    }
+}
+
+/// A policy for retrying requests which failed due to being rate limited or a
+/// server error, with an exponential backoff between attempts.
+///
+/// # Examples
+///
+/// ```rust
+/// use kitsu_io::bridge::reqwest::RetryPolicy;
+/// use std::time::Duration;
+///
+/// let policy = RetryPolicy::new(4, Duration::from_millis(250));
+///
+/// assert_eq!(policy.backoff(0), Duration::from_millis(250));
+/// assert_eq!(policy.backoff(2), Duration::from_secs(1));
+/// ```
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct RetryPolicy {
+    /// The maximum number of attempts to make, including the first. At least
+    /// one attempt is always made.
+    pub max_attempts: u32,
+    /// How long to wait before the first retry, which doubles with each
+    /// subsequent retry.
+    pub base_backoff: Duration,
+}
+
+impl RetryPolicy {
+    /// Creates a new policy with a maximum number of attempts and the backoff
+    /// before the first retry.
+    pub fn new(max_attempts: u32, base_backoff: Duration) -> Self {
+        RetryPolicy {
+            max_attempts,
+            base_backoff,
+        }
+    }
+
+    /// How long to wait before a retry, where the first retry is `0`.
+    ///
+    /// The backoff saturates at `Duration::MAX` rather than overflowing.
+    pub fn backoff(&self, retry: u32) -> Duration {
+        self.base_backoff.checked_mul(2u32.saturating_pow(retry)).unwrap_or(Duration::MAX)
+    }
+}
+
+impl Default for RetryPolicy {
+    /// A policy of 3 attempts, waiting 500 milliseconds before the first
+    /// retry.
+    fn default() -> Self {
+        RetryPolicy::new(3, Duration::from_millis(500))
+    }
+}
+
+/// Calls a function making a request, retrying it according to a policy if it
+/// fails due to being rate limited or a server error.
+///
+/// When rate limited, the `Retry-After` duration given by the API is waited
+/// instead of the policy's backoff.
+///
+/// # Examples
+///
+/// Retry searching for anime:
+///
+/// ```rust,no_run
+/// extern crate kitsu_io;
+/// extern crate reqwest;
+///
+/// use kitsu_io::bridge::reqwest::{retry_with_backoff, RetryPolicy};
+/// use kitsu_io::KitsuReqwestRequester;
+/// use reqwest::blocking::Client;
+///
+/// fn main() {
+///     let client = Client::new();
+///
+///     let anime = retry_with_backoff(RetryPolicy::default(), || {
+///         client.search_anime(|f| f.filter("text", "Cowboy Bebop"))
+///     }).expect("Error searching for anime");
+///
+///     // Do something with anime
+/// }
+/// ```
+///
+/// # Errors
+///
+/// Returns the error of the last attempt if every attempt failed, or the
+/// first error which is not retryable.
+pub fn retry_with_backoff<T, F: FnMut() -> Result<T>>(policy: RetryPolicy, mut f: F)
+    -> Result<T> {
+    let mut retry = 0;
+
+    loop {
+        let why = match f() {
+            Ok(value) => return Ok(value),
+            Err(why) => why,
+        };
+
+        if retry + 1 >= policy.max_attempts {
+            return Err(why);
+        }
+
+        let backoff = match why {
+            Error::RateLimited { retry_after, .. } => {
+                retry_after.unwrap_or_else(|| policy.backoff(retry))
+            },
+            _ if why.status().is_some_and(|status| (500..600).contains(&status)) => {
+                policy.backoff(retry)
+            },
+            _ => return Err(why),
+        };
+
+        thread::sleep(backoff);
+        retry += 1;
+    }
+}
+
+/// Fetches a page of resources from a URL.
+type FetchPage<T> = fn(&dyn Base, url::Url) -> Result<Response<Vec<T>>>;
+
+/// An iterator over the items of every page of a search, created by methods
+/// such as [`KitsuRequester::search_anime_all`].
+///
+/// The link to each following page must be on the API at the requester's
+/// base URL. Otherwise the iterator yields an [`Error::ForeignUrl`] and
+/// stops, rather than sending a request elsewhere.
+///
+/// [`Error::ForeignUrl`]: ../../enum.Error.html#variant.ForeignUrl
+/// [`KitsuRequester::search_anime_all`]: trait.KitsuRequester.html#tymethod.search_anime_all
+pub struct Paginated<'a, T> {
+    base: &'a dyn Base,
+    error: Option<Error>,
+    fetch: FetchPage<T>,
+    items: IntoIter<T>,
+    next: Option<Result<url::Url>>,
+}
+
+impl<'a, T: DeserializeOwned> Paginated<'a, T> {
+    fn new(base: &'a dyn Base, url: String) -> Self {
+        Paginated::with_fetch(base, url, fetch_page)
+    }
+
+    /// Creates an iterator which only yields an error, such as when the search
+    /// is invalid.
+    fn failed(base: &'a dyn Base, error: Error) -> Self {
+        Paginated {
+            base,
+            error: Some(error),
+            fetch: fetch_page,
+            items: Vec::new().into_iter(),
+            next: None,
+        }
+    }
+}
+
+impl<'a, T> Paginated<'a, T> {
+    /// Creates an iterator which fetches each page using the given function,
+    /// such as when the items are not the primary data of the response.
+    fn with_fetch(
+        base: &'a dyn Base,
+        url: String,
+        fetch: FetchPage<T>,
+    ) -> Self {
+        Paginated {
+            base,
+            error: None,
+            fetch,
+            items: Vec::new().into_iter(),
+            next: Some(api_url(base.base_url(), &url)),
+        }
+    }
+}
+
+impl<'a, T> Iterator for Paginated<'a, T> {
+    type Item = Result<T>;
+
+    fn next(&mut self) -> Option<Result<T>> {
+        if let Some(why) = self.error.take() {
+            return Some(Err(why));
+        }
 
-        handle_request::<Response<Vec<User>>>(self.get(uri))
+        loop {
+            if let Some(item) = self.items.next() {
+                return Some(Ok(item));
+            }
+
+            let page = self.next.take()?
+                .and_then(|uri| (self.fetch)(self.base, uri));
+
+            match page {
+                Ok(mut page) => {
+                    self.next = next_link(self.base.base_url(), &mut page);
+                    self.items = page.data.into_iter();
+                },
+                Err(why) => return Some(Err(why)),
+            }
+        }
     }
 }
 
-fn handle_request<T: DeserializeOwned>(request: RequestBuilder) -> Result<T> {
-    let response = request.send()?;
+/// Fetches a page of results which are the primary data of the response.
+fn fetch_page<T: DeserializeOwned>(base: &dyn Base, uri: url::Url)
+    -> Result<Response<Vec<T>>> {
+    handle_request::<Response<Vec<T>>>(base, uri)
+}
+
+/// Fetches a page of follows of a user, returning the followers.
+fn fetch_followers(base: &dyn Base, uri: url::Url) -> Result<Response<Vec<User>>> {
+    fetch_follow_users(base, uri, |relationships| relationships.follower.as_ref())
+}
+
+/// Fetches a page of follows by a user, returning the followed users.
+fn fetch_followed(base: &dyn Base, uri: url::Url) -> Result<Response<Vec<User>>> {
+    fetch_follow_users(base, uri, |relationships| relationships.followed.as_ref())
+}
+
+/// Fetches a page of follows, returning the users on one side of the follows
+/// in place of the follows, in the order of the follows.
+///
+/// The follows are requested with that side of each follow included, so each
+/// user is looked up by the id in the follow's relationship among the
+/// included users. Follows whose user was not included are skipped.
+fn fetch_follow_users(
+    base: &dyn Base,
+    uri: url::Url,
+    side: fn(&FollowRelationships) -> Option<&ToOneRelationship>,
+) -> Result<Response<Vec<User>>> {
+    let follows = handle_request::<Response<Vec<Follow>>>(base, uri)?;
+    let mut users = HashMap::new();
+    let mut included = Vec::new();
 
-    match response.status() {
-        StatusCode::OK => {}
-        StatusCode::BAD_REQUEST => {
-            return Err(Error::ReqwestBad());
+    for resource in follows.included {
+        match resource {
+            Included::User(user) => {
+                users.insert(user.id.clone(), user);
+            },
+            other => included.push(other),
         }
-        StatusCode::UNAUTHORIZED => {
-            return Err(Error::ReqwestUnauthorized());
+    }
+
+    let data = follows.data.iter()
+        .filter_map(|follow| follow.relationships.as_ref())
+        .filter_map(|relationships| side(relationships)?.data.as_ref())
+        .filter_map(|identifier| users.remove(&identifier.id))
+        .collect();
+
+    Ok(Response {
+        data,
+        included,
+        links: follows.links,
+        meta: follows.meta,
+    })
+}
+
+/// Builds the URL of a search for follows which filters on one side of the
+/// follows by a user's id, and includes the users on the other side.
+fn follows_url<F: FnOnce(Search) -> Search>(
+    base_url: &str,
+    filter: &str,
+    user_id: u64,
+    include: &str,
+    f: F,
+) -> Result<String> {
+    let search = f(Search::default())
+        .filter(filter, &user_id.to_string())
+        .include(&[include]);
+    search.validate()?;
+
+    Ok(format!("{}/follows?{}", base_url, search.as_query()))
+}
+
+/// Handles a `GET` request made by a client, which is made conditionally if
+/// the client has an `EtagCache`.
+///
+/// When a cached response to the URL has an `ETag`, it is sent in the
+/// `If-None-Match` header, and the cached body is used if the API responds
+/// with `304 Not Modified`.
+///
+/// Requests with a query, such as searches and the pages of a search, are
+/// never cached, as each distinct query would otherwise occupy the cache.
+fn handle_get<T: DeserializeOwned>(base: &dyn Base, uri: url::Url) -> Result<T> {
+    let cache = match base.etag_cache() {
+        Some(cache) if uri.query().is_none() => cache,
+        _ => return handle_request(base, uri),
+    };
+
+    let key = uri.to_string();
+    let cached = cache.get(&key);
+    let mut headers = HeaderMap::new();
+
+    if let Some((ref etag, _)) = cached {
+        if let Ok(etag) = HeaderValue::from_str(etag) {
+            headers.insert(IF_NONE_MATCH, etag);
         }
-        _ => return Err(Error::ReqwestInvalid()),
     }
 
-    from_reader(response)
+    let (request_id, response) = send(base, Method::GET, uri, headers)?;
+
+    match (response.status, cached) {
+        (StatusCode::OK, _) => {
+            let value = from_bytes(&response.body, &request_id)?;
+            let etag = response.headers.get(ETAG)
+                .and_then(|value| value.to_str().ok())
+                .map(str::to_owned);
+
+            if let Some(etag) = etag {
+                cache.insert(key, etag, response.body);
+            }
+
+            Ok(value)
+        },
+        (StatusCode::NOT_MODIFIED, Some((_, body))) => from_bytes(&body, &request_id),
+        _ => Err(response_error(response, request_id)),
+    }
+}
+
+fn handle_request<T: DeserializeOwned>(base: &dyn Base, uri: url::Url) -> Result<T> {
+    let (request_id, response) = send(base, Method::GET, uri, HeaderMap::new())?;
+
+    match response.status {
+        StatusCode::OK => from_bytes(&response.body, &request_id),
+        _ => Err(response_error(response, request_id)),
+    }
+}
+
+/// Handles a request for a single resource, returning `None` if the resource
+/// was not found rather than an error.
+fn handle_optional<T: DeserializeOwned>(base: &dyn Base, uri: url::Url) -> Result<Option<T>> {
+    let (request_id, response) = send(base, Method::GET, uri, HeaderMap::new())?;
+
+    match response.status {
+        StatusCode::OK => from_bytes(&response.body, &request_id).map(Some),
+        StatusCode::NOT_FOUND => Ok(None),
+        _ => Err(response_error(response, request_id)),
+    }
+}
+
+/// Handles a `HEAD` request for a single resource, returning whether it
+/// exists.
+fn handle_exists(base: &dyn Base, uri: url::Url) -> Result<bool> {
+    let (request_id, response) = send(base, Method::HEAD, uri, HeaderMap::new())?;
+
+    match response.status {
+        StatusCode::OK => Ok(true),
+        StatusCode::NOT_FOUND => Ok(false),
+        _ => Err(response_error(response, request_id)),
+    }
+}
+
+/// Sends a request accepting JSON:API documents with a newly generated
+/// `X-Request-Id` header, returning the id alongside the response so that it
+/// can be surfaced in errors.
+fn send(base: &dyn Base, method: Method, url: url::Url, mut headers: HeaderMap)
+    -> Result<(String, RawResponse)> {
+    let request_id = request_id();
+    headers.insert(ACCEPT, HeaderValue::from_static(JSON_API));
+
+    if let Ok(value) = HeaderValue::from_str(&request_id) {
+        headers.insert(X_REQUEST_ID, value);
+    }
+
+    let response = base.send(RawRequest {
+        headers,
+        method,
+        url,
+    })?;
+
+    Ok((request_id, response))
+}
+
+/// Creates an error from a non-OK response, using the errors in the response's
+/// body if it is a JSON:API error document.
+fn response_error(response: RawResponse, request_id: String) -> Error {
+    error_from_parts(response.status, &response.headers, &response.body, request_id)
+}
+
+/// Creates an error from the parts of a non-OK response.
+pub(crate) fn error_from_parts(
+    status: StatusCode,
+    headers: &HeaderMap,
+    body: &[u8],
+    request_id: String,
+) -> Error {
+    if status == StatusCode::TOO_MANY_REQUESTS {
+        let retry_after = headers.get(RETRY_AFTER)
+            .and_then(|value| value.to_str().ok())
+            .and_then(retry_after);
+
+        return Error::RateLimited {
+            retry_after,
+            request_id: Some(request_id),
+        };
+    }
+
+    match api_errors(body) {
+        Some(errors) => Error::Api(errors, Some(request_id)),
+        None => status_error(status, request_id),
+    }
+}
+
+fn status_error(status: StatusCode, request_id: String) -> Error {
+    match status {
+        StatusCode::BAD_REQUEST => Error::ReqwestBad(request_id),
+        StatusCode::UNAUTHORIZED => Error::ReqwestUnauthorized(request_id),
+        _ => Error::ReqwestInvalid(status, request_id),
+    }
+}
+
+/// Parses a URL, ensuring that it is on the API at the given base URL.
+///
+/// The scheme, host, and port must match those of the base URL, and the path
+/// must be within the base URL's path, so that a URL such as
+/// `https://kitsu.io/api/edge.example.com` is not accepted.
+pub(crate) fn api_url(base_url: &str, url: &str) -> Result<url::Url> {
+    let api = url::Url::parse(base_url)?;
+    let parsed = url::Url::parse(url)?;
+
+    let within_api = parsed.path() == api.path()
+        || parsed.path().starts_with(&format!("{}/", api.path()));
+
+    if parsed.origin() == api.origin() && within_api {
+        Ok(parsed)
+    } else {
+        Err(Error::ForeignUrl(url.to_owned()))
+    }
+}
+
+/// Takes the link to the page following a page of results, ensuring that it
+/// is on the API at the given base URL.
+pub(crate) fn next_link<T>(base_url: &str, page: &mut Response<T>) -> Option<Result<url::Url>> {
+    page.links.remove("next").map(|url| api_url(base_url, &url))
+}
+
+/// Generates a unique id to send in the `X-Request-Id` header of a request.
+pub(crate) fn request_id() -> String {
+    format!("{:016x}", random())
+}
+
+/// Generates a random number using the randomly seeded keys of the standard
+/// library's hasher.
+fn random() -> u64 {
+    RandomState::new().build_hasher().finish()
 }
 
+/// Deserializes the body of a response to the request with the given id.
 #[inline]
-fn from_reader<T: DeserializeOwned, U: Read>(reader: U) -> Result<T> {
-    serde_json::from_reader(reader).map_err(From::from)
+pub(crate) fn from_bytes<T: DeserializeOwned>(bytes: &[u8], request_id: &str) -> Result<T> {
+    let body = str::from_utf8(bytes).map_err(Error::InvalidEncoding)?;
+
+    serde_json::from_str(body).map_err(|source| Error::Json {
+        source,
+        body: body.to_owned(),
+        request_id: Some(request_id.to_owned()),
+    })
 }
@@ -0,0 +1,644 @@
+//! Bridge to provide an asynchronous client implementation for the `reqwest`
+//! crate.
+//!
+//! # Examples
+//!
+//! Refer to the documentation for [`KitsuRequester`].
+//!
+//! [`KitsuRequester`]: trait.KitsuRequester.html
+
+use crate::builder::Search;
+use crate::model::{Anime, Manga, Response, User};
+use crate::{Result, API_URL};
+use futures_util::stream::{self, Stream};
+use reqwest::header::ACCEPT;
+use reqwest::{Client as ReqwestClient, RequestBuilder, StatusCode};
+use serde::de::DeserializeOwned;
+use std::future::Future;
+use std::vec::IntoIter;
+use self::sealed::Base;
+use super::reqwest::{error_from_parts, from_bytes, next_link, request_id, X_REQUEST_ID};
+use super::JSON_API;
+
+/// Trait which defines the methods necessary to interact with the service
+/// asynchronously.
+///
+/// # Examples
+///
+/// To bring in the implemenation for the asynchronous `reqwest` Client, simply
+/// use the trait:
+///
+/// ```rust,no_run
+/// use kitsu_io::KitsuAsyncRequester;
+/// ```
+///
+/// At this point, the methods will be on your Reqwest Client, which makes
+/// requests to the [`API_URL`]. To make requests to another base URL, such as
+/// a proxy or a mock server, use a [`KitsuAsyncClient`] instead:
+///
+/// ```rust
+/// use kitsu_io::KitsuAsyncClient;
+///
+/// let client = KitsuAsyncClient::new().with_base_url("http://localhost:8080");
+/// ```
+///
+/// [`API_URL`]: ../../constant.API_URL.html
+/// [`KitsuAsyncClient`]: struct.KitsuAsyncClient.html
+pub trait KitsuRequester {
+    /// Gets an anime using its id.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use kitsu_io::KitsuAsyncRequester;
+    /// use reqwest::Client;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     // Create the reqwest Client.
+    ///     let client = Client::new();
+    ///
+    ///     let anime_id = 1;
+    ///
+    ///     // Get the anime.
+    ///     let anime = client.get_anime(anime_id).await
+    ///         .expect("Error getting anime");
+    ///
+    ///     // Do something with anime
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Json`] if there was an error parsing the response
+    /// body.
+    ///
+    /// Returns [`Error::ReqwestBad`] if the request was otherwise bad for some
+    /// reason, containing the response.
+    ///
+    /// Returns [`Error::ReqwestInvalid`] if the response was a non-OK (status
+    /// code 200) response, containing the response.
+    ///
+    /// Returns [`Error::ReqwestUnauthorized`] if the authorization token was
+    /// invalid.
+    ///
+    /// [`Error::Json`]: ../../enum.Error.html#variant.Json
+    /// [`Error::ReqwestBad`]: ../../enum.Error.html#variant.ReqwestBad
+    /// [`Error::ReqwestInvalid`]: ../../enum.Error.html#variant.ReqwestInvalid
+    /// [`Error::ReqwestUnauthorized`]: ../../enum.Error.html#variant.ReqwestUnauthorized
+    fn get_anime(&self, id: u64) -> impl Future<Output = Result<Response<Anime>>> + Send;
+
+    /// Gets a manga using its id.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use kitsu_io::KitsuAsyncRequester;
+    /// use reqwest::Client;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     // Create the reqwest Client.
+    ///     let client = Client::new();
+    ///
+    ///     let manga_id = 1;
+    ///
+    ///     // Get the manga.
+    ///     let manga = client.get_manga(manga_id).await
+    ///         .expect("Error getting manga");
+    ///
+    ///     // Do something with manga
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Json`] if there was an error parsing the response
+    /// body.
+    ///
+    /// Returns [`Error::ReqwestBad`] if the request was otherwise bad for some
+    /// reason, containing the response.
+    ///
+    /// Returns [`Error::ReqwestInvalid`] if the response was a non-OK (status
+    /// code 200) response, containing the response.
+    ///
+    /// Returns [`Error::ReqwestUnauthorized`] if the authorization token was
+    /// invalid.
+    ///
+    /// [`Error::Json`]: ../../enum.Error.html#variant.Json
+    /// [`Error::ReqwestBad`]: ../../enum.Error.html#variant.ReqwestBad
+    /// [`Error::ReqwestInvalid`]: ../../enum.Error.html#variant.ReqwestInvalid
+    /// [`Error::ReqwestUnauthorized`]: ../../enum.Error.html#variant.ReqwestUnauthorized
+    fn get_manga(&self, id: u64) -> impl Future<Output = Result<Response<Manga>>> + Send;
+
+    /// Gets a user using their id.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use kitsu_io::KitsuAsyncRequester;
+    /// use reqwest::Client;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     // Create the reqwest Client.
+    ///     let client = Client::new();
+    ///
+    ///     let user_id = 1;
+    ///
+    ///     // Get the user.
+    ///     let user = client.get_user(user_id).await
+    ///         .expect("Error getting user");
+    ///
+    ///     // Do something with user
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Json`] if there was an error parsing the response
+    /// body.
+    ///
+    /// Returns [`Error::ReqwestBad`] if the request was otherwise bad for some
+    /// reason, containing the response.
+    ///
+    /// Returns [`Error::ReqwestInvalid`] if the response was a non-OK (status
+    /// code 200) response, containing the response.
+    ///
+    /// Returns [`Error::ReqwestUnauthorized`] if the authorization token was
+    /// invalid.
+    ///
+    /// [`Error::Json`]: ../../enum.Error.html#variant.Json
+    /// [`Error::ReqwestBad`]: ../../enum.Error.html#variant.ReqwestBad
+    /// [`Error::ReqwestInvalid`]: ../../enum.Error.html#variant.ReqwestInvalid
+    /// [`Error::ReqwestUnauthorized`]: ../../enum.Error.html#variant.ReqwestUnauthorized
+    fn get_user(&self, id: u64) -> impl Future<Output = Result<Response<User>>> + Send;
+
+    /// Searches for an anime using the passed [`Search`] builder.
+    ///
+    /// [`Search`]: ../../builder/struct.Search.html
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use kitsu_io::KitsuAsyncRequester;
+    /// use reqwest::Client;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     // Create the reqwest Client.
+    ///     let client = Client::new();
+    ///
+    ///     let anime_name = "Your Lie in April";
+    ///
+    ///     // Search for the anime.
+    ///     let anime = client.search_anime(|f| f.filter("text", anime_name)).await
+    ///         .expect("Error searching for anime");
+    ///
+    ///     // Do something with anime
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Json`] if there was an error parsing the response
+    /// body.
+    ///
+    /// Returns [`Error::ReqwestBad`] if the request was otherwise bad for some
+    /// reason, containing the response.
+    ///
+    /// Returns [`Error::ReqwestInvalid`] if the response was a non-OK (status
+    /// code 200) response, containing the response.
+    ///
+    /// Returns [`Error::ReqwestUnauthorized`] if the authorization token was
+    /// invalid.
+    ///
+    /// [`Error::Json`]: ../../enum.Error.html#variant.Json
+    /// [`Error::ReqwestBad`]: ../../enum.Error.html#variant.ReqwestBad
+    /// [`Error::ReqwestInvalid`]: ../../enum.Error.html#variant.ReqwestInvalid
+    /// [`Error::ReqwestUnauthorized`]: ../../enum.Error.html#variant.ReqwestUnauthorized
+    fn search_anime<F: FnOnce(Search) -> Search>(&self, f: F) ->
+        impl Future<Output = Result<Response<Vec<Anime>>>> + Send;
+
+    /// Searches for anime using the passed [`Search`] builder, returning a
+    /// stream over the anime of every page of results.
+    ///
+    /// Pages are fetched lazily by following the `next` link of each response,
+    /// and the next page is only requested once every anime of the previous
+    /// page has been taken from the stream. The stream ends after the first
+    /// error, which is yielded.
+    ///
+    /// [`Search`]: ../../builder/struct.Search.html
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use futures_util::StreamExt;
+    /// use kitsu_io::KitsuAsyncRequester;
+    /// use reqwest::Client;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     // Create the reqwest Client.
+    ///     let client = Client::new();
+    ///
+    ///     // Stream every anime airing this season.
+    ///     let mut anime = Box::pin(client.search_anime_stream(|f| {
+    ///         f.filter("season", "winter")
+    ///     }));
+    ///
+    ///     while let Some(anime) = anime.next().await {
+    ///         let anime = anime.expect("Error searching for anime");
+    ///
+    ///         println!("{}", anime.attributes.canonical_title);
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Json`] if there was an error parsing the response
+    /// body.
+    ///
+    /// Returns [`Error::ReqwestBad`] if the request was otherwise bad for some
+    /// reason, containing the response.
+    ///
+    /// Returns [`Error::ReqwestInvalid`] if the response was a non-OK (status
+    /// code 200) response, containing the response.
+    ///
+    /// Returns [`Error::ReqwestUnauthorized`] if the authorization token was
+    /// invalid.
+    ///
+    /// Returns [`Error::ForeignUrl`] if the link to the next page was not on
+    /// the API.
+    ///
+    /// [`Error::ForeignUrl`]: ../../enum.Error.html#variant.ForeignUrl
+    /// [`Error::Json`]: ../../enum.Error.html#variant.Json
+    /// [`Error::ReqwestBad`]: ../../enum.Error.html#variant.ReqwestBad
+    /// [`Error::ReqwestInvalid`]: ../../enum.Error.html#variant.ReqwestInvalid
+    /// [`Error::ReqwestUnauthorized`]: ../../enum.Error.html#variant.ReqwestUnauthorized
+    fn search_anime_stream<F: FnOnce(Search) -> Search>(&self, f: F) ->
+        impl Stream<Item = Result<Anime>> + Send;
+
+    /// Searches for a manga using the passed [`Search`] builder.
+    ///
+    /// [`Search`]: ../../builder/struct.Search.html
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use kitsu_io::KitsuAsyncRequester;
+    /// use reqwest::Client;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     // Create the reqwest Client.
+    ///     let client = Client::new();
+    ///
+    ///     let manga_name = "Say I Love You";
+    ///
+    ///     // Search for the manga.
+    ///     let manga = client.search_manga(|f| f.filter("text", manga_name)).await
+    ///         .expect("Error searching for manga");
+    ///
+    ///     // Do something with manga
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Json`] if there was an error parsing the response
+    /// body.
+    ///
+    /// Returns [`Error::ReqwestBad`] if the request was otherwise bad for some
+    /// reason, containing the response.
+    ///
+    /// Returns [`Error::ReqwestInvalid`] if the response was a non-OK (status
+    /// code 200) response, containing the response.
+    ///
+    /// Returns [`Error::ReqwestUnauthorized`] if the authorization token was
+    /// invalid.
+    ///
+    /// [`Error::Json`]: ../../enum.Error.html#variant.Json
+    /// [`Error::ReqwestBad`]: ../../enum.Error.html#variant.ReqwestBad
+    /// [`Error::ReqwestInvalid`]: ../../enum.Error.html#variant.ReqwestInvalid
+    /// [`Error::ReqwestUnauthorized`]: ../../enum.Error.html#variant.ReqwestUnauthorized
+    fn search_manga<F: FnOnce(Search) -> Search>(&self, f: F) ->
+        impl Future<Output = Result<Response<Vec<Manga>>>> + Send;
+
+    /// Searches for a user using the passed [`Search`] builder.
+    ///
+    /// [`Search`]: ../../builder/struct.Search.html
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use kitsu_io::KitsuAsyncRequester;
+    /// use reqwest::Client;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     // Create the reqwest Client.
+    ///     let client = Client::new();
+    ///
+    ///     let user_name = "Billy";
+    ///
+    ///     // Search for the user.
+    ///     let users = client.search_users(|f| f.filter("name", user_name)).await
+    ///         .expect("Error searching for user");
+    ///
+    ///     // Do something with users
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Json`] if there was an error parsing the response
+    /// body.
+    ///
+    /// Returns [`Error::ReqwestBad`] if the request was otherwise bad for some
+    /// reason, containing the response.
+    ///
+    /// Returns [`Error::ReqwestInvalid`] if the response was a non-OK (status
+    /// code 200) response, containing the response.
+    ///
+    /// Returns [`Error::ReqwestUnauthorized`] if the authorization token was
+    /// invalid.
+    ///
+    /// [`Error::Json`]: ../../enum.Error.html#variant.Json
+    /// [`Error::ReqwestBad`]: ../../enum.Error.html#variant.ReqwestBad
+    /// [`Error::ReqwestInvalid`]: ../../enum.Error.html#variant.ReqwestInvalid
+    /// [`Error::ReqwestUnauthorized`]: ../../enum.Error.html#variant.ReqwestUnauthorized
+    fn search_users<F: FnOnce(Search) -> Search>(&self, f: F) ->
+        impl Future<Output = Result<Response<Vec<User>>>> + Send;
+}
+
+impl<B: Base + Sync> KitsuRequester for B {
+    fn get_anime(&self, id: u64) -> impl Future<Output = Result<Response<Anime>>> + Send {
+        let request = url::Url::parse(&format!("{}/anime/{}", self.base_url(), id))
+            .map(|uri| self.client().get(uri));
+
+        async move { handle_request(request?).await }
+    }
+
+    fn get_manga(&self, id: u64) -> impl Future<Output = Result<Response<Manga>>> + Send {
+        let request = url::Url::parse(&format!("{}/manga/{}", self.base_url(), id))
+            .map(|uri| self.client().get(uri));
+
+        async move { handle_request(request?).await }
+    }
+
+    fn get_user(&self, id: u64) -> impl Future<Output = Result<Response<User>>> + Send {
+        let request = url::Url::parse(&format!("{}/users/{}", self.base_url(), id))
+            .map(|uri| self.client().get(uri));
+
+        async move { handle_request(request?).await }
+    }
+
+    fn search_anime<F: FnOnce(Search) -> Search>(&self, f: F) ->
+        impl Future<Output = Result<Response<Vec<Anime>>>> + Send {
+        let search = f(Search::default());
+        let request = search.validate()
+            .and_then(|_| {
+                url::Url::parse(&format!("{}/anime?{}", self.base_url(), search.as_query()))
+                    .map_err(From::from)
+            })
+            .map(|uri| self.client().get(uri));
+
+        async move { handle_request(request?).await }
+    }
+
+    fn search_anime_stream<F: FnOnce(Search) -> Search>(&self, f: F) ->
+        impl Stream<Item = Result<Anime>> + Send {
+        let search = f(Search::default());
+        let first = search.validate()
+            .and_then(|_| {
+                url::Url::parse(&format!("{}/anime?{}", self.base_url(), search.as_query()))
+                    .map_err(From::from)
+            });
+
+        paginate(self.client().clone(), self.base_url().to_owned(), first)
+    }
+
+    fn search_manga<F: FnOnce(Search) -> Search>(&self, f: F) ->
+        impl Future<Output = Result<Response<Vec<Manga>>>> + Send {
+        let search = f(Search::default());
+        let request = search.validate()
+            .and_then(|_| {
+                url::Url::parse(&format!("{}/manga?{}", self.base_url(), search.as_query()))
+                    .map_err(From::from)
+            })
+            .map(|uri| self.client().get(uri));
+
+        async move { handle_request(request?).await }
+    }
+
+    fn search_users<F: FnOnce(Search) -> Search>(&self, f: F) ->
+        impl Future<Output = Result<Response<Vec<User>>>> + Send {
+        let search = f(Search::default());
+        let request = search.validate()
+            .and_then(|_| {
+                url::Url::parse(&format!("{}/users?{}", self.base_url(), search.as_query()))
+                    .map_err(From::from)
+            })
+            .map(|uri| self.client().get(uri));
+
+        async move { handle_request(request?).await }
+    }
+}
+
+/// An asynchronous `reqwest` Client alongside the base URL of the API to make
+/// requests to.
+///
+/// The methods of [`KitsuAsyncRequester`] are available on the client, as they
+/// are on an asynchronous `reqwest` Client. The base URL defaults to
+/// [`API_URL`], and can be changed to point the client at a proxy or a mock
+/// server.
+///
+/// Cloning the client is cheap, and clones share the pool of connections of
+/// the wrapped `reqwest` Client.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use kitsu_io::{KitsuAsyncClient, KitsuAsyncRequester};
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let client = KitsuAsyncClient::new().with_base_url("http://localhost:8080");
+///
+///     let anime = client.get_anime(1).await.expect("Error getting anime");
+///
+///     // Do something with anime
+/// }
+/// ```
+///
+/// [`API_URL`]: ../../constant.API_URL.html
+/// [`KitsuAsyncRequester`]: trait.KitsuRequester.html
+#[derive(Clone, Debug)]
+pub struct KitsuAsyncClient {
+    base_url: String,
+    client: ReqwestClient,
+}
+
+impl KitsuAsyncClient {
+    /// Creates a new client with a default `reqwest` Client, which makes
+    /// requests to the [`API_URL`].
+    ///
+    /// [`API_URL`]: ../../constant.API_URL.html
+    pub fn new() -> Self {
+        Self::with_client(ReqwestClient::new())
+    }
+
+    /// Creates a new client wrapping an existing `reqwest` Client, which makes
+    /// requests to the [`API_URL`].
+    ///
+    /// [`API_URL`]: ../../constant.API_URL.html
+    pub fn with_client(client: ReqwestClient) -> Self {
+        KitsuAsyncClient {
+            base_url: API_URL.to_owned(),
+            client,
+        }
+    }
+
+    /// Sets the base URL of the API to make requests to, in place of the
+    /// [`API_URL`].
+    ///
+    /// A trailing slash is removed.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use kitsu_io::KitsuAsyncClient;
+    ///
+    /// let client = KitsuAsyncClient::new().with_base_url("http://localhost:8080/");
+    ///
+    /// assert_eq!(client.base_url(), "http://localhost:8080");
+    /// ```
+    ///
+    /// [`API_URL`]: ../../constant.API_URL.html
+    pub fn with_base_url(mut self, base_url: &str) -> Self {
+        self.base_url = base_url.trim_end_matches('/').to_owned();
+
+        self
+    }
+
+    /// The base URL of the API which requests are made to.
+    pub fn base_url(&self) -> &str {
+        &self.base_url
+    }
+
+    /// The `reqwest` Client which requests are sent with.
+    pub fn client(&self) -> &ReqwestClient {
+        &self.client
+    }
+}
+
+impl Default for KitsuAsyncClient {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Base for KitsuAsyncClient {
+    fn base_url(&self) -> &str {
+        &self.base_url
+    }
+
+    fn client(&self) -> &ReqwestClient {
+        &self.client
+    }
+}
+
+impl Base for ReqwestClient {
+    fn base_url(&self) -> &str {
+        API_URL
+    }
+
+    fn client(&self) -> &ReqwestClient {
+        self
+    }
+}
+
+pub(crate) mod sealed {
+    use reqwest::Client as ReqwestClient;
+
+    /// The client and base URL which requests are made with.
+    pub trait Base {
+        /// The base URL of the API, without a trailing slash.
+        fn base_url(&self) -> &str;
+
+        /// The `reqwest` client which requests are sent with.
+        fn client(&self) -> &ReqwestClient;
+    }
+}
+
+/// The state of a stream over the items of every page of a search.
+struct Pages<T> {
+    base_url: String,
+    client: ReqwestClient,
+    items: IntoIter<T>,
+    next: Option<Result<url::Url>>,
+}
+
+/// Streams the items of every page of a search, starting with the page at the
+/// first URL.
+///
+/// A page is only requested once every item of the previous page has been
+/// yielded, so at most one page is buffered. The link to each following page
+/// must be on the API at the base URL, or the stream yields an
+/// [`Error::ForeignUrl`] and ends.
+///
+/// [`Error::ForeignUrl`]: ../../enum.Error.html#variant.ForeignUrl
+fn paginate<T: DeserializeOwned + Send>(
+    client: ReqwestClient,
+    base_url: String,
+    first: Result<url::Url>,
+) -> impl Stream<Item = Result<T>> + Send {
+    let pages = Pages {
+        base_url,
+        client,
+        items: Vec::new().into_iter(),
+        next: Some(first),
+    };
+
+    stream::unfold(pages, |mut pages| async move {
+        loop {
+            if let Some(item) = pages.items.next() {
+                return Some((Ok(item), pages));
+            }
+
+            let page = match pages.next.take()? {
+                Ok(uri) => handle_request::<Response<Vec<T>>>(pages.client.get(uri)).await,
+                Err(why) => Err(why),
+            };
+
+            match page {
+                Ok(mut page) => {
+                    pages.next = next_link(&pages.base_url, &mut page);
+                    pages.items = page.data.into_iter();
+                },
+                Err(why) => return Some((Err(why), pages)),
+            }
+        }
+    })
+}
+
+async fn handle_request<T: DeserializeOwned>(request: RequestBuilder) -> Result<T> {
+    let request_id = request_id();
+    let response = request
+        .header(ACCEPT, JSON_API)
+        .header(X_REQUEST_ID, request_id.as_str())
+        .send()
+        .await?;
+    let status = response.status();
+
+    if status == StatusCode::OK {
+        return from_bytes(&response.bytes().await?, &request_id);
+    }
+
+    let headers = response.headers().clone();
+    let body = response.bytes().await.unwrap_or_default();
+
+    Err(error_from_parts(status, &headers, &body, request_id))
+}
@@ -1,53 +1,687 @@
 //! A set of builders for ease of use with optional parameters around the API.
 
-use std::fmt::Write;
+use std::fmt::{Arguments, Write};
+use url::form_urlencoded;
+use crate::{Error, Result};
+
+/// The filter keys documented by the API as supported when searching for
+/// anime.
+pub const ANIME_FILTERS: &[&str] = &[
+    "ageRating",
+    "averageRating",
+    "categories",
+    "episodeCount",
+    "episodeLength",
+    "genres",
+    "id",
+    "season",
+    "seasonYear",
+    "slug",
+    "status",
+    "streamers",
+    "subtype",
+    "text",
+    "year",
+];
+
+/// The filter keys documented by the API as supported when searching for
+/// manga.
+pub const MANGA_FILTERS: &[&str] = &[
+    "averageRating",
+    "categories",
+    "chapterCount",
+    "genres",
+    "id",
+    "slug",
+    "status",
+    "subtype",
+    "text",
+    "volumeCount",
+    "year",
+];
+
+/// The filter keys documented by the API as supported when searching for
+/// users.
+pub const USER_FILTERS: &[&str] = &[
+    "id",
+    "name",
+    "query",
+    "self",
+    "slug",
+];
+
+/// Retrieves the filter keys supported by the given resource type, if it is
+/// known.
+///
+/// The API does not advertise its supported filters, so this is a static list
+/// which can be used to validate filter keys before making a request.
+///
+/// # Examples
+///
+/// ```rust
+/// use kitsu_io::builder::supported_filters;
+///
+/// assert!(supported_filters("anime").unwrap().contains(&"season"));
+/// assert!(supported_filters("episodes").is_none());
+/// ```
+pub fn supported_filters(resource: &str) -> Option<&'static [&'static str]> {
+    match resource {
+        "anime" => Some(ANIME_FILTERS),
+        "manga" => Some(MANGA_FILTERS),
+        "users" => Some(USER_FILTERS),
+        _ => None,
+    }
+}
 
 /// Filters search results.
 ///
 /// The following are filters in addition to each search type's fields:
 ///
-/// - `search_anime`: `season`, `streamers`, `text`
-/// - `search_manga]: `text`
-#[derive(Default)]
-pub struct Search(pub String);
+/// - `search_anime`: `season`, `status`, `streamers`, `text`
+/// - `search_manga`: `text`
+///
+/// Setting a parameter which has already been set follows these rules:
+///
+/// - [`filter`] and [`filter_in`] merge the values for the same key into one
+///   filter, as Kitsu only reads one value per filter key.
+/// - [`include`] merges the relationships into one `include` parameter.
+/// - [`fields`] replaces the selection for the same type of resource.
+/// - Scalar parameters, set by [`filter_range`], [`limit`], [`offset`],
+///   [`page_number`], [`page_size`], [`season_year`], [`sort`] and
+///   [`sort_by`], replace the previous value in place.
+/// - [`raw_param`] always appends, without merging or replacing.
+///
+/// A search can be cloned to build variations of a common base search. The
+/// built query is retrieved using [`as_query`].
+///
+/// [`as_query`]: #method.as_query
+/// [`fields`]: #method.fields
+/// [`filter`]: #method.filter
+/// [`filter_in`]: #method.filter_in
+/// [`filter_range`]: #method.filter_range
+/// [`include`]: #method.include
+/// [`limit`]: #method.limit
+/// [`offset`]: #method.offset
+/// [`page_number`]: #method.page_number
+/// [`page_size`]: #method.page_size
+/// [`raw_param`]: #method.raw_param
+/// [`season_year`]: #method.season_year
+/// [`sort`]: #method.sort
+/// [`sort_by`]: #method.sort_by
+#[derive(Clone, Debug, Default)]
+pub struct Search(String);
 
 impl Search {
+    /// Creates a new, empty search. This is the same as the `Default`
+    /// implementation.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use kitsu_io::builder::Search;
+    ///
+    /// let base = Search::new().filter("text", "gundam").limit(5);
+    ///
+    /// let oldest = base.clone().sort("startDate");
+    /// let newest = base.sort("-startDate");
+    ///
+    /// assert_eq!(oldest.as_query(), "filter[text]=gundam&page[limit]=5&sort=startDate");
+    /// assert_eq!(newest.as_query(), "filter[text]=gundam&page[limit]=5&sort=-startDate");
+    /// ```
+    pub fn new() -> Self {
+        Search::default()
+    }
+
+    /// The built query string, without a leading `?` or `&`, which can be
+    /// appended to a URL after a `?`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use kitsu_io::builder::Search;
+    ///
+    /// let search = Search::new().filter("text", "gundam").limit(5);
+    ///
+    /// assert_eq!(search.as_query(), "filter[text]=gundam&page[limit]=5");
+    /// assert_eq!(Search::new().as_query(), "");
+    /// ```
+    pub fn as_query(&self) -> &str {
+        &self.0
+    }
+
+    /// Selects the only fields to return for a type of resource, reducing the
+    /// size of the response.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use kitsu_io::builder::Search;
+    ///
+    /// let search = Search::default()
+    ///     .fields("anime", &["canonicalTitle", "averageRating"])
+    ///     .fields("genres", &["name"]);
+    ///
+    /// assert_eq!(
+    ///     search.as_query(),
+    ///     "fields[anime]=canonicalTitle,averageRating&fields[genres]=name",
+    /// );
+    /// ```
+    pub fn fields(self, resource_type: &str, fields: &[&str]) -> Self {
+        let key = format!("fields[{}]", encode(resource_type));
+        let fields = fields.iter()
+            .map(|field| encode(field))
+            .collect::<Vec<_>>()
+            .join(",");
+
+        self.set_param(&key, &fields)
+    }
+
     /// Filters results by a key and value.
-    pub fn filter(mut self, key: &str, value: &str) -> Self {
-        let _ = write!(self.0, "&filter[{}]={}", key, value);
+    ///
+    /// The key and value are percent-encoded. Filtering by a key which has
+    /// already been filtered on matches results with any of the values, as
+    /// with [`filter_in`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use kitsu_io::builder::Search;
+    ///
+    /// let search = Search::default().filter("text", "Fate/stay night");
+    ///
+    /// assert_eq!(search.as_query(), "filter[text]=Fate%2Fstay+night");
+    ///
+    /// let search = Search::default()
+    ///     .filter("categories", "action")
+    ///     .filter("categories", "comedy");
+    ///
+    /// assert_eq!(search.as_query(), "filter[categories]=action,comedy");
+    /// ```
+    ///
+    /// [`filter_in`]: #method.filter_in
+    pub fn filter(self, key: &str, value: &str) -> Self {
+        self.filter_in(key, &[value])
+    }
 
-        self
+    /// Filters results by a key matching any of a list of values, such as to
+    /// retrieve multiple resources by their ids in one request.
+    ///
+    /// The key and each value are percent-encoded, and the values are joined
+    /// with a comma (`','`).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use kitsu_io::builder::Search;
+    ///
+    /// let search = Search::default().filter_in("id", &["1", "2", "3"]);
+    ///
+    /// assert_eq!(search.as_query(), "filter[id]=1,2,3");
+    /// ```
+    pub fn filter_in(self, key: &str, values: &[&str]) -> Self {
+        let key = format!("filter[{}]", encode(key));
+        let mut merged = self.param(&key)
+            .filter(|value| !value.is_empty())
+            .map(|value| value.split(',').map(str::to_owned).collect::<Vec<_>>())
+            .unwrap_or_default();
+
+        for value in values {
+            let value = encode(value);
+
+            if !merged.contains(&value) {
+                merged.push(value);
+            }
+        }
+
+        self.set_param(&key, &merged.join(","))
+    }
+
+    /// Filters results by a numeric key being within a range, such as anime
+    /// with an average rating of at least `70`.
+    ///
+    /// Either bound may be omitted to leave that end of the range open. If
+    /// neither bound is given, then no filter is added.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use kitsu_io::builder::Search;
+    ///
+    /// let search = Search::default()
+    ///     .filter_range("averageRating", Some(70.0), None)
+    ///     .filter_range("episodeCount", Some(12.0), Some(26.0));
+    ///
+    /// assert_eq!(search.as_query(), "filter[averageRating]=70..&filter[episodeCount]=12..26");
+    /// ```
+    pub fn filter_range(self, key: &str, min: Option<f64>, max: Option<f64>) -> Self {
+        if min.is_none() && max.is_none() {
+            return self;
+        }
+
+        let bound = |value: Option<f64>| value.map(|value| value.to_string()).unwrap_or_default();
+
+        let key = format!("filter[{}]", encode(key));
+
+        self.set_param(&key, &format!("{}..{}", bound(min), bound(max)))
+    }
+
+    /// Sideloads related resources into the [`included`] resources of the
+    /// response, avoiding the need to follow relationship links.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use kitsu_io::builder::Search;
+    ///
+    /// let search = Search::default()
+    ///     .include(&["genres"])
+    ///     .include(&["categories", "genres"]);
+    ///
+    /// assert_eq!(search.as_query(), "include=genres,categories");
+    /// ```
+    ///
+    /// [`included`]: ../model/struct.Response.html#structfield.included
+    pub fn include(self, relationships: &[&str]) -> Self {
+        let mut included = self.param("include")
+            .map(|value| value.split(',').map(str::to_owned).collect::<Vec<_>>())
+            .unwrap_or_default();
+
+        for relationship in relationships {
+            let relationship = encode(relationship);
+
+            if !included.contains(&relationship) {
+                included.push(relationship);
+            }
+        }
+
+        self.set_param("include", &included.join(","))
     }
 
     /// Sets a limit to the number of results that can be returned.
     ///
-    /// This is used for pagination, in conjunction with [`offset`].
+    /// This is used for pagination, in conjunction with [`offset`]. This must
+    /// not be mixed with [`page_number`] and [`page_size`] in one request.
     ///
     /// [`offset`]: #method.offset
-    pub fn limit(mut self, limit: u64) -> Self {
-        let _ = write!(self.0, "&page[limit]={}", limit);
-
-        self
+    /// [`page_number`]: #method.page_number
+    /// [`page_size`]: #method.page_size
+    pub fn limit(self, limit: u64) -> Self {
+        self.set_param("page[limit]", &limit.to_string())
     }
 
     /// Sets an offset to the number of results that can be returned.
     ///
-    /// This is used for pagination, in conjunction with [`limit`].
+    /// This is used for pagination, in conjunction with [`limit`]. This must
+    /// not be mixed with [`page_number`] and [`page_size`] in one request.
     ///
     /// [`limit`]: #method.limit
-    pub fn offset(mut self, offset: u64) -> Self {
-        let _ = write!(self.0, "&page[offset]={}", offset);
+    /// [`page_number`]: #method.page_number
+    /// [`page_size`]: #method.page_size
+    pub fn offset(self, offset: u64) -> Self {
+        self.set_param("page[offset]", &offset.to_string())
+    }
 
-        self
+    /// Sets the number of the page of results to return, starting from `1`.
+    ///
+    /// This is used for number-based pagination, in conjunction with
+    /// [`page_size`].
+    ///
+    /// Kitsu rejects requests which mix number-based pagination with the
+    /// offset-based [`limit`] and [`offset`], so pick one style per request.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use kitsu_io::builder::Search;
+    ///
+    /// let search = Search::default().page_size(20).page_number(3);
+    ///
+    /// assert_eq!(search.as_query(), "page[size]=20&page[number]=3");
+    /// ```
+    ///
+    /// [`limit`]: #method.limit
+    /// [`offset`]: #method.offset
+    /// [`page_size`]: #method.page_size
+    pub fn page_number(self, number: u64) -> Self {
+        self.set_param("page[number]", &number.to_string())
+    }
+
+    /// Sets the number of results in each page.
+    ///
+    /// This is used for number-based pagination, in conjunction with
+    /// [`page_number`].
+    ///
+    /// Kitsu rejects requests which mix number-based pagination with the
+    /// offset-based [`limit`] and [`offset`], so pick one style per request.
+    ///
+    /// [`limit`]: #method.limit
+    /// [`offset`]: #method.offset
+    /// [`page_number`]: #method.page_number
+    pub fn page_size(self, size: u64) -> Self {
+        self.set_param("page[size]", &size.to_string())
+    }
+
+    /// Appends an arbitrary parameter to the query, for parameters which the
+    /// builder does not otherwise support.
+    ///
+    /// The key and value are percent-encoded, except for the square brackets
+    /// of the key, so that a key such as `filter[subtype]` is sent as written.
+    /// The parameter is not checked against any parameters set by the other
+    /// methods.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use kitsu_io::builder::Search;
+    ///
+    /// let search = Search::default()
+    ///     .filter("text", "orange")
+    ///     .raw_param("filter[subtype]", "TV special");
+    ///
+    /// assert_eq!(search.as_query(), "filter[text]=orange&filter[subtype]=TV+special");
+    /// ```
+    pub fn raw_param(self, key: &str, value: &str) -> Self {
+        let key = encode(key).replace("%5B", "[").replace("%5D", "]");
+
+        self.push(format_args!("{}={}", key, encode(value)))
+    }
+
+    /// Renders the query with its parameters in a deterministic, sorted order.
+    ///
+    /// Searches with the same parameters produce the same canonical query
+    /// regardless of the order in which the parameters were added, making it
+    /// suitable for use as a cache key. The comma-separated values of a
+    /// parameter such as a filter or `include` are sorted too, except those of
+    /// `sort`, whose order changes the results.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use kitsu_io::builder::Search;
+    ///
+    /// let a = Search::default().filter("text", "orange").limit(5);
+    /// let b = Search::default().limit(5).filter("text", "orange");
+    ///
+    /// assert_eq!(a.canonical_query(), b.canonical_query());
+    /// assert_eq!(a.canonical_query(), "filter[text]=orange&page[limit]=5");
+    /// ```
+    pub fn canonical_query(&self) -> String {
+        let mut params = self.0.split('&')
+            .filter(|param| !param.is_empty())
+            .map(|param| match param.split_once('=') {
+                Some((key, value)) if key != "sort" && value.contains(',') => {
+                    let mut values = value.split(',').collect::<Vec<_>>();
+                    values.sort_unstable();
+
+                    format!("{}={}", key, values.join(","))
+                },
+                _ => param.to_owned(),
+            })
+            .collect::<Vec<_>>();
+        params.sort();
+
+        params.join("&")
+    }
+
+    /// Filters results by the server-side release status of the media.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use kitsu_io::builder::{MediaStatus, Search};
+    ///
+    /// let search = Search::default().status(MediaStatus::Current);
+    ///
+    /// assert_eq!(search.as_query(), "filter[status]=current");
+    /// ```
+    pub fn status(self, status: MediaStatus) -> Self {
+        self.filter("status", status.name())
+    }
+
+    /// Filters anime by the season in which they started airing.
+    ///
+    /// This is commonly used in conjunction with [`season_year`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use kitsu_io::builder::{Search, Season};
+    ///
+    /// let search = Search::default().season(Season::Spring).season_year(1998);
+    ///
+    /// assert_eq!(search.as_query(), "filter[season]=spring&filter[seasonYear]=1998");
+    /// ```
+    ///
+    /// [`season_year`]: #method.season_year
+    pub fn season(self, season: Season) -> Self {
+        self.filter("season", season.name())
+    }
+
+    /// Filters anime by the year of the season in which they started airing.
+    ///
+    /// This is commonly used in conjunction with [`season`].
+    ///
+    /// [`season`]: #method.season
+    pub fn season_year(self, year: u16) -> Self {
+        self.set_param("filter[seasonYear]", &year.to_string())
     }
 
     /// Sets a sorting order to use by specifying fields.
     ///
     /// `id` will sort ascending, while `-id` will sort descending. Multiple
     /// sorters can be provided by joining with a comma (`','`).
-    pub fn sort(mut self, sort: &str) -> Self {
-        let _ = write!(self.0, "&sort={}", sort);
+    ///
+    /// The sort is percent-encoded.
+    pub fn sort(self, sort: &str) -> Self {
+        self.set_param("sort", &encode(sort))
+    }
+
+    /// Sets a sorting order to use by a list of fields, in order of priority.
+    ///
+    /// This is an alternative to [`sort`] which adds the `-` prefix for
+    /// descending fields and joins the fields with a comma (`','`). Each field
+    /// is percent-encoded. If no fields are given, then no sort is added.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use kitsu_io::builder::{Search, SortField};
+    ///
+    /// let search = Search::default().sort_by(&[
+    ///     SortField {
+    ///         field: "averageRating".to_owned(),
+    ///         descending: true,
+    ///     },
+    ///     SortField {
+    ///         field: "startDate".to_owned(),
+    ///         descending: false,
+    ///     },
+    /// ]);
+    ///
+    /// assert_eq!(search.as_query(), "sort=-averageRating,startDate");
+    /// ```
+    ///
+    /// [`sort`]: #method.sort
+    pub fn sort_by(self, fields: &[SortField]) -> Self {
+        if fields.is_empty() {
+            return self;
+        }
+
+        let fields = fields.iter()
+            .map(|sort| {
+                let prefix = if sort.descending { "-" } else { "" };
+
+                format!("{}{}", prefix, encode(&sort.field))
+            })
+            .collect::<Vec<_>>()
+            .join(",");
+
+        self.set_param("sort", &fields)
+    }
+
+    /// Checks that the search can be sent, such as that it does not mix
+    /// offset-based pagination using [`limit`] and [`offset`] with
+    /// number-based pagination using [`page_number`] and [`page_size`].
+    ///
+    /// This is done by the requesters before a search is sent, so that an
+    /// invalid search does not make a request.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use kitsu_io::builder::Search;
+    ///
+    /// assert!(Search::default().limit(10).offset(20).validate().is_ok());
+    /// assert!(Search::default().limit(10).page_number(2).validate().is_err());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidSearch`] if the search mixes pagination styles.
+    ///
+    /// [`Error::InvalidSearch`]: ../enum.Error.html#variant.InvalidSearch
+    /// [`limit`]: #method.limit
+    /// [`offset`]: #method.offset
+    /// [`page_number`]: #method.page_number
+    /// [`page_size`]: #method.page_size
+    pub fn validate(&self) -> Result<()> {
+        let offset_based = ["page[limit]", "page[offset]"].iter()
+            .any(|key| self.param(key).is_some());
+        let number_based = ["page[number]", "page[size]"].iter()
+            .any(|key| self.param(key).is_some());
+
+        if offset_based && number_based {
+            return Err(Error::InvalidSearch(
+                "page[limit] and page[offset] can not be mixed with page[number] and \
+                 page[size]".to_owned(),
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// Appends a parameter, separating it from any existing parameters with an
+    /// ampersand (`'&'`).
+    fn push(mut self, param: Arguments) -> Self {
+        if !self.0.is_empty() {
+            self.0.push('&');
+        }
+
+        let _ = self.0.write_fmt(param);
 
         self
     }
+
+    /// Retrieves the raw value of a parameter, if it has been set.
+    fn param(&self, key: &str) -> Option<&str> {
+        let prefix = format!("{}=", key);
+
+        self.0.split('&')
+            .find(|param| param.starts_with(&prefix))
+            .map(|param| &param[prefix.len()..])
+    }
+
+    /// Sets the raw value of a parameter, replacing its existing value in place
+    /// if it has already been set, and removing any repeats of it.
+    fn set_param(mut self, key: &str, value: &str) -> Self {
+        if self.param(key).is_none() {
+            return self.push(format_args!("{}={}", key, value));
+        }
+
+        let prefix = format!("{}=", key);
+        let mut replaced = false;
+        self.0 = self.0.split('&')
+            .filter_map(|param| if !param.starts_with(&prefix) {
+                Some(param.to_owned())
+            } else if replaced {
+                None
+            } else {
+                replaced = true;
+
+                Some(format!("{}{}", prefix, value))
+            })
+            .collect::<Vec<_>>()
+            .join("&");
+
+        self
+    }
+}
+
+/// A field to sort results by, as used by [`Search::sort_by`].
+///
+/// [`Search::sort_by`]: struct.Search.html#method.sort_by
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct SortField {
+    /// The name of the field to sort by.
+    ///
+    /// # Examples
+    ///
+    /// `averageRating`
+    pub field: String,
+    /// Whether to sort by the field in descending order, rather than
+    /// ascending order.
+    pub descending: bool,
+}
+
+/// Percent-encodes a query string component.
+fn encode(value: &str) -> String {
+    form_urlencoded::byte_serialize(value.as_bytes()).collect()
+}
+
+/// The release status of a media item, as filtered on by the API.
+///
+/// This is the filter counterpart of the [`AiringStatusKind`] given in an
+/// anime's attributes.
+///
+/// [`AiringStatusKind`]: ../model/enum.AiringStatusKind.html
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum MediaStatus {
+    /// Indicator that the media is currently releasing.
+    Current,
+    /// Indicator that the media has finished releasing.
+    Finished,
+    /// Indicator that the release date of the media is yet to be announced.
+    Tba,
+    /// Indicator that the media has not been released.
+    Unreleased,
+    /// Indicator that the media has a release date in the near future.
+    Upcoming,
+}
+
+impl MediaStatus {
+    /// The name of the media status, as used by the API.
+    pub fn name(&self) -> &'static str {
+        match *self {
+            MediaStatus::Current => "current",
+            MediaStatus::Finished => "finished",
+            MediaStatus::Tba => "tba",
+            MediaStatus::Unreleased => "unreleased",
+            MediaStatus::Upcoming => "upcoming",
+        }
+    }
+}
+
+/// A season of the year in which anime air, as filtered on by the API.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Season {
+    /// Indicator of the winter season, from January to March.
+    Winter,
+    /// Indicator of the spring season, from April to June.
+    Spring,
+    /// Indicator of the summer season, from July to September.
+    Summer,
+    /// Indicator of the fall season, from October to December.
+    Fall,
+}
+
+impl Season {
+    /// The name of the season, as used by the API.
+    pub fn name(&self) -> &'static str {
+        match *self {
+            Season::Winter => "winter",
+            Season::Spring => "spring",
+            Season::Summer => "summer",
+            Season::Fall => "fall",
+        }
+    }
 }
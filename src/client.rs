@@ -0,0 +1,923 @@
+//! A client which makes requests to a configurable base URL.
+//!
+//! # Examples
+//!
+//! Refer to the documentation for [`KitsuClient`].
+//!
+//! [`KitsuClient`]: struct.KitsuClient.html
+
+use crate::bridge::reqwest::sealed::{self, Base, EtagCache, RawRequest, RawResponse};
+use crate::bridge::reqwest::{KitsuRequester, Paginated, RetryPolicy};
+use crate::builder::Search;
+use crate::model::{Anime, AnimeProduction, Casting, Category, Chapter, Character, Comment, Episode, Group, Installment, LibraryEntry, LibraryEvent, Manga, Mapping, MediaReaction, MediaRelationship, Person, Post, Response, Review, StreamingLink, User};
+use crate::{Result, API_URL};
+use reqwest::blocking::Client as ReqwestClient;
+use reqwest::Proxy;
+use serde::de::DeserializeOwned;
+use std::sync::Arc;
+use std::time::Duration;
+
+/// The default `User-Agent` sent with requests by a [`KitsuClient`], which
+/// identifies the library to Kitsu.
+///
+/// [`KitsuClient`]: struct.KitsuClient.html
+pub const USER_AGENT: &str = concat!(
+    "kitsu_io/",
+    env!("CARGO_PKG_VERSION"),
+    " (+https://github.com/zeyla/kitsu.rs)",
+);
+
+/// The maximum number of responses kept by the `ETag` cache of a
+/// [`KitsuClient`], after which the least recently used response is evicted.
+///
+/// [`KitsuClient`]: struct.KitsuClient.html
+pub const ETAG_CACHE_CAPACITY: usize = 128;
+
+/// A client which wraps a `reqwest` Client alongside the base URL of the API
+/// to make requests to.
+///
+/// The methods of [`KitsuReqwestRequester`] are available on the client as
+/// inherent methods, so the trait only needs to be imported to write code
+/// which is generic over requesters. The trait and the client can be imported
+/// together from the [`prelude`]. The base URL defaults to [`API_URL`], and
+/// can be changed to point the client at a proxy or a mock server.
+///
+/// Requests are sent with the [`USER_AGENT`] by default, which can be changed
+/// using a [`KitsuClientBuilder`]. Responses may be compressed with gzip, which
+/// are decompressed transparently.
+///
+/// Requests are sent through the proxies given in the `HTTP_PROXY` and
+/// `HTTPS_PROXY` environment variables, if they are set, unless a proxy is
+/// configured using a [`KitsuClientBuilder`].
+///
+/// Responses can optionally be cached by their `ETag`, which is disabled by
+/// default. Refer to [`with_etag_cache`] for how the cache is used.
+///
+/// Cloning the client is cheap, and clones share the pool of connections of
+/// the wrapped `reqwest` Client, as well as the cache of responses.
+///
+/// # Examples
+///
+/// Create a client which makes requests to a local mock server:
+///
+/// ```rust,no_run
+/// extern crate kitsu_io;
+///
+/// use kitsu_io::KitsuClient;
+///
+/// fn main() {
+///     let client = KitsuClient::new().with_base_url("http://localhost:8080");
+///
+///     let anime = client.get_anime(1).expect("Error getting anime");
+///
+///     // Do something with anime
+/// }
+/// ```
+///
+/// [`API_URL`]: ../constant.API_URL.html
+/// [`KitsuClientBuilder`]: struct.KitsuClientBuilder.html
+/// [`KitsuReqwestRequester`]: ../bridge/reqwest/trait.KitsuRequester.html
+/// [`USER_AGENT`]: constant.USER_AGENT.html
+/// [`prelude`]: ../prelude/index.html
+/// [`with_etag_cache`]: #method.with_etag_cache
+#[derive(Clone, Debug)]
+pub struct KitsuClient {
+    base_url: String,
+    client: ReqwestClient,
+    etag_cache: Option<Arc<EtagCache>>,
+}
+
+impl KitsuClient {
+    /// Creates a new client with the default configuration, which makes
+    /// requests to the [`API_URL`] with the [`USER_AGENT`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the `reqwest` Client could not be created, such as if a TLS
+    /// backend could not be initialized. Use [`builder`] to handle the error
+    /// instead.
+    ///
+    /// [`API_URL`]: ../constant.API_URL.html
+    /// [`USER_AGENT`]: constant.USER_AGENT.html
+    /// [`builder`]: #method.builder
+    pub fn new() -> Self {
+        Self::builder().build().expect("Error creating the reqwest Client")
+    }
+
+    /// Creates a builder to configure a new client.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use kitsu_io::KitsuClient;
+    ///
+    /// let client = KitsuClient::builder()
+    ///     .user_agent("my-app/1.0")
+    ///     .build()
+    ///     .expect("Error creating client");
+    /// ```
+    pub fn builder() -> KitsuClientBuilder {
+        KitsuClientBuilder::default()
+    }
+
+    /// Creates a new client wrapping an existing `reqwest` Client, which makes
+    /// requests to the [`API_URL`].
+    ///
+    /// The Client is used as-is, so requests are sent with its `User-Agent`
+    /// rather than the [`USER_AGENT`].
+    ///
+    /// [`API_URL`]: ../constant.API_URL.html
+    /// [`USER_AGENT`]: constant.USER_AGENT.html
+    pub fn with_client(client: ReqwestClient) -> Self {
+        KitsuClient {
+            base_url: API_URL.to_owned(),
+            client,
+            etag_cache: None,
+        }
+    }
+
+    /// Sets the base URL of the API to make requests to, in place of the
+    /// [`API_URL`].
+    ///
+    /// A trailing slash is removed.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use kitsu_io::KitsuClient;
+    ///
+    /// let client = KitsuClient::new().with_base_url("http://localhost:8080/");
+    ///
+    /// assert_eq!(client.base_url(), "http://localhost:8080");
+    /// ```
+    ///
+    /// [`API_URL`]: ../constant.API_URL.html
+    pub fn with_base_url(mut self, base_url: &str) -> Self {
+        self.base_url = base_url.trim_end_matches('/').to_owned();
+
+        self
+    }
+
+    /// Sets whether responses are cached by their `ETag` to make conditional
+    /// requests, which is disabled by default.
+    ///
+    /// When enabled, responses which have an `ETag` are cached in memory by the
+    /// URL requested. Repeated requests to the URL send the `ETag` in an
+    /// `If-None-Match` header, and use the cached body if the API responds that
+    /// it has not been modified, saving bandwidth when polling the same
+    /// resources.
+    ///
+    /// Each cached response keeps its full body in memory, for up to
+    /// [`ETAG_CACHE_CAPACITY`] responses, after which the least recently used
+    /// response is evicted. Requests with a query, such as searches and the
+    /// pages of a search, are never cached.
+    ///
+    /// Disabling the cache removes any cached responses from this client, but
+    /// not from clones which were made before it was disabled.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use kitsu_io::KitsuClient;
+    ///
+    /// let client = KitsuClient::new().with_etag_cache(true);
+    /// ```
+    ///
+    /// [`ETAG_CACHE_CAPACITY`]: constant.ETAG_CACHE_CAPACITY.html
+    pub fn with_etag_cache(mut self, enabled: bool) -> Self {
+        self.etag_cache = match (enabled, self.etag_cache.take()) {
+            (true, Some(cache)) => Some(cache),
+            (true, None) => Some(Arc::default()),
+            (false, _) => None,
+        };
+
+        self
+    }
+
+    /// Removes every response cached by its `ETag`, including those cached by
+    /// clones of the client, so that the next request to each URL is made
+    /// unconditionally.
+    pub fn clear_etag_cache(&self) {
+        if let Some(ref cache) = self.etag_cache {
+            cache.clear();
+        }
+    }
+
+    /// The base URL of the API which requests are made to.
+    pub fn base_url(&self) -> &str {
+        &self.base_url
+    }
+
+    /// The `reqwest` Client which requests are sent with.
+    pub fn client(&self) -> &ReqwestClient {
+        &self.client
+    }
+}
+
+impl Default for KitsuClient {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Base for KitsuClient {
+    fn base_url(&self) -> &str {
+        &self.base_url
+    }
+
+    fn send(&self, request: RawRequest) -> Result<RawResponse> {
+        sealed::send_reqwest(&self.client, request)
+    }
+
+    fn etag_cache(&self) -> Option<&EtagCache> {
+        self.etag_cache.as_deref()
+    }
+}
+
+/// The methods of [`KitsuReqwestRequester`], which delegate to the trait.
+///
+/// [`KitsuReqwestRequester`]: ../bridge/reqwest/trait.KitsuRequester.html
+impl KitsuClient {
+    /// Checks whether an anime with the given id exists.
+    ///
+    /// Refer to [`KitsuReqwestRequester::anime_exists`] for examples and errors.
+    ///
+    /// [`KitsuReqwestRequester::anime_exists`]: ../bridge/reqwest/trait.KitsuRequester.html#tymethod.anime_exists
+    pub fn anime_exists(&self, id: u64) -> Result<bool> {
+        KitsuRequester::anime_exists(self, id)
+    }
+
+    /// Gets an anime using its id.
+    ///
+    /// Refer to [`KitsuReqwestRequester::get_anime`] for examples and errors.
+    ///
+    /// [`KitsuReqwestRequester::get_anime`]: ../bridge/reqwest/trait.KitsuRequester.html#tymethod.get_anime
+    pub fn get_anime(&self, id: u64) -> Result<Response<Anime>> {
+        KitsuRequester::get_anime(self, id)
+    }
+
+    /// Gets a manga using its id.
+    ///
+    /// Refer to [`KitsuReqwestRequester::get_manga`] for examples and errors.
+    ///
+    /// [`KitsuReqwestRequester::get_manga`]: ../bridge/reqwest/trait.KitsuRequester.html#tymethod.get_manga
+    pub fn get_manga(&self, id: u64) -> Result<Response<Manga>> {
+        KitsuRequester::get_manga(self, id)
+    }
+
+    /// Gets a user using their id.
+    ///
+    /// Refer to [`KitsuReqwestRequester::get_user`] for examples and errors.
+    ///
+    /// [`KitsuReqwestRequester::get_user`]: ../bridge/reqwest/trait.KitsuRequester.html#tymethod.get_user
+    pub fn get_user(&self, id: u64) -> Result<Response<User>> {
+        KitsuRequester::get_user(self, id)
+    }
+
+    /// Gets a random anime.
+    ///
+    /// Refer to [`KitsuReqwestRequester::random_anime`] for examples and errors.
+    ///
+    /// [`KitsuReqwestRequester::random_anime`]: ../bridge/reqwest/trait.KitsuRequester.html#tymethod.random_anime
+    pub fn random_anime(&self) -> Result<Option<Anime>> {
+        KitsuRequester::random_anime(self)
+    }
+
+    /// Gets the most recently updated anime, limited to the given number of
+    /// results.
+    ///
+    /// Refer to [`KitsuReqwestRequester::recently_updated_anime`] for examples and errors.
+    ///
+    /// [`KitsuReqwestRequester::recently_updated_anime`]: ../bridge/reqwest/trait.KitsuRequester.html#tymethod.recently_updated_anime
+    pub fn recently_updated_anime(&self, limit: u64) -> Result<Response<Vec<Anime>>> {
+        KitsuRequester::recently_updated_anime(self, limit)
+    }
+
+    /// Searches for anime using the passed [`Search`] builder.
+    ///
+    /// Refer to [`KitsuReqwestRequester::search_anime`] for examples and errors.
+    ///
+    /// [`KitsuReqwestRequester::search_anime`]: ../bridge/reqwest/trait.KitsuRequester.html#tymethod.search_anime
+    /// [`Search`]: ../builder/struct.Search.html
+    pub fn search_anime<F: FnOnce(Search) -> Search>(&self, f: F) -> Result<Response<Vec<Anime>>> {
+        KitsuRequester::search_anime(self, f)
+    }
+
+    /// Searches for manga using the passed [`Search`] builder.
+    ///
+    /// Refer to [`KitsuReqwestRequester::search_manga`] for examples and errors.
+    ///
+    /// [`KitsuReqwestRequester::search_manga`]: ../bridge/reqwest/trait.KitsuRequester.html#tymethod.search_manga
+    /// [`Search`]: ../builder/struct.Search.html
+    pub fn search_manga<F: FnOnce(Search) -> Search>(&self, f: F) -> Result<Response<Vec<Manga>>> {
+        KitsuRequester::search_manga(self, f)
+    }
+
+    /// Searches for users using the passed [`Search`] builder.
+    ///
+    /// Refer to [`KitsuReqwestRequester::search_users`] for examples and errors.
+    ///
+    /// [`KitsuReqwestRequester::search_users`]: ../bridge/reqwest/trait.KitsuRequester.html#tymethod.search_users
+    /// [`Search`]: ../builder/struct.Search.html
+    pub fn search_users<F: FnOnce(Search) -> Search>(&self, f: F) -> Result<Response<Vec<User>>> {
+        KitsuRequester::search_users(self, f)
+    }
+
+    /// Gets the names of the genres of an anime using the anime's id.
+    ///
+    /// Refer to [`KitsuReqwestRequester::get_anime_genre_names`] for examples and errors.
+    ///
+    /// [`KitsuReqwestRequester::get_anime_genre_names`]: ../bridge/reqwest/trait.KitsuRequester.html#tymethod.get_anime_genre_names
+    pub fn get_anime_genre_names(&self, anime_id: u64) -> Result<Vec<String>> {
+        KitsuRequester::get_anime_genre_names(self, anime_id)
+    }
+
+    /// Searches for anime by text, falling back to matching on the canonical
+    /// title.
+    ///
+    /// Refer to [`KitsuReqwestRequester::search_anime_fuzzy`] for examples and errors.
+    ///
+    /// [`KitsuReqwestRequester::search_anime_fuzzy`]: ../bridge/reqwest/trait.KitsuRequester.html#tymethod.search_anime_fuzzy
+    pub fn search_anime_fuzzy(&self, query: &str) -> Result<Vec<Anime>> {
+        KitsuRequester::search_anime_fuzzy(self, query)
+    }
+
+    /// Gets the user that an OAuth access token belongs to.
+    ///
+    /// Refer to [`KitsuReqwestRequester::get_self`] for examples and errors.
+    ///
+    /// [`KitsuReqwestRequester::get_self`]: ../bridge/reqwest/trait.KitsuRequester.html#tymethod.get_self
+    pub fn get_self(&self, token: &str) -> Result<Response<User>> {
+        KitsuRequester::get_self(self, token)
+    }
+
+    /// Gets the episodes of an anime using the anime's id.
+    ///
+    /// Refer to [`KitsuReqwestRequester::get_anime_episodes`] for examples and errors.
+    ///
+    /// [`KitsuReqwestRequester::get_anime_episodes`]: ../bridge/reqwest/trait.KitsuRequester.html#tymethod.get_anime_episodes
+    pub fn get_anime_episodes(&self, anime_id: u64) -> Result<Response<Vec<Episode>>> {
+        KitsuRequester::get_anime_episodes(self, anime_id)
+    }
+
+    /// Gets the chapters of a manga using the manga's id.
+    ///
+    /// Refer to [`KitsuReqwestRequester::get_manga_chapters`] for examples and errors.
+    ///
+    /// [`KitsuReqwestRequester::get_manga_chapters`]: ../bridge/reqwest/trait.KitsuRequester.html#tymethod.get_manga_chapters
+    pub fn get_manga_chapters(&self, manga_id: u64) -> Result<Response<Vec<Chapter>>> {
+        KitsuRequester::get_manga_chapters(self, manga_id)
+    }
+
+    /// Searches for anime using the passed [`Search`] builder, returning an
+    /// iterator over the results of every page.
+    ///
+    /// Refer to [`KitsuReqwestRequester::search_anime_all`] for examples and errors.
+    ///
+    /// [`KitsuReqwestRequester::search_anime_all`]: ../bridge/reqwest/trait.KitsuRequester.html#tymethod.search_anime_all
+    /// [`Search`]: ../builder/struct.Search.html
+    pub fn search_anime_all<F: FnOnce(Search) -> Search>(&self, f: F) -> Paginated<'_, Anime> {
+        KitsuRequester::search_anime_all(self, f)
+    }
+
+    /// Gets the links to where an anime can be legally streamed using the
+    /// anime's id.
+    ///
+    /// Refer to [`KitsuReqwestRequester::get_anime_streaming_links`] for examples and errors.
+    ///
+    /// [`KitsuReqwestRequester::get_anime_streaming_links`]: ../bridge/reqwest/trait.KitsuRequester.html#tymethod.get_anime_streaming_links
+    pub fn get_anime_streaming_links(&self, anime_id: u64) -> Result<Response<Vec<StreamingLink>>> {
+        KitsuRequester::get_anime_streaming_links(self, anime_id)
+    }
+
+    /// Gets the categories of an anime using the anime's id.
+    ///
+    /// Refer to [`KitsuReqwestRequester::get_anime_categories`] for examples and errors.
+    ///
+    /// [`KitsuReqwestRequester::get_anime_categories`]: ../bridge/reqwest/trait.KitsuRequester.html#tymethod.get_anime_categories
+    pub fn get_anime_categories(&self, anime_id: u64) -> Result<Response<Vec<Category>>> {
+        KitsuRequester::get_anime_categories(self, anime_id)
+    }
+
+    /// Gets a list of categories using the search builder.
+    ///
+    /// Refer to [`KitsuReqwestRequester::get_categories`] for examples and errors.
+    ///
+    /// [`KitsuReqwestRequester::get_categories`]: ../bridge/reqwest/trait.KitsuRequester.html#tymethod.get_categories
+    pub fn get_categories<F: FnOnce(Search) -> Search>(&self, f: F)
+        -> Result<Response<Vec<Category>>> {
+        KitsuRequester::get_categories(self, f)
+    }
+
+    /// Gets a resource from a URL on the Kitsu API, such as the link to a
+    /// relationship of another resource.
+    ///
+    /// Refer to [`KitsuReqwestRequester::fetch`] for examples and errors.
+    ///
+    /// [`KitsuReqwestRequester::fetch`]: ../bridge/reqwest/trait.KitsuRequester.html#tymethod.fetch
+    pub fn fetch<T: DeserializeOwned>(&self, url: &str) -> Result<Response<T>> {
+        KitsuRequester::fetch(self, url)
+    }
+
+    /// Gets the anime which are currently trending on Kitsu.
+    ///
+    /// Refer to [`KitsuReqwestRequester::get_trending_anime`] for examples and errors.
+    ///
+    /// [`KitsuReqwestRequester::get_trending_anime`]: ../bridge/reqwest/trait.KitsuRequester.html#tymethod.get_trending_anime
+    pub fn get_trending_anime(&self) -> Result<Response<Vec<Anime>>> {
+        KitsuRequester::get_trending_anime(self)
+    }
+
+    /// Gets the manga which are currently trending on Kitsu.
+    ///
+    /// Refer to [`KitsuReqwestRequester::get_trending_manga`] for examples and errors.
+    ///
+    /// [`KitsuReqwestRequester::get_trending_manga`]: ../bridge/reqwest/trait.KitsuRequester.html#tymethod.get_trending_manga
+    pub fn get_trending_manga(&self) -> Result<Response<Vec<Manga>>> {
+        KitsuRequester::get_trending_manga(self)
+    }
+
+    /// Gets the reviews of an anime using the anime's id.
+    ///
+    /// Refer to [`KitsuReqwestRequester::get_anime_reviews`] for examples and errors.
+    ///
+    /// [`KitsuReqwestRequester::get_anime_reviews`]: ../bridge/reqwest/trait.KitsuRequester.html#tymethod.get_anime_reviews
+    pub fn get_anime_reviews(&self, anime_id: u64) -> Result<Response<Vec<Review>>> {
+        KitsuRequester::get_anime_reviews(self, anime_id)
+    }
+
+    /// Gets the entries in a user's library using the user's id.
+    ///
+    /// Refer to [`KitsuReqwestRequester::get_user_library_entries`] for examples and errors.
+    ///
+    /// [`KitsuReqwestRequester::get_user_library_entries`]: ../bridge/reqwest/trait.KitsuRequester.html#tymethod.get_user_library_entries
+    pub fn get_user_library_entries<F: FnOnce(Search) -> Search>(&self, user_id: u64, f: F)
+        -> Result<Response<Vec<LibraryEntry>>> {
+        KitsuRequester::get_user_library_entries(self, user_id, f)
+    }
+
+    /// Gets the castings of an anime using the anime's id.
+    ///
+    /// Refer to [`KitsuReqwestRequester::get_anime_castings`] for examples and errors.
+    ///
+    /// [`KitsuReqwestRequester::get_anime_castings`]: ../bridge/reqwest/trait.KitsuRequester.html#tymethod.get_anime_castings
+    pub fn get_anime_castings(&self, anime_id: u64) -> Result<Response<Vec<Casting>>> {
+        KitsuRequester::get_anime_castings(self, anime_id)
+    }
+
+    /// Gets an anime using its slug, such as `attack-on-titan`.
+    ///
+    /// Refer to [`KitsuReqwestRequester::get_anime_by_slug`] for examples and errors.
+    ///
+    /// [`KitsuReqwestRequester::get_anime_by_slug`]: ../bridge/reqwest/trait.KitsuRequester.html#tymethod.get_anime_by_slug
+    pub fn get_anime_by_slug(&self, slug: &str) -> Result<Option<Anime>> {
+        KitsuRequester::get_anime_by_slug(self, slug)
+    }
+
+    /// Gets a manga using its slug, such as `berserk`.
+    ///
+    /// Refer to [`KitsuReqwestRequester::get_manga_by_slug`] for examples and errors.
+    ///
+    /// [`KitsuReqwestRequester::get_manga_by_slug`]: ../bridge/reqwest/trait.KitsuRequester.html#tymethod.get_manga_by_slug
+    pub fn get_manga_by_slug(&self, slug: &str) -> Result<Option<Manga>> {
+        KitsuRequester::get_manga_by_slug(self, slug)
+    }
+
+    /// Gets a person, such as a voice actor or a member of staff, using their
+    /// id.
+    ///
+    /// Refer to [`KitsuReqwestRequester::get_person`] for examples and errors.
+    ///
+    /// [`KitsuReqwestRequester::get_person`]: ../bridge/reqwest/trait.KitsuRequester.html#tymethod.get_person
+    pub fn get_person(&self, id: u64) -> Result<Response<Person>> {
+        KitsuRequester::get_person(self, id)
+    }
+
+    /// Gets a list of people using the search builder.
+    ///
+    /// Refer to [`KitsuReqwestRequester::search_people`] for examples and errors.
+    ///
+    /// [`KitsuReqwestRequester::search_people`]: ../bridge/reqwest/trait.KitsuRequester.html#tymethod.search_people
+    pub fn search_people<F: FnOnce(Search) -> Search>(&self, f: F)
+        -> Result<Response<Vec<Person>>> {
+        KitsuRequester::search_people(self, f)
+    }
+
+    /// Gets an anime using its id, returning `None` if there is no anime with
+    /// the id.
+    ///
+    /// Refer to [`KitsuReqwestRequester::try_get_anime`] for examples and errors.
+    ///
+    /// [`KitsuReqwestRequester::try_get_anime`]: ../bridge/reqwest/trait.KitsuRequester.html#tymethod.try_get_anime
+    pub fn try_get_anime(&self, id: u64) -> Result<Option<Response<Anime>>> {
+        KitsuRequester::try_get_anime(self, id)
+    }
+
+    /// Gets a manga using its id, returning `None` if there is no manga with
+    /// the id.
+    ///
+    /// Refer to [`KitsuReqwestRequester::try_get_manga`] for examples and errors.
+    ///
+    /// [`KitsuReqwestRequester::try_get_manga`]: ../bridge/reqwest/trait.KitsuRequester.html#tymethod.try_get_manga
+    pub fn try_get_manga(&self, id: u64) -> Result<Option<Response<Manga>>> {
+        KitsuRequester::try_get_manga(self, id)
+    }
+
+    /// Gets a user using its id, returning `None` if there is no user with
+    /// the id.
+    ///
+    /// Refer to [`KitsuReqwestRequester::try_get_user`] for examples and errors.
+    ///
+    /// [`KitsuReqwestRequester::try_get_user`]: ../bridge/reqwest/trait.KitsuRequester.html#tymethod.try_get_user
+    pub fn try_get_user(&self, id: u64) -> Result<Option<Response<User>>> {
+        KitsuRequester::try_get_user(self, id)
+    }
+
+    /// Gets the mappings of an anime to its ids on external sites, such as
+    /// MyAnimeList or AniList.
+    ///
+    /// Refer to [`KitsuReqwestRequester::get_anime_mappings`] for examples and errors.
+    ///
+    /// [`KitsuReqwestRequester::get_anime_mappings`]: ../bridge/reqwest/trait.KitsuRequester.html#tymethod.get_anime_mappings
+    pub fn get_anime_mappings(&self, anime_id: u64) -> Result<Response<Vec<Mapping>>> {
+        KitsuRequester::get_anime_mappings(self, anime_id)
+    }
+
+    /// Searches for anime using a prebuilt [`Search`].
+    ///
+    /// Refer to [`KitsuReqwestRequester::search_anime_with`] for examples and errors.
+    ///
+    /// [`KitsuReqwestRequester::search_anime_with`]: ../bridge/reqwest/trait.KitsuRequester.html#tymethod.search_anime_with
+    /// [`Search`]: ../builder/struct.Search.html
+    pub fn search_anime_with(&self, search: Search) -> Result<Response<Vec<Anime>>> {
+        KitsuRequester::search_anime_with(self, search)
+    }
+
+    /// Searches for manga using a prebuilt [`Search`].
+    ///
+    /// Refer to [`KitsuReqwestRequester::search_manga_with`] for examples and errors.
+    ///
+    /// [`KitsuReqwestRequester::search_manga_with`]: ../bridge/reqwest/trait.KitsuRequester.html#tymethod.search_manga_with
+    /// [`Search`]: ../builder/struct.Search.html
+    pub fn search_manga_with(&self, search: Search) -> Result<Response<Vec<Manga>>> {
+        KitsuRequester::search_manga_with(self, search)
+    }
+
+    /// Searches for users using a prebuilt [`Search`].
+    ///
+    /// Refer to [`KitsuReqwestRequester::search_users_with`] for examples and errors.
+    ///
+    /// [`KitsuReqwestRequester::search_users_with`]: ../bridge/reqwest/trait.KitsuRequester.html#tymethod.search_users_with
+    /// [`Search`]: ../builder/struct.Search.html
+    pub fn search_users_with(&self, search: Search) -> Result<Response<Vec<User>>> {
+        KitsuRequester::search_users_with(self, search)
+    }
+
+    /// Gets the relationships of an anime to its related media, such as its
+    /// sequels, prequels, and adaptations, using the anime's id.
+    ///
+    /// Refer to [`KitsuReqwestRequester::get_anime_media_relationships`] for examples and errors.
+    ///
+    /// [`KitsuReqwestRequester::get_anime_media_relationships`]: ../bridge/reqwest/trait.KitsuRequester.html#tymethod.get_anime_media_relationships
+    pub fn get_anime_media_relationships(&self, anime_id: u64)
+        -> Result<Response<Vec<MediaRelationship>>> {
+        KitsuRequester::get_anime_media_relationships(self, anime_id)
+    }
+
+    /// Gets an anime using its id, retrying the request according to a
+    /// [`RetryPolicy`] if it fails due to being rate limited or a server error.
+    ///
+    /// Refer to [`KitsuReqwestRequester::get_anime_retrying`] for examples and errors.
+    ///
+    /// [`KitsuReqwestRequester::get_anime_retrying`]: ../bridge/reqwest/trait.KitsuRequester.html#tymethod.get_anime_retrying
+    /// [`RetryPolicy`]: ../bridge/reqwest/struct.RetryPolicy.html
+    pub fn get_anime_retrying(&self, id: u64, policy: RetryPolicy) -> Result<Response<Anime>> {
+        KitsuRequester::get_anime_retrying(self, id, policy)
+    }
+
+    /// Gets the installments of an anime in its franchises using the anime's id.
+    ///
+    /// Refer to [`KitsuReqwestRequester::get_anime_installments`] for examples and errors.
+    ///
+    /// [`KitsuReqwestRequester::get_anime_installments`]: ../bridge/reqwest/trait.KitsuRequester.html#tymethod.get_anime_installments
+    pub fn get_anime_installments(&self, anime_id: u64) -> Result<Response<Vec<Installment>>> {
+        KitsuRequester::get_anime_installments(self, anime_id)
+    }
+
+    /// Gets the production credits of an anime, such as its studios and
+    /// licensors, using the anime's id.
+    ///
+    /// Refer to [`KitsuReqwestRequester::get_anime_productions`] for examples and errors.
+    ///
+    /// [`KitsuReqwestRequester::get_anime_productions`]: ../bridge/reqwest/trait.KitsuRequester.html#tymethod.get_anime_productions
+    pub fn get_anime_productions(&self, anime_id: u64) -> Result<Response<Vec<AnimeProduction>>> {
+        KitsuRequester::get_anime_productions(self, anime_id)
+    }
+
+    /// Gets the short reactions of users to an anime using the anime's id.
+    ///
+    /// Refer to [`KitsuReqwestRequester::get_anime_reactions`] for examples and errors.
+    ///
+    /// [`KitsuReqwestRequester::get_anime_reactions`]: ../bridge/reqwest/trait.KitsuRequester.html#tymethod.get_anime_reactions
+    pub fn get_anime_reactions(&self, anime_id: u64) -> Result<Response<Vec<MediaReaction>>> {
+        KitsuRequester::get_anime_reactions(self, anime_id)
+    }
+
+    /// Gets a group using its id.
+    ///
+    /// Refer to [`KitsuReqwestRequester::get_group`] for examples and errors.
+    ///
+    /// [`KitsuReqwestRequester::get_group`]: ../bridge/reqwest/trait.KitsuRequester.html#tymethod.get_group
+    pub fn get_group(&self, id: u64) -> Result<Response<Group>> {
+        KitsuRequester::get_group(self, id)
+    }
+
+    /// Searches for groups using the passed [`Search`] builder.
+    ///
+    /// Refer to [`KitsuReqwestRequester::search_groups`] for examples and errors.
+    ///
+    /// [`KitsuReqwestRequester::search_groups`]: ../bridge/reqwest/trait.KitsuRequester.html#tymethod.search_groups
+    /// [`Search`]: ../builder/struct.Search.html
+    pub fn search_groups<F: FnOnce(Search) -> Search>(&self, f: F) -> Result<Response<Vec<Group>>> {
+        KitsuRequester::search_groups(self, f)
+    }
+
+    /// Searches for groups using a prepared [`Search`] builder.
+    ///
+    /// Refer to [`KitsuReqwestRequester::search_groups_with`] for examples and errors.
+    ///
+    /// [`KitsuReqwestRequester::search_groups_with`]: ../bridge/reqwest/trait.KitsuRequester.html#tymethod.search_groups_with
+    /// [`Search`]: ../builder/struct.Search.html
+    pub fn search_groups_with(&self, search: Search) -> Result<Response<Vec<Group>>> {
+        KitsuRequester::search_groups_with(self, search)
+    }
+
+    /// Gets the waifu or husbando character of a user using the user's id, by
+    /// following the user's [`waifu`] relationship.
+    ///
+    /// Refer to [`KitsuReqwestRequester::get_user_waifu`] for examples and errors.
+    ///
+    /// [`KitsuReqwestRequester::get_user_waifu`]: ../bridge/reqwest/trait.KitsuRequester.html#tymethod.get_user_waifu
+    /// [`waifu`]: ../model/struct.UserRelationships.html#structfield.waifu
+    pub fn get_user_waifu(&self, user_id: u64) -> Result<Response<Option<Character>>> {
+        KitsuRequester::get_user_waifu(self, user_id)
+    }
+
+    /// Gets the events in a user's library using the user's id, such as when
+    /// they progressed through a media item or changed its status.
+    ///
+    /// Refer to [`KitsuReqwestRequester::get_user_library_events`] for examples and errors.
+    ///
+    /// [`KitsuReqwestRequester::get_user_library_events`]: ../bridge/reqwest/trait.KitsuRequester.html#tymethod.get_user_library_events
+    pub fn get_user_library_events<F: FnOnce(Search) -> Search>(&self, user_id: u64, f: F)
+        -> Result<Response<Vec<LibraryEvent>>> {
+        KitsuRequester::get_user_library_events(self, user_id, f)
+    }
+
+    /// Gets multiple anime using their ids, such as those in a user's library.
+    ///
+    /// Refer to [`KitsuReqwestRequester::get_anime_many`] for examples and errors.
+    ///
+    /// [`KitsuReqwestRequester::get_anime_many`]: ../bridge/reqwest/trait.KitsuRequester.html#tymethod.get_anime_many
+    pub fn get_anime_many(&self, ids: &[u64]) -> Result<Response<Vec<Anime>>> {
+        KitsuRequester::get_anime_many(self, ids)
+    }
+
+    /// Gets the users following a user using the user's id.
+    ///
+    /// Refer to [`KitsuReqwestRequester::get_user_followers`] for examples and errors.
+    ///
+    /// [`KitsuReqwestRequester::get_user_followers`]: ../bridge/reqwest/trait.KitsuRequester.html#tymethod.get_user_followers
+    pub fn get_user_followers<F: FnOnce(Search) -> Search>(&self, user_id: u64, f: F)
+        -> Result<Response<Vec<User>>> {
+        KitsuRequester::get_user_followers(self, user_id, f)
+    }
+
+    /// Gets the users which a user is following using the user's id.
+    ///
+    /// Refer to [`KitsuReqwestRequester::get_user_following`] for examples and errors.
+    ///
+    /// [`KitsuReqwestRequester::get_user_following`]: ../bridge/reqwest/trait.KitsuRequester.html#tymethod.get_user_following
+    pub fn get_user_following<F: FnOnce(Search) -> Search>(&self, user_id: u64, f: F)
+        -> Result<Response<Vec<User>>> {
+        KitsuRequester::get_user_following(self, user_id, f)
+    }
+
+    /// Gets the users following a user using the user's id, returning an
+    /// iterator over the followers of every page.
+    ///
+    /// Refer to [`KitsuReqwestRequester::get_user_followers_all`] for examples and errors.
+    ///
+    /// [`KitsuReqwestRequester::get_user_followers_all`]: ../bridge/reqwest/trait.KitsuRequester.html#tymethod.get_user_followers_all
+    pub fn get_user_followers_all<F: FnOnce(Search) -> Search>(&self, user_id: u64, f: F)
+        -> Paginated<'_, User> {
+        KitsuRequester::get_user_followers_all(self, user_id, f)
+    }
+
+    /// Gets the users which a user is following using the user's id, returning
+    /// an iterator over the users of every page.
+    ///
+    /// Refer to [`KitsuReqwestRequester::get_user_following_all`] for examples and errors.
+    ///
+    /// [`KitsuReqwestRequester::get_user_following_all`]: ../bridge/reqwest/trait.KitsuRequester.html#tymethod.get_user_following_all
+    pub fn get_user_following_all<F: FnOnce(Search) -> Search>(&self, user_id: u64, f: F)
+        -> Paginated<'_, User> {
+        KitsuRequester::get_user_following_all(self, user_id, f)
+    }
+
+    /// Gets the posts made by a user using the user's id, such as to mirror
+    /// their timeline.
+    ///
+    /// Refer to [`KitsuReqwestRequester::get_user_posts`] for examples and errors.
+    ///
+    /// [`KitsuReqwestRequester::get_user_posts`]: ../bridge/reqwest/trait.KitsuRequester.html#tymethod.get_user_posts
+    pub fn get_user_posts<F: FnOnce(Search) -> Search>(&self, user_id: u64, f: F)
+        -> Result<Response<Vec<Post>>> {
+        KitsuRequester::get_user_posts(self, user_id, f)
+    }
+
+    /// Gets the comments on a post using the post's id.
+    ///
+    /// Refer to [`KitsuReqwestRequester::get_post_comments`] for examples and errors.
+    ///
+    /// [`KitsuReqwestRequester::get_post_comments`]: ../bridge/reqwest/trait.KitsuRequester.html#tymethod.get_post_comments
+    pub fn get_post_comments<F: FnOnce(Search) -> Search>(&self, post_id: u64, f: F)
+        -> Result<Response<Vec<Comment>>> {
+        KitsuRequester::get_post_comments(self, post_id, f)
+    }
+}
+
+/// A builder to configure a [`KitsuClient`], created by
+/// [`KitsuClient::builder`].
+///
+/// [`KitsuClient`]: struct.KitsuClient.html
+/// [`KitsuClient::builder`]: struct.KitsuClient.html#method.builder
+#[derive(Clone, Debug)]
+pub struct KitsuClientBuilder {
+    base_url: String,
+    etag_cache: bool,
+    no_proxy: bool,
+    proxies: Vec<Proxy>,
+    timeout: Option<Duration>,
+    user_agent: String,
+}
+
+impl KitsuClientBuilder {
+    /// Sets the base URL of the API to make requests to, in place of the
+    /// [`API_URL`].
+    ///
+    /// A trailing slash is removed.
+    ///
+    /// [`API_URL`]: ../constant.API_URL.html
+    pub fn base_url(mut self, base_url: &str) -> Self {
+        self.base_url = base_url.trim_end_matches('/').to_owned();
+
+        self
+    }
+
+    /// Sets whether responses are cached by their `ETag` to make conditional
+    /// requests, which is disabled by default.
+    ///
+    /// Each cached response keeps its full body in memory, for up to
+    /// [`ETAG_CACHE_CAPACITY`] responses. Refer to
+    /// [`KitsuClient::with_etag_cache`] for how the cache is used.
+    ///
+    /// [`ETAG_CACHE_CAPACITY`]: constant.ETAG_CACHE_CAPACITY.html
+    /// [`KitsuClient::with_etag_cache`]: struct.KitsuClient.html#method.with_etag_cache
+    pub fn etag_cache(mut self, enabled: bool) -> Self {
+        self.etag_cache = enabled;
+
+        self
+    }
+
+    /// Disables sending requests through a proxy, including any given in the
+    /// `HTTP_PROXY` and `HTTPS_PROXY` environment variables.
+    ///
+    /// This takes precedence over proxies added using [`proxy`].
+    ///
+    /// [`proxy`]: #method.proxy
+    pub fn no_proxy(mut self) -> Self {
+        self.no_proxy = true;
+
+        self
+    }
+
+    /// Adds a proxy to send requests through, such as when outbound traffic
+    /// must go through a corporate proxy.
+    ///
+    /// Proxies are tried in the order that they are added. Adding a proxy
+    /// disables the use of the proxies given in the `HTTP_PROXY` and
+    /// `HTTPS_PROXY` environment variables, which are otherwise used by
+    /// default.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// extern crate kitsu_io;
+    /// extern crate reqwest;
+    ///
+    /// use kitsu_io::KitsuClient;
+    /// use reqwest::Proxy;
+    ///
+    /// fn main() {
+    ///     let proxy = Proxy::all("http://proxy.example.com:8080")
+    ///         .expect("Error parsing proxy URL");
+    ///
+    ///     let client = KitsuClient::builder()
+    ///         .proxy(proxy)
+    ///         .build()
+    ///         .expect("Error creating client");
+    /// }
+    /// ```
+    pub fn proxy(mut self, proxy: Proxy) -> Self {
+        self.proxies.push(proxy);
+
+        self
+    }
+
+    /// Sets the timeout of each request, from when it is sent until its
+    /// response body has been read.
+    ///
+    /// Requests which do not complete in time fail with [`Error::Timeout`].
+    /// If this is not set, the default timeout of the `reqwest` Client is
+    /// used.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use kitsu_io::KitsuClient;
+    /// use std::time::Duration;
+    ///
+    /// let client = KitsuClient::builder()
+    ///     .timeout(Duration::from_secs(5))
+    ///     .build()
+    ///     .expect("Error creating client");
+    /// ```
+    ///
+    /// [`Error::Timeout`]: ../enum.Error.html#variant.Timeout
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+
+        self
+    }
+
+    /// Sets the `User-Agent` to send with requests, in place of the
+    /// [`USER_AGENT`].
+    ///
+    /// Kitsu asks that consumers of the API identify themselves, so this
+    /// should name your application.
+    ///
+    /// [`USER_AGENT`]: constant.USER_AGENT.html
+    pub fn user_agent(mut self, user_agent: &str) -> Self {
+        self.user_agent = user_agent.to_owned();
+
+        self
+    }
+
+    /// Creates the client.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Reqwest`] if the `reqwest` Client could not be
+    /// created, such as if a TLS backend could not be initialized.
+    ///
+    /// [`Error::Reqwest`]: ../enum.Error.html#variant.Reqwest
+    pub fn build(self) -> Result<KitsuClient> {
+        let mut builder = ReqwestClient::builder()
+            .gzip(true)
+            .user_agent(self.user_agent);
+
+        for proxy in self.proxies {
+            builder = builder.proxy(proxy);
+        }
+
+        if self.no_proxy {
+            builder = builder.no_proxy();
+        }
+
+        if let Some(timeout) = self.timeout {
+            builder = builder.timeout(timeout);
+        }
+
+        let client = builder.build()?;
+
+        Ok(KitsuClient {
+            base_url: self.base_url,
+            client,
+            etag_cache: if self.etag_cache { Some(Arc::default()) } else { None },
+        })
+    }
+}
+
+impl Default for KitsuClientBuilder {
+    fn default() -> Self {
+        KitsuClientBuilder {
+            base_url: API_URL.to_owned(),
+            etag_cache: false,
+            no_proxy: false,
+            proxies: Vec::new(),
+            timeout: None,
+            user_agent: USER_AGENT.to_owned(),
+        }
+    }
+}
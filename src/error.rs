@@ -1,12 +1,23 @@
-#[cfg(feature = "reqwest")]
+#[cfg(any(feature = "hyper", feature = "reqwest"))]
 use serde_json::Error as JsonError;
+use std::error::Error as StdError;
 use std::fmt::{Display, Formatter, Result as FmtResult};
 use std::result::Result as StdResult;
+#[cfg(feature = "reqwest")]
+use std::str::Utf8Error;
+#[cfg(any(feature = "hyper", feature = "reqwest"))]
+use std::time::Duration;
 
 #[cfg(feature = "hyper")]
-use hyper::error::UriError;
+use hyper::http::uri::InvalidUri;
+#[cfg(feature = "hyper")]
+use hyper::Error as HyperError;
+#[cfg(feature = "hyper")]
+use hyper::StatusCode as HyperStatusCode;
 #[cfg(feature = "reqwest")]
 use reqwest::Error as ReqwestError;
+#[cfg(feature = "reqwest")]
+use reqwest::StatusCode;
 
 /// A result type to compose a successful value and the library's [`Error`]
 /// type.
@@ -18,45 +29,198 @@ pub type Result<T> = StdResult<T, Error>;
 /// errors.
 #[derive(Debug)]
 pub enum Error {
+    /// An error returned by the API in a JSON:API error document, such as when
+    /// a search contains an invalid filter.
+    ///
+    /// Contains the errors in the document, and the id sent in the request's
+    /// `X-Request-Id` header if the request was made with one.
+    #[cfg(any(feature = "hyper", feature = "reqwest"))]
+    Api(Vec<ApiError>, Option<String>),
     /// An error from the `serde_json` crate.
     ///
     /// A potential reason for this is when there is an error deserializing a
-    /// JSON response body.
+    /// JSON response body, such as when the API responds with an HTML error
+    /// page.
+    #[cfg(any(feature = "hyper", feature = "reqwest"))]
+    Json {
+        /// The error from deserializing.
+        source: JsonError,
+        /// The text of the response body which failed to deserialize, with any
+        /// invalid UTF-8 replaced. This is empty if the error did not occur
+        /// while deserializing a response body.
+        body: String,
+        /// The id sent in the `X-Request-Id` header of the request whose
+        /// response failed to deserialize, if the request was made with one.
+        request_id: Option<String>,
+    },
+    /// An error indicating that a URL given to be fetched is not on the Kitsu
+    /// API, and so was not requested.
+    ///
+    /// Contains the URL that was given.
     #[cfg(feature = "reqwest")]
-    Json(JsonError),
+    ForeignUrl(String),
+    /// An error indicating that a search is invalid, such as when it mixes
+    /// pagination styles, and so was not sent.
+    ///
+    /// Contains a description of why the search is invalid.
+    InvalidSearch(String),
+    /// An error indicating that a response body was not valid UTF-8.
+    ///
+    /// This is distinct from [`Json`], and indicates an issue with the encoding
+    /// of the response rather than its structure.
+    ///
+    /// [`Json`]: #variant.Json
+    #[cfg(feature = "reqwest")]
+    InvalidEncoding(Utf8Error),
+    /// An error indicating that the client has been rate limited by the API,
+    /// and should back off before retrying.
+    #[cfg(any(feature = "hyper", feature = "reqwest"))]
+    RateLimited {
+        /// How long to wait before retrying, parsed from the `Retry-After`
+        /// header, if it was given in seconds.
+        retry_after: Option<Duration>,
+        /// The id sent in the `X-Request-Id` header of the request which was
+        /// rate limited, if the request was made with one.
+        request_id: Option<String>,
+    },
     /// An error from the `reqwest` crate when it is enabled.
     #[cfg(feature = "reqwest")]
     Reqwest(ReqwestError),
+    /// An error indicating that a request did not complete before the timeout
+    /// configured on the client elapsed.
+    #[cfg(feature = "reqwest")]
+    Timeout,
     /// An error indicating a bad request when using `reqwest`.
+    ///
+    /// Contains the id sent in the request's `X-Request-Id` header.
     #[cfg(feature = "reqwest")]
-    ReqwestBad(),
-    /// An error indicating an invalid request when using `reqwest`.
+    ReqwestBad(String),
+    /// An error indicating an invalid request when using `reqwest`, such as
+    /// when the requested resource was not found.
+    ///
+    /// Contains the status code of the response, and the id sent in the
+    /// request's `X-Request-Id` header.
     #[cfg(feature = "reqwest")]
-    ReqwestInvalid(),
+    ReqwestInvalid(StatusCode, String),
     /// An error indicating an unathorized request when using `reqwest`.
+    ///
+    /// Contains the id sent in the request's `X-Request-Id` header.
     #[cfg(feature = "reqwest")]
-    ReqwestUnauthorized(),
+    ReqwestUnauthorized(String),
+    /// An error from the `hyper` crate when it is enabled, such as when reading
+    /// a response's body.
+    #[cfg(feature = "hyper")]
+    Hyper(HyperError),
+    /// An error indicating a non-OK response when using `hyper`, such as when
+    /// the requested resource was not found.
+    ///
+    /// Contains the status code of the response.
+    #[cfg(feature = "hyper")]
+    HyperInvalid(HyperStatusCode),
     /// An error when building a request's URI from the `hyper` crate when it is
     /// enabled.
     #[cfg(feature = "hyper")]
-    Uri(UriError),
+    Uri(InvalidUri),
 
     /// An error when parsing the URL
     #[cfg(feature = "reqwest")]
     ParseError(url::ParseError),
 }
 
-#[cfg(feature = "reqwest")]
+/// An error returned by the API, describing why a request failed.
+#[cfg(any(feature = "hyper", feature = "reqwest"))]
+#[derive(Clone, Debug, Deserialize)]
+pub struct ApiError {
+    /// An application-specific code for the error.
+    pub code: Option<String>,
+    /// A human-readable explanation of the error.
+    ///
+    /// # Examples
+    ///
+    /// `seasonYear is not a valid filter`
+    pub detail: Option<String>,
+    /// The HTTP status code of the error.
+    ///
+    /// # Examples
+    ///
+    /// `400`
+    pub status: Option<String>,
+    /// A short summary of the error.
+    ///
+    /// # Examples
+    ///
+    /// `Filter not allowed`
+    pub title: Option<String>,
+}
+
+impl Error {
+    /// The id sent in the `X-Request-Id` header of the request which caused
+    /// the error, if the error was caused by the response to a request.
+    ///
+    /// This can be quoted when reporting an issue to Kitsu, to correlate the
+    /// request with the server's logs.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            #[cfg(any(feature = "hyper", feature = "reqwest"))]
+            Error::Api(_, ref id)
+            | Error::Json { request_id: ref id, .. }
+            | Error::RateLimited { request_id: ref id, .. } => id.as_deref(),
+            #[cfg(feature = "reqwest")]
+            Error::ReqwestBad(ref id)
+            | Error::ReqwestInvalid(_, ref id)
+            | Error::ReqwestUnauthorized(ref id) => Some(id),
+            _ => None,
+        }
+    }
+
+    /// The HTTP status code of the response which caused the error, if the
+    /// error was caused by a response.
+    ///
+    /// This can be used to tell apart errors such as a resource not being
+    /// found (`404`) and the service being unavailable (`503`), whichever
+    /// bridge made the request.
+    pub fn status(&self) -> Option<u16> {
+        match *self {
+            #[cfg(any(feature = "hyper", feature = "reqwest"))]
+            Error::Api(ref errors, _) => errors.iter()
+                .filter_map(|error| error.status.as_ref())
+                .find_map(|status| status.parse::<u16>().ok()),
+            #[cfg(any(feature = "hyper", feature = "reqwest"))]
+            Error::RateLimited { .. } => Some(429),
+            #[cfg(feature = "hyper")]
+            Error::HyperInvalid(status) => Some(status.as_u16()),
+            #[cfg(feature = "reqwest")]
+            Error::Reqwest(ref inner) => inner.status().map(|status| status.as_u16()),
+            #[cfg(feature = "reqwest")]
+            Error::ReqwestBad(_) => Some(400),
+            #[cfg(feature = "reqwest")]
+            Error::ReqwestInvalid(status, _) => Some(status.as_u16()),
+            #[cfg(feature = "reqwest")]
+            Error::ReqwestUnauthorized(_) => Some(401),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(any(feature = "hyper", feature = "reqwest"))]
 impl From<JsonError> for Error {
     fn from(err: JsonError) -> Self {
-        Error::Json(err)
+        Error::Json {
+            source: err,
+            body: String::new(),
+            request_id: None,
+        }
     }
 }
 
 #[cfg(feature = "reqwest")]
 impl From<ReqwestError> for Error {
     fn from(err: ReqwestError) -> Self {
-        Error::Reqwest(err)
+        if err.is_timeout() {
+            Error::Timeout
+        } else {
+            Error::Reqwest(err)
+        }
     }
 }
 
@@ -68,14 +232,127 @@ impl From<url::ParseError> for Error {
 }
 
 #[cfg(feature = "hyper")]
-impl From<UriError> for Error {
-    fn from(err: UriError) -> Error {
+impl From<HyperError> for Error {
+    fn from(err: HyperError) -> Error {
+        Error::Hyper(err)
+    }
+}
+
+#[cfg(feature = "hyper")]
+impl From<InvalidUri> for Error {
+    fn from(err: InvalidUri) -> Error {
         Error::Uri(err)
     }
 }
 
 impl Display for Error {
+    #[cfg_attr(not(any(feature = "hyper", feature = "reqwest")), allow(unused_variables))]
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        match *self {
+            #[cfg(any(feature = "hyper", feature = "reqwest"))]
+            Error::Api(ref errors, ref id) => {
+                f.write_str("API error: ")?;
+
+                for (idx, error) in errors.iter().enumerate() {
+                    if idx > 0 {
+                        f.write_str("; ")?;
+                    }
+
+                    Display::fmt(error, f)?;
+                }
+
+                fmt_request_id(id, f)
+            },
+            #[cfg(any(feature = "hyper", feature = "reqwest"))]
+            Error::Json { ref source, request_id: ref id, .. } => {
+                write!(f, "JSON error: {}", source)?;
+
+                fmt_request_id(id, f)
+            },
+            #[cfg(feature = "reqwest")]
+            Error::ForeignUrl(ref url) => write!(f, "URL is not on the Kitsu API: {}", url),
+            Error::InvalidSearch(ref reason) => write!(f, "Invalid search: {}", reason),
+            #[cfg(feature = "reqwest")]
+            Error::InvalidEncoding(ref inner) => {
+                write!(f, "Response body is not valid UTF-8: {}", inner)
+            },
+            #[cfg(any(feature = "hyper", feature = "reqwest"))]
+            Error::RateLimited { retry_after, request_id: ref id } => {
+                f.write_str("Rate limited")?;
+
+                if let Some(retry_after) = retry_after {
+                    write!(f, ", retry after {}s", retry_after.as_secs())?;
+                }
+
+                fmt_request_id(id, f)
+            },
+            #[cfg(feature = "reqwest")]
+            Error::Reqwest(ref inner) => write!(f, "Reqwest error: {}", inner),
+            #[cfg(feature = "reqwest")]
+            Error::Timeout => f.write_str("Request timed out"),
+            #[cfg(feature = "reqwest")]
+            Error::ReqwestBad(ref id) => write!(f, "Bad request (request id {})", id),
+            #[cfg(feature = "reqwest")]
+            Error::ReqwestInvalid(status, ref id) => {
+                write!(f, "Invalid response with status {} (request id {})", status, id)
+            },
+            #[cfg(feature = "reqwest")]
+            Error::ReqwestUnauthorized(ref id) => {
+                write!(f, "Unauthorized request (request id {})", id)
+            },
+            #[cfg(feature = "hyper")]
+            Error::Hyper(ref inner) => write!(f, "Hyper error: {}", inner),
+            #[cfg(feature = "hyper")]
+            Error::HyperInvalid(status) => write!(f, "Invalid response with status {}", status),
+            #[cfg(feature = "hyper")]
+            Error::Uri(ref inner) => write!(f, "URI error: {}", inner),
+            #[cfg(feature = "reqwest")]
+            Error::ParseError(ref inner) => write!(f, "URL parse error: {}", inner),
+        }
+    }
+}
+
+impl StdError for Error {
+    fn source(&self) -> Option<&(dyn StdError + 'static)> {
+        match *self {
+            #[cfg(any(feature = "hyper", feature = "reqwest"))]
+            Error::Json { ref source, .. } => Some(source),
+            #[cfg(feature = "reqwest")]
+            Error::InvalidEncoding(ref inner) => Some(inner),
+            #[cfg(feature = "reqwest")]
+            Error::Reqwest(ref inner) => Some(inner),
+            #[cfg(feature = "hyper")]
+            Error::Hyper(ref inner) => Some(inner),
+            #[cfg(feature = "hyper")]
+            Error::Uri(ref inner) => Some(inner),
+            #[cfg(feature = "reqwest")]
+            Error::ParseError(ref inner) => Some(inner),
+            _ => None,
+        }
+    }
+}
+
+/// Writes the request id of an error, if there is one, in the same form as the
+/// errors which always have a request id.
+#[cfg(any(feature = "hyper", feature = "reqwest"))]
+fn fmt_request_id(request_id: &Option<String>, f: &mut Formatter) -> FmtResult {
+    match *request_id {
+        Some(ref id) => write!(f, " (request id {})", id),
+        None => Ok(()),
+    }
+}
+
+#[cfg(any(feature = "hyper", feature = "reqwest"))]
+impl StdError for ApiError {}
+
+#[cfg(any(feature = "hyper", feature = "reqwest"))]
+impl Display for ApiError {
     fn fmt(&self, f: &mut Formatter) -> FmtResult {
-        f.write_str(&*self.to_string())
+        let title = self.title.as_deref().unwrap_or("Unknown error");
+
+        match self.detail {
+            Some(ref detail) => write!(f, "{}: {}", title, detail),
+            None => f.write_str(title),
+        }
     }
 }
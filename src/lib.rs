@@ -1,15 +1,21 @@
 //! # kitsu.rs
 //!
 //! An unofficial Rust library acting as a wrapper around the [Kitsu] API, offering
-//! implementations for both asynchronous hyper(v0.11) and synchronous reqwest(0.8.0).
+//! implementations for asynchronous hyper (v0.13), and both asynchronous and
+//! synchronous reqwest (v0.10).
 //!
 //! **note:** The library supports retrieval from the API, but does not currently
-//! support authenticated requests.
+//! support authenticated requests, other than retrieving the user that an access
+//! token belongs to.
 //!
 //! ### Compile features
 //!
+//! - **chrono**: Compiles with `chrono` support, for parsed timestamps on models
 //! - **hyper-support**: Compiles with `hyper` support
+//! - **mock**: Compiles with a `MockRequester` serving canned responses, for
+//!   testing without a connection to the internet
 //! - **reqwest-support**: Compliles with `reqwest` support (*default*)
+//! - **reqwest-async**: Compiles with asynchronous `reqwest` support
 //!
 //! ### Installation
 //!
@@ -52,7 +58,7 @@
 //! # fn main() {
 //! #
 //! use kitsu_io::KitsuReqwestRequester;
-//! use reqwest::Client;
+//! use reqwest::blocking::Client;
 //! use std::io::{self, Write};
 //!
 //! // Create the reqwest Client.
@@ -97,6 +103,10 @@
 //! [license file]: https://github.com/zeyla/kitsu.rs/blob/master/README.md
 #![deny(missing_docs)]
 
+#[cfg(feature = "chrono")]
+extern crate chrono;
+#[cfg(feature = "futures-util")]
+extern crate futures_util;
 #[cfg(feature = "hyper")]
 extern crate hyper;
 #[cfg(feature = "hyper")]
@@ -110,21 +120,35 @@ extern crate serde;
 extern crate serde_derive;
 #[cfg(feature = "serde_derive")]
 extern crate serde_json;
+extern crate url;
 
 pub mod bridge;
 pub mod builder;
+#[cfg(feature = "reqwest")]
+pub mod client;
 
+#[cfg(feature = "mock")]
+pub mod mock;
 #[cfg(feature = "serde_derive")]
 pub mod model;
+pub mod prelude;
 
 mod error;
 
 pub use error::{Error, Result};
+#[cfg(any(feature = "hyper", feature = "reqwest"))]
+pub use error::ApiError;
+#[cfg(feature = "reqwest")]
+pub use client::{KitsuClient, KitsuClientBuilder};
 
 #[cfg(feature = "hyper")]
 pub use bridge::hyper::KitsuRequester as KitsuHyperRequester;
 #[cfg(feature = "reqwest")]
 pub use bridge::reqwest::KitsuRequester as KitsuReqwestRequester;
+#[cfg(feature = "reqwest-async")]
+pub use bridge::reqwest_async::KitsuRequester as KitsuAsyncRequester;
+#[cfg(feature = "reqwest-async")]
+pub use bridge::reqwest_async::KitsuAsyncClient;
 
 /// Kitsu API Url
-pub const API_URL: &'static str = "https://kitsu.io/api/edge";
+pub const API_URL: &str = "https://kitsu.io/api/edge";
@@ -0,0 +1,218 @@
+//! An offline requester which serves canned responses, for testing code which
+//! makes requests without a connection to the internet.
+//!
+//! The requester serves its responses from memory, so no socket is opened and
+//! no requests leave the process.
+//!
+//! # Examples
+//!
+//! Write the code under test against the [`KitsuReqwestRequester`] trait, and
+//! pass it a [`MockRequester`] in tests:
+//!
+//! ```rust
+//! use kitsu_io::mock::MockRequester;
+//! use kitsu_io::KitsuReqwestRequester;
+//!
+//! fn category_titles<R: KitsuReqwestRequester>(requester: &R, anime_id: u64) -> Vec<String> {
+//!     let categories = requester.get_anime_categories(anime_id)
+//!         .expect("Error getting categories");
+//!
+//!     categories.data.into_iter().map(|c| c.attributes.title).collect()
+//! }
+//!
+//! let mock = MockRequester::new();
+//! mock.respond("/anime/1/categories", r#"{
+//!     "data": [
+//!         {
+//!             "id": "1",
+//!             "type": "categories",
+//!             "links": {},
+//!             "attributes": {
+//!                 "childCount": 0,
+//!                 "description": null,
+//!                 "nsfw": false,
+//!                 "slug": "space",
+//!                 "title": "Space"
+//!             }
+//!         }
+//!     ]
+//! }"#);
+//!
+//! assert_eq!(category_titles(&mock, 1), vec!["Space"]);
+//! assert_eq!(mock.requests(), vec!["/anime/1/categories"]);
+//! ```
+//!
+//! [`KitsuReqwestRequester`]: ../bridge/reqwest/trait.KitsuRequester.html
+//! [`MockRequester`]: struct.MockRequester.html
+
+use crate::bridge::reqwest::sealed::{Base, RawRequest, RawResponse};
+use crate::bridge::JSON_API;
+use crate::{Result, API_URL};
+use reqwest::header::{HeaderMap, HeaderValue, CONTENT_TYPE};
+use reqwest::{Method, StatusCode};
+use serde::Serialize;
+use std::collections::HashMap;
+use std::sync::{Mutex, MutexGuard};
+
+/// The path the API is served under by the [`API_URL`], which is stripped
+/// from the paths requests are matched by.
+///
+/// [`API_URL`]: ../constant.API_URL.html
+const API_PATH: &str = "/api/edge";
+
+/// The body of the response to a request without a canned response.
+const NOT_FOUND: &str = r#"{"errors":[{"title":"Record not found","status":"404"}]}"#;
+
+/// A requester which serves canned responses from memory, rather than making
+/// requests to the API.
+///
+/// Every method of the [`KitsuReqwestRequester`] trait is available, so code
+/// which is generic over the trait, or which takes it as a trait object, can
+/// be given a `MockRequester` in place of a [`KitsuClient`] or `reqwest`
+/// Client.
+///
+/// Responses are matched by the path and query of the request relative to the
+/// API, such as `/anime/1` or `/anime?filter[text]=bebop`. A response for a
+/// path without a query is served for any query on that path, unless a
+/// response was given for the exact query. Requests without a response are
+/// served a `404 Not Found` error document.
+///
+/// [`KitsuClient`]: ../client/struct.KitsuClient.html
+/// [`KitsuReqwestRequester`]: ../bridge/reqwest/trait.KitsuRequester.html
+#[derive(Debug, Default)]
+pub struct MockRequester {
+    state: Mutex<State>,
+}
+
+#[derive(Debug, Default)]
+struct State {
+    requests: Vec<String>,
+    responses: HashMap<String, (StatusCode, Vec<u8>)>,
+}
+
+impl MockRequester {
+    /// Creates a new requester without any canned responses.
+    ///
+    /// Responses are not cached by their `ETag`, so each call makes a request.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Serves a `200 OK` response with the given JSON body to requests for a
+    /// path, replacing any previous response for the path.
+    pub fn respond(&self, path: &str, body: &str) -> &Self {
+        self.respond_with_status(path, StatusCode::OK, body)
+    }
+
+    /// Serves a `200 OK` response with the given value serialized as its JSON
+    /// body, as in [`respond`].
+    ///
+    /// This can be used to serve a [`Response`] built from the models, rather
+    /// than writing out the JSON body.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use kitsu_io::mock::MockRequester;
+    /// use kitsu_io::model::{Category, Response};
+    /// use kitsu_io::KitsuReqwestRequester;
+    ///
+    /// let mock = MockRequester::new();
+    /// let categories: Response<Vec<Category>> = Response {
+    ///     data: Vec::new(),
+    ///     included: Vec::new(),
+    ///     links: Default::default(),
+    ///     meta: Default::default(),
+    /// };
+    /// mock.respond_value("/anime/1/categories", &categories);
+    ///
+    /// assert!(mock.get_anime_categories(1).unwrap().data.is_empty());
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value could not be serialized.
+    ///
+    /// [`Response`]: ../model/struct.Response.html
+    /// [`respond`]: #method.respond
+    pub fn respond_value<T: Serialize>(&self, path: &str, value: &T) -> &Self {
+        let body = serde_json::to_string(value).expect("Error serializing the response");
+
+        self.respond(path, &body)
+    }
+
+    /// Serves a response with the given status and JSON body to requests for a
+    /// path, as in [`respond`].
+    ///
+    /// This can be used to test the handling of errors, such as a
+    /// `429 Too Many Requests` response.
+    ///
+    /// [`respond`]: #method.respond
+    pub fn respond_with_status(&self, path: &str, status: StatusCode, body: &str) -> &Self {
+        self.state().responses.insert(path.to_owned(), (status, body.as_bytes().to_vec()));
+
+        self
+    }
+
+    /// The paths and queries of the requests which were made, in the order
+    /// they were received.
+    pub fn requests(&self) -> Vec<String> {
+        self.state().requests.clone()
+    }
+
+    /// Removes every canned response and recorded request.
+    pub fn reset(&self) {
+        let mut state = self.state();
+
+        state.requests.clear();
+        state.responses.clear();
+    }
+
+    /// The base URL which the URLs of requests are built from, which is the
+    /// [`API_URL`]. No requests are made to it.
+    ///
+    /// [`API_URL`]: ../constant.API_URL.html
+    pub fn base_url(&self) -> &str {
+        API_URL
+    }
+
+    fn state(&self) -> MutexGuard<'_, State> {
+        self.state.lock().unwrap_or_else(|why| why.into_inner())
+    }
+}
+
+impl Base for MockRequester {
+    fn base_url(&self) -> &str {
+        API_URL
+    }
+
+    fn send(&self, request: RawRequest) -> Result<RawResponse> {
+        let route = request.url.path();
+        let route = route.strip_prefix(API_PATH).unwrap_or(route).to_owned();
+        let path = match request.url.query() {
+            Some(query) => format!("{}?{}", route, query),
+            None => route.clone(),
+        };
+
+        let (status, body) = {
+            let mut state = self.state();
+            let response = state.responses.get(&path)
+                .or_else(|| state.responses.get(&route))
+                .cloned()
+                .unwrap_or_else(|| (StatusCode::NOT_FOUND, NOT_FOUND.as_bytes().to_vec()));
+
+            state.requests.push(path);
+
+            response
+        };
+
+        let mut headers = HeaderMap::new();
+        headers.insert(CONTENT_TYPE, HeaderValue::from_static(JSON_API));
+
+        Ok(RawResponse {
+            body: if request.method == Method::HEAD { Vec::new() } else { body },
+            headers,
+            status,
+        })
+    }
+}
@@ -1,15 +1,24 @@
 //! Models in struct form, parsed out from JSON in response bodies.
 
-use serde_json;
+#[cfg(feature = "chrono")]
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::de::Error as DeError;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde_json::{self, Value};
 use std::collections::HashMap;
-use ::Result;
+use std::error::Error as StdError;
+use std::fmt::{Display, Formatter, Result as FmtResult};
+use std::result::Result as StdResult;
+use std::str::FromStr;
+use crate::Result;
 
 /// Information about an anime.
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct Anime {
     /// Information about the anime.
     pub attributes: AnimeAttributes,
     /// The id of the anime.
+    #[serde(deserialize_with="string_or_number")]
     pub id: String,
     /// The type of item this is. Should always be [`Type::Anime`].
     ///
@@ -29,6 +38,25 @@ impl Anime {
         self.attributes.airing_status()
     }
 
+    /// Whether the anime is intended for adults.
+    #[inline]
+    pub fn is_adult(&self) -> bool {
+        self.attributes.is_adult()
+    }
+
+    /// The identity of the anime, made of its type and id.
+    #[inline]
+    pub fn media_id(&self) -> MediaId {
+        MediaId::new(self.kind, self.id.clone())
+    }
+
+    /// The average rating of the anime on a 0-5 star scale, rounded to the
+    /// nearest half-star.
+    #[inline]
+    pub fn star_rating(&self) -> Option<f32> {
+        self.attributes.star_rating()
+    }
+
     /// Generates a URL to the Kitsu page for the anime.
     #[inline]
     pub fn url(&self) -> String {
@@ -45,7 +73,7 @@ impl Anime {
 /// Information about an [`Anime`].
 ///
 /// [`Anime`]: struct.Anime.html
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 #[serde(rename_all= "camelCase")]
 pub struct AnimeAttributes {
     /// Shortened nicknames for the [anime][`Anime`].
@@ -55,6 +83,7 @@ pub struct AnimeAttributes {
     /// `Attack on Titan`
     ///
     /// [`Anime`]: struct.Anime.html
+    #[serde(default, deserialize_with="string_or_vec")]
     pub abbreviated_titles: Option<Vec<String>>,
     /// Age rating for the anime.
     ///
@@ -68,11 +97,11 @@ pub struct AnimeAttributes {
     ///
     /// `Violence, Profanity`
     pub age_rating_guide: Option<String>,
-    /// The average of all user ratings for the anime.
+    /// The average of all user ratings for the anime, as a percentage.
     ///
     /// # Examples
     ///
-    /// `4.26984658306698`
+    /// `85.39`
     pub average_rating: Option<String>,
     /// Canonical title for the anime.
     ///
@@ -86,12 +115,19 @@ pub struct AnimeAttributes {
     ///
     /// `https://static.hummingbird.me/anime/7442/cover/$1.png`
     pub cover_image: Option<CoverImage>,
-    /// The cover's offset from the top.
+    /// The cover's offset from the top, if the media has a cover.
     ///
     /// # Examples
     ///
     /// `263`
-    pub cover_image_top_offset: u16,
+    #[serde(default)]
+    pub cover_image_top_offset: Option<u16>,
+    /// When the anime was added to Kitsu.
+    ///
+    /// # Examples
+    ///
+    /// `2013-02-20T16:00:13.609Z`
+    pub created_at: Option<String>,
     /// Date the anime finished airing.
     ///
     /// # Examples
@@ -160,7 +196,23 @@ pub struct AnimeAttributes {
     ///
     /// `2013-04-07`
     pub start_date: Option<String>,
+    /// The release status of the anime, as determined by the API.
+    ///
+    /// This is used by [`airing_status`] when present.
+    ///
+    /// [`airing_status`]: #method.airing_status
+    #[serde(default)]
+    pub status: Option<AiringStatusKind>,
     /// The sub type of the anime.
+    ///
+    /// Use [`sub_type_typed`] to retrieve this as an [`AnimeSubType`].
+    ///
+    /// # Examples
+    ///
+    /// `TV`
+    ///
+    /// [`AnimeSubType`]: enum.AnimeSubType.html
+    /// [`sub_type_typed`]: #method.sub_type_typed
     pub sub_type: Option<String>,
     /// Synopsis of the anime.
     ///
@@ -168,8 +220,22 @@ pub struct AnimeAttributes {
     ///
     /// `Several hundred years ago, humans were exterminated by titans...`
     pub synopsis: String,
+    /// A note on when an anime which is yet to be released is expected to
+    /// air, if its date has not been announced.
+    ///
+    /// # Examples
+    ///
+    /// `Fall 2017`
+    #[serde(default)]
+    pub tba: Option<String>,
     /// The titles of the anime.
     pub titles: AnimeTitles,
+    /// When the anime was last updated.
+    ///
+    /// # Examples
+    ///
+    /// `2017-02-27T14:26:54.672Z`
+    pub updated_at: Option<String>,
     /// The number of users who have marked the anime.
     ///
     /// # Examples
@@ -186,14 +252,117 @@ pub struct AnimeAttributes {
 
 impl AnimeAttributes {
     /// The current airing status of the anime.
+    ///
+    /// This is read from the [`status`] given by the API when present.
+    /// Otherwise, it is inferred from whether the anime has an end date.
+    ///
+    /// [`status`]: #structfield.status
     pub fn airing_status(&self) -> AiringStatus {
-        if self.end_date.is_some() {
-            AiringStatus::Finished
-        } else {
-            AiringStatus::Airing
+        match self.status {
+            Some(AiringStatusKind::Current) => AiringStatus::Airing,
+            Some(AiringStatusKind::Finished) => AiringStatus::Finished,
+            Some(AiringStatusKind::Tba)
+            | Some(AiringStatusKind::Unreleased)
+            | Some(AiringStatusKind::Upcoming) => AiringStatus::Upcoming,
+            Some(AiringStatusKind::Unknown) | None if self.end_date.is_some() => {
+                AiringStatus::Finished
+            },
+            Some(AiringStatusKind::Unknown) | None => AiringStatus::Airing,
         }
     }
 
+    /// Parses the [`average_rating`] of the anime, which is given by Kitsu as a
+    /// string.
+    ///
+    /// Returns `None` if the anime has not been rated, or if the rating could
+    /// not be parsed.
+    ///
+    /// [`average_rating`]: #structfield.average_rating
+    pub fn average_rating_f64(&self) -> Option<f64> {
+        self.average_rating.as_ref().and_then(|rating| rating.trim().parse().ok())
+    }
+
+    /// Whether the anime is intended for adults.
+    ///
+    /// This is the case if the anime is marked as [`nsfw`], or if its
+    /// [`age_rating`] is [`AgeRating::R18`] or [`AgeRating::R18Plus`], as some
+    /// anime with an adult age rating are not marked as NSFW.
+    ///
+    /// [`AgeRating::R18`]: enum.AgeRating.html#variant.R18
+    /// [`AgeRating::R18Plus`]: enum.AgeRating.html#variant.R18Plus
+    /// [`age_rating`]: #structfield.age_rating
+    /// [`nsfw`]: #structfield.nsfw
+    pub fn is_adult(&self) -> bool {
+        self.nsfw || matches!(
+            self.age_rating,
+            Some(AgeRating::R18) | Some(AgeRating::R18Plus)
+        )
+    }
+
+    /// The average rating of the anime on a 0-5 star scale, rounded to the
+    /// nearest half-star.
+    ///
+    /// The [`average_rating`] is given as a percentage, so it is divided by
+    /// 20 to convert it to stars. Returns `None` if the anime has not been
+    /// rated.
+    ///
+    /// [`average_rating`]: #structfield.average_rating
+    pub fn star_rating(&self) -> Option<f32> {
+        self.average_rating_f64().map(|rating| star_rating(rating as f32))
+    }
+
+    /// Parses the [`created_at`] timestamp of the anime.
+    ///
+    /// Returns `None` if the timestamp is not present or is not valid
+    /// RFC 3339.
+    ///
+    /// [`created_at`]: #structfield.created_at
+    #[cfg(feature = "chrono")]
+    pub fn created_at_parsed(&self) -> Option<DateTime<Utc>> {
+        self.created_at.as_deref().and_then(parse_datetime)
+    }
+
+    /// Parses the [`end_date`] of the anime.
+    ///
+    /// Returns `None` if the date is not present or is not a valid date, such
+    /// as when it is empty.
+    ///
+    /// [`end_date`]: #structfield.end_date
+    #[cfg(feature = "chrono")]
+    pub fn end_date_parsed(&self) -> Option<NaiveDate> {
+        self.end_date.as_deref().and_then(parse_date)
+    }
+
+    /// Parses the [`start_date`] of the anime.
+    ///
+    /// Returns `None` if the date is not present or is not a valid date, such
+    /// as when it is empty.
+    ///
+    /// [`start_date`]: #structfield.start_date
+    #[cfg(feature = "chrono")]
+    pub fn start_date_parsed(&self) -> Option<NaiveDate> {
+        self.start_date.as_deref().and_then(parse_date)
+    }
+
+    /// Parses the [`updated_at`] timestamp of the anime.
+    ///
+    /// Returns `None` if the timestamp is not present or is not valid
+    /// RFC 3339.
+    ///
+    /// [`updated_at`]: #structfield.updated_at
+    #[cfg(feature = "chrono")]
+    pub fn updated_at_parsed(&self) -> Option<DateTime<Utc>> {
+        self.updated_at.as_deref().and_then(parse_datetime)
+    }
+
+    /// The [`sub_type`] of the anime as an [`AnimeSubType`], if present.
+    ///
+    /// [`AnimeSubType`]: enum.AnimeSubType.html
+    /// [`sub_type`]: #structfield.sub_type
+    pub fn sub_type_typed(&self) -> Option<AnimeSubType> {
+        self.sub_type.as_deref().map(AnimeSubType::parse)
+    }
+
     /// Generates a URL to the Kitsu page for the anime.
     #[inline]
     pub fn url(&self) -> String {
@@ -208,7 +377,7 @@ impl AnimeAttributes {
 }
 
 /// Links related to the media item.
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct Links {
     /// Link to a related media item.
     pub related: String,
@@ -218,36 +387,128 @@ pub struct Links {
 }
 
 /// A relationship for a media item.
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct Relationship {
     /// Links for one set of the media item's related links.
     pub links: Links,
 }
 
+impl Relationship {
+    /// The URL of the related resources themselves, which can be given to
+    /// the requesters' `fetch` method to retrieve them.
+    ///
+    /// # Examples
+    ///
+    /// `https://kitsu.io/api/edge/anime/1/genres`
+    #[inline]
+    pub fn related_url(&self) -> &str {
+        &self.links.related
+    }
+
+    /// The URL of the relationship itself, which only contains the types and
+    /// ids of the related resources.
+    ///
+    /// # Examples
+    ///
+    /// `https://kitsu.io/api/edge/anime/1/relationships/genres`
+    #[inline]
+    pub fn self_url(&self) -> &str {
+        &self.links.own
+    }
+}
+
+/// The type and id of a related resource, which identify it among the
+/// [`included`] resources of a response when it has been sideloaded.
+///
+/// [`included`]: struct.Response.html#structfield.included
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct ResourceIdentifier {
+    /// The id of the resource.
+    #[serde(deserialize_with="string_or_number")]
+    pub id: String,
+    /// The type of the resource.
+    #[serde(rename="type")]
+    pub kind: Type,
+}
+
+/// A relationship to a single resource, such as one side of a [`Follow`].
+///
+/// [`Follow`]: struct.Follow.html
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct ToOneRelationship {
+    /// The type and id of the related resource, which is given when the
+    /// resource has been sideloaded, or `None` if there is no related
+    /// resource.
+    pub data: Option<ResourceIdentifier>,
+    /// Links for the related resource.
+    pub links: Option<Links>,
+}
+
 /// Relationships for an [`Anime`].
 ///
 /// [`Anime`]: struct.Anime.html
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct AnimeRelationships {
     /// Castings for the anime.
-    pub castings: Relationship,
+    pub castings: Option<Relationship>,
     /// The anime's episodes.
     pub episodes: Relationship,
     /// The anime's genres.
     pub genres: Relationship,
     /// The anime's installments.
-    pub installments: Relationship,
+    pub installments: Option<Relationship>,
     /// The anime's mappings.
-    pub mappings: Relationship,
+    pub mappings: Option<Relationship>,
     /// The anime's reviews.
-    pub reviews: Relationship,
+    pub reviews: Option<Relationship>,
     /// The anime's streaming links.
     #[serde(rename="streamingLinks")]
-    pub streaming_links: Relationship,
+    pub streaming_links: Option<Relationship>,
+}
+
+/// A production credit of an [`Anime`], linking it to a [`Producer`], such as
+/// the studio which animated it.
+///
+/// [`Anime`]: struct.Anime.html
+/// [`Producer`]: struct.Producer.html
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct AnimeProduction {
+    /// Information about the production.
+    pub attributes: AnimeProductionAttributes,
+    /// The id of the production.
+    #[serde(deserialize_with="string_or_number")]
+    pub id: String,
+    /// The type of item this is. Should always be
+    /// [`Type::AnimeProductions`].
+    ///
+    /// [`Type::AnimeProductions`]: enum.Type.html#variant.AnimeProductions
+    #[serde(rename="type")]
+    pub kind: Type,
+    /// Links related to the production.
+    pub links: HashMap<String, String>,
+}
+
+/// Information about an [`AnimeProduction`].
+///
+/// [`AnimeProduction`]: struct.AnimeProduction.html
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct AnimeProductionAttributes {
+    /// The role of the producer in the production of the anime.
+    ///
+    /// # Examples
+    ///
+    /// `studio`
+    pub role: AnimeProductionRole,
 }
 
 /// Information about the cover image for a media item.
-#[derive(Clone, Debug, Deserialize)]
+///
+/// Some legacy entries provide the cover image as a lone URL rather than an
+/// object of sizes, in which case it is deserialized into the [`original`]
+/// field.
+///
+/// [`original`]: #structfield.original
+#[derive(Clone, Debug, Serialize)]
 pub struct CoverImage {
     /// Link to the large copy.
     pub large: Option<String>,
@@ -264,623 +525,3001 @@ impl CoverImage {
     /// This places priority on the [`original`] field.
     ///
     /// [`original`]: #structfield.original
-    pub fn largest<'a>(&'a self) -> Option<&'a String> {
+    pub fn largest(&self) -> Option<&String> {
         self.original.as_ref().or(self.large.as_ref()).or(self.small.as_ref())
     }
-}
-
-/// A list of links to the media's relevant images.
-#[derive(Clone, Debug, Deserialize)]
-pub struct Image {
-    /// Link to a large size of the image.
-    pub large: Option<String>,
-    /// Link to a medium size of the image.
-    pub medium: Option<String>,
-    /// Link to an original size of the image.
-    pub original: Option<String>,
-    /// Link to a small size of the image.
-    pub small: Option<String>,
-    /// Link to a tiny size of the image.
-    pub tiny: Option<String>,
-}
 
-impl Image {
-    /// Retrieves the URL to the largest image in descending order where
-    /// available, if any.
+    /// Retrieves a usable URL to the cover image in the given size, if
+    /// available.
     ///
-    /// This places priority on the [`original`] field.
+    /// Kitsu sometimes gives image URLs as templates containing `$1` in place
+    /// of the size, which is substituted with the name of the size. If there
+    /// is no link for the size, such as for the sizes which cover images do
+    /// not have, a template in the [`original`] field is used instead.
     ///
     /// [`original`]: #structfield.original
-    pub fn largest<'a>(&'a self) -> Option<&'a String> {
-        self.original.as_ref()
-            .or(self.large.as_ref())
-            .or(self.medium.as_ref())
-            .or(self.small.as_ref())
-            .or(self.tiny.as_ref())
+    pub fn url_for_size(&self, size: ImageSize) -> Option<String> {
+        let url = match size {
+            ImageSize::Large => self.large.as_ref(),
+            ImageSize::Original => self.original.as_ref(),
+            ImageSize::Small => self.small.as_ref(),
+            ImageSize::Medium | ImageSize::Tiny => None,
+        };
+
+        image_url(url, self.original.as_ref(), size)
     }
 }
 
-/// Information about a manga.
-#[derive(Clone, Debug, Deserialize)]
-pub struct Manga {
-    /// Information about the manga.
-    pub attributes: MangaAttributes,
-    /// The id of the manga.
+impl<'de> Deserialize<'de> for CoverImage {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> StdResult<Self, D::Error> {
+        #[derive(Deserialize)]
+        struct Sizes {
+            large: Option<String>,
+            original: Option<String>,
+            small: Option<String>,
+        }
+
+        Ok(match ImageOrUrl::deserialize(deserializer)? {
+            ImageOrUrl::Image(Sizes { large, original, small }) => CoverImage {
+                large,
+                original,
+                small,
+            },
+            ImageOrUrl::Url(url) => CoverImage {
+                large: None,
+                original: Some(url),
+                small: None,
+            },
+        })
+    }
+}
+
+/// Information about a person's role in an [`Anime`], such as a voice actor
+/// for a [`Character`].
+///
+/// [`Anime`]: struct.Anime.html
+/// [`Character`]: struct.Character.html
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct Casting {
+    /// Information about the casting.
+    pub attributes: CastingAttributes,
+    /// The id of the casting.
+    #[serde(deserialize_with="string_or_number")]
     pub id: String,
-    /// The type of item this is. Should always be [`Type::Manga`].
+    /// The type of item this is. Should always be [`Type::Castings`].
     ///
-    /// [`Type::Manga`]: enum.Type.html#variant.Manga
+    /// [`Type::Castings`]: enum.Type.html#variant.Castings
     #[serde(rename="type")]
     pub kind: Type,
-    /// Links related to the manga.
+    /// Links related to the casting.
     pub links: HashMap<String, String>,
 }
 
-impl Manga {
-    /// The current airing status of the manga.
-    #[inline]
-    pub fn airing_status(&self) -> AiringStatus {
-        self.attributes.airing_status()
-    }
-
-    /// Generates a URL to the Kitsu page for the manga.
-    #[inline]
-    pub fn url(&self) -> String {
-        self.attributes.url()
-    }
-
-    /// Generates a formatted URL to the youtube video.
-    #[inline]
-    pub fn youtube_url(&self) -> Option<String> {
-        self.attributes.youtube_url()
-    }
-}
-
-/// Information about a [`Manga`].
+/// Information about a [`Casting`].
 ///
-/// [`Manga`]: struct.Manga.html
-#[derive(Clone, Debug, Deserialize)]
+/// [`Casting`]: struct.Casting.html
+#[derive(Clone, Debug, Deserialize, Serialize)]
 #[serde(rename_all="camelCase")]
-pub struct MangaAttributes {
-    /// Shortened nicknames for the manga.
-    pub abbreviated_titles: Option<Vec<String>>,
-    /// The average of all user ratings for the manga.
+pub struct CastingAttributes {
+    /// Whether the casting is featured on the anime's page.
+    pub featured: bool,
+    /// The language that the role was performed in, if the casting is for a
+    /// voice actor.
     ///
     /// # Examples
     ///
-    /// `4.34926964198231`
-    pub average_rating: Option<String>,
-    /// Canonical title for the manga.
+    /// `Japanese`
+    pub language: Option<String>,
+    /// The role of the person in the anime.
     ///
     /// # Examples
     ///
-    /// `Horimiya`
-    pub canonical_title: String,
-    /// The number of chapters released.
-    pub chapter_count: Option<u64>,
-    /// The URL template for the cover.
+    /// `Voice Actor`
+    pub role: Option<String>,
+    /// Whether the person is a voice actor for a character.
+    pub voice_actor: bool,
+}
+
+/// Information about a category, which is a more granular form of a
+/// [`Genre`].
+///
+/// [`Genre`]: struct.Genre.html
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct Category {
+    /// Information about the category.
+    pub attributes: CategoryAttributes,
+    /// The id of the category.
+    #[serde(deserialize_with="string_or_number")]
+    pub id: String,
+    /// The type of item this is. Should always be [`Type::Categories`].
+    ///
+    /// [`Type::Categories`]: enum.Type.html#variant.Categories
+    #[serde(rename="type")]
+    pub kind: Type,
+    /// Links related to the category.
+    pub links: HashMap<String, String>,
+}
+
+/// Information about a [`Category`].
+///
+/// [`Category`]: struct.Category.html
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all="camelCase")]
+pub struct CategoryAttributes {
+    /// The number of categories which are children of the category.
     ///
     /// # Examples
     ///
-    /// `https://static.hummingbird.me/manga/22352/cover/$1.png`
-    pub cover_image: Option<CoverImage>,
-    /// The cover's offset from the top.
+    /// `4`
+    pub child_count: u32,
+    /// Description of the category.
+    pub description: Option<String>,
+    /// Whether the category is Not Safe For Work.
+    pub nsfw: bool,
+    /// The URL slug of the category.
     ///
     /// # Examples
     ///
-    /// `60`
-    pub cover_image_top_offset: u16,
-    /// Date the manga finished.
+    /// `space`
+    pub slug: String,
+    /// The title of the category.
     ///
     /// # Examples
     ///
-    /// `2013-09-28`
-    pub end_date: Option<String>,
-    /// Show format of the manga.
+    /// `Space`
+    pub title: String,
+}
+
+/// Information about a chapter of a [`Manga`].
+///
+/// [`Manga`]: struct.Manga.html
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct Chapter {
+    /// Information about the chapter.
+    pub attributes: ChapterAttributes,
+    /// The id of the chapter.
+    #[serde(deserialize_with="string_or_number")]
+    pub id: String,
+    /// The type of item this is. Should always be [`Type::Chapters`].
     ///
-    /// # Examples
+    /// [`Type::Chapters`]: enum.Type.html#variant.Chapters
+    #[serde(rename="type")]
+    pub kind: Type,
+    /// Links related to the chapter.
+    pub links: HashMap<String, String>,
+}
+
+/// Information about a [`Chapter`].
+///
+/// [`Chapter`]: struct.Chapter.html
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all="camelCase")]
+pub struct ChapterAttributes {
+    /// Canonical title for the chapter.
     ///
-    /// [`MangaType::Novel`]
+    /// # Examples
     ///
-    /// [`MangaType::Novel`]: enum.MangaType.html#variant.Novel
-    #[serde(rename="mangaType")]
-    pub kind: MangaType,
-    /// The rank based on the popularityof the manga.
+    /// `Hori and Miyamura`
+    pub canonical_title: Option<String>,
+    /// How many pages long the chapter is.
     ///
     /// # Examples
     ///
-    /// `10`
-    pub popularity_rank: Option<u32>,
-    /// The URL template for the poster.
+    /// `42`
+    pub length: Option<u32>,
+    /// The number of the chapter within the manga.
     ///
     /// # Examples
     ///
-    /// `https://static.hummingbird.me/manga/22352/poster/$1.png`
-    pub poster_image: Image,
-    /// How many times each rating has been given to the manga.
-    pub rating_frequencies: RatingFrequencies,
-    /// The rank of the manga based on its overall rating.
+    /// `1`
+    pub number: Option<u32>,
+    /// Date the chapter was published.
     ///
     /// # Examples
     ///
-    /// `13`
-    pub rating_rank: Option<u32>,
-    /// Name of media of serialization.
-    pub serialization: Option<String>,
-    /// Unique slug used for page URLs.
+    /// `2011-10-18`
+    pub published: Option<String>,
+    /// Synopsis of the chapter.
+    pub synopsis: Option<String>,
+    /// The volume that the chapter is in.
     ///
     /// # Examples
     ///
-    /// `horimiya`
-    pub slug: String,
-    /// Date the manga was serialized.
+    /// `1`
+    pub volume_number: Option<u32>,
+}
+
+/// Information about a character which appears in media.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct Character {
+    /// Information about the character.
+    pub attributes: CharacterAttributes,
+    /// The id of the character.
+    #[serde(deserialize_with="string_or_number")]
+    pub id: String,
+    /// The type of item this is. Should always be [`Type::Characters`].
+    ///
+    /// [`Type::Characters`]: enum.Type.html#variant.Characters
+    #[serde(rename="type")]
+    pub kind: Type,
+    /// Links related to the character.
+    pub links: HashMap<String, String>,
+}
+
+/// Information about a [`Character`].
+///
+/// [`Character`]: struct.Character.html
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct CharacterAttributes {
+    /// Description of the character.
+    pub description: Option<String>,
+    /// Links to the character's image.
+    pub image: Option<Image>,
+    /// The name of the character.
     ///
     /// # Examples
     ///
-    /// `2013-04-07`
-    pub start_date: Option<String>,
-    /// Synopsis of the manga.
+    /// `Spike Spiegel`
+    pub name: String,
+    /// The URL slug of the character.
     ///
     /// # Examples
     ///
-    /// `Hori may seem like a normal teenage girl, but she's a completely...`
-    pub synopsis: String,
-    /// The titles of the manga.
-    pub titles: MangaTitles,
-    /// The number of volumes released for the manga.
-    pub volume_count: Option<u64>,
-    /// The id of the related YouTube video.
-    pub youtube_video_id: Option<String>,
-}
-
-impl MangaAttributes {
-    /// The current airing status of the manga.
-    pub fn airing_status(&self) -> AiringStatus {
-        if self.end_date.is_some() {
-            AiringStatus::Finished
-        } else {
-            AiringStatus::Airing
-        }
-    }
-
-    /// Generates a URL to the Kitsu page for the manga.
-    #[inline]
-    pub fn url(&self) -> String {
-        format!("https://kitsu.io/manga/{}", self.slug)
-    }
-
-    /// Generates a formatted URL to the youtube video.
-    #[inline]
-    pub fn youtube_url(&self) -> Option<String> {
-        self.youtube_video_id.as_ref().map(youtube_url)
-    }
+    /// `spike-spiegel`
+    pub slug: String,
 }
 
-/// How many times each rating has been given to the media item.
-#[derive(Clone, Copy, Debug, Deserialize)]
-pub struct RatingFrequencies {
-    /// Number of 0 stars given.
-    #[serde(default, rename="0.0")]
-    pub rating_0_0: i64,
-    /// Number of 0.5 stars given.
-    #[serde(default, rename="0.5")]
-    pub rating_0_5: i64,
-    /// Number of 1.0 stars given.
-    #[serde(default, rename="1.0")]
-    pub rating_1_0: i64,
-    /// Nubmer of 1.5 stars given.
-    #[serde(default, rename="1.5")]
-    pub rating_1_5: i64,
-    /// Number of 2.0 stars given.
-    #[serde(default, rename="2.0")]
-    pub rating_2_0: i64,
-    /// Number of 2.5 stars given.
-    #[serde(default, rename="2.5")]
-    pub rating_2_5: i64,
-    /// Number of 3.0 stars given.
-    #[serde(default, rename="3.0")]
-    pub rating_3_0: i64,
-    /// Number of 3.5 stars given.
-    #[serde(default, rename="3.5")]
-    pub rating_3_5: i64,
-    /// Number of 4.0 stars given.
-    #[serde(default, rename="4.0")]
-    pub rating_4_0: i64,
-    /// Number of 4.5 stars given.
-    #[serde(default, rename="4.5")]
-    pub rating_4_5: i64,
-    /// Number of 5.0 stars given.
-    #[serde(default, rename="5.0")]
-    pub rating_5_0: i64,
+/// A comment on a [`Post`], or a reply to another comment.
+///
+/// [`Post`]: struct.Post.html
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct Comment {
+    /// Information about the comment.
+    pub attributes: CommentAttributes,
+    /// The id of the comment.
+    #[serde(deserialize_with="string_or_number")]
+    pub id: String,
+    /// The type of item this is. Should always be [`Type::Comments`].
+    ///
+    /// [`Type::Comments`]: enum.Type.html#variant.Comments
+    #[serde(rename="type")]
+    pub kind: Type,
+    /// Links related to the comment.
+    pub links: HashMap<String, String>,
 }
 
-/// The titles of the anime.
-#[derive(Clone, Debug, Deserialize)]
-pub struct AnimeTitles {
-    /// The English title of the anime.
+/// Information about a [`Comment`].
+///
+/// [`Comment`]: struct.Comment.html
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all="camelCase")]
+pub struct CommentAttributes {
+    /// The content of the comment, as written.
     ///
     /// # Examples
     ///
-    /// `Attack on Titan`
-    pub en: Option<String>,
-    /// The romaji title of the anime.
+    /// `Welcome to Kitsu!`
+    pub content: String,
+    /// The content of the comment, formatted as HTML.
     ///
     /// # Examples
     ///
-    /// `Shingeki no Kyojin`
-    pub en_jp: Option<String>,
-    /// The Japanese title of the anime.
+    /// `<p>Welcome to Kitsu!</p>`
+    pub content_formatted: Option<String>,
+    /// When the comment was created.
     ///
     /// # Examples
     ///
-    /// `進撃の巨人`
-    pub ja_jp: Option<String>,
-}
-
-/// The titles of the manga.
-#[derive(Clone, Debug, Deserialize)]
-pub struct MangaTitles {
-    /// The English title of the manga.
+    /// `2017-05-22T06:00:10.247Z`
+    pub created_at: String,
+    /// When the comment was last edited, if it has been.
     ///
     /// # Examples
     ///
-    /// `Attack on Titan`
-    pub en: Option<String>,
-    /// The romaji title of the manga.
+    /// `2017-05-22T06:00:10.247Z`
+    #[serde(default)]
+    pub edited_at: Option<String>,
+    /// The number of likes of the comment.
     ///
     /// # Examples
     ///
-    /// `Shingeki no Kyojin`
-    pub en_jp: Option<String>,
+    /// `3`
+    #[serde(default)]
+    pub likes_count: u64,
+    /// The number of replies to the comment.
+    ///
+    /// # Examples
+    ///
+    /// `1`
+    #[serde(default)]
+    pub replies_count: u64,
+    /// When the comment was last updated.
+    ///
+    /// # Examples
+    ///
+    /// `2017-05-22T06:00:10.247Z`
+    pub updated_at: Option<String>,
 }
 
-/// Data from a response.
-#[derive(Clone, Debug, Deserialize)]
-pub struct Response<T> {
-    /// The full data from a response.
-    pub data: T,
-    /// Links relevant to the search.
-    #[serde(default)]
-    pub links: HashMap<String, String>,
+impl CommentAttributes {
+    /// Parses the [`created_at`] timestamp of the comment.
+    ///
+    /// Returns `None` if the timestamp is not valid RFC 3339.
+    ///
+    /// [`created_at`]: #structfield.created_at
+    #[cfg(feature = "chrono")]
+    pub fn created_at_parsed(&self) -> Option<DateTime<Utc>> {
+        parse_datetime(&self.created_at)
+    }
 }
 
-/// Information about a user.
-#[derive(Clone, Debug, Deserialize)]
-pub struct User {
-    /// Information about the user.
-    pub attributes: UserAttributes,
-    /// The id of the user.
+/// Information about an episode of an [`Anime`].
+///
+/// [`Anime`]: struct.Anime.html
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct Episode {
+    /// Information about the episode.
+    pub attributes: EpisodeAttributes,
+    /// The id of the episode.
+    #[serde(deserialize_with="string_or_number")]
     pub id: String,
-    /// The type of item this is. Should always be [`Type::User`].
+    /// The type of item this is. Should always be [`Type::Episodes`].
     ///
-    /// [`Type::User`]: enum.Type.html#variant.User
+    /// [`Type::Episodes`]: enum.Type.html#variant.Episodes
     #[serde(rename="type")]
     pub kind: Type,
-    /// Links related to the user.
+    /// Links related to the episode.
     pub links: HashMap<String, String>,
-    /// List of the user's relationships.
-    pub relationships: UserRelationships,
 }
 
-/// Information about a [`User`].
+/// Information about an [`Episode`].
 ///
-/// [`User`]: struct.User.html
-#[derive(Clone, Debug, Deserialize)]
+/// [`Episode`]: struct.Episode.html
+#[derive(Clone, Debug, Deserialize, Serialize)]
 #[serde(rename_all="camelCase")]
-pub struct UserAttributes {
-    /// The raw markdown for the user's long-form about text.
+pub struct EpisodeAttributes {
+    /// Date the episode aired.
     ///
     /// # Examples
     ///
-    /// `I'm curious about https://kitsu.io/anime/nichijou!`
-    pub about: String,
-    /// The processed and sanitized HTML for the user's long form about text.
+    /// `1998-04-03`
+    pub airdate: Option<String>,
+    /// Canonical title for the episode.
     ///
     /// # Examples
     ///
-    /// `I'm curious about <a href="https://kitsu.io/anime/nichijou">Nichijou</a>!`
-    pub about_formatted: Option<String>,
-    /// Links to the user's avatar.
-    pub avatar: Option<Image>,
-    /// A short (140 character) biographical blurb about the user.
+    /// `Asteroid Blues`
+    pub canonical_title: Option<String>,
+    /// How many minutes long the episode is.
     ///
     /// # Examples
     ///
-    /// `私、気になります!`
-    pub bio: Option<String>,
-    /// The user's birthday.
+    /// `24`
+    pub length: Option<u32>,
+    /// The number of the episode within the anime.
     ///
     /// # Examples
     ///
-    /// `1985-07-26`
-    pub birthday: Option<String>,
-    /// Number of comments user has submitted.
+    /// `1`
+    pub number: Option<u32>,
+    /// The season that the episode is in.
     ///
     /// # Examples
     ///
-    /// `15`
-    pub comments_count: u64,
-    /// Links to the user's cover image.
-    pub cover_image: Option<Image>,
-    /// When the user signed up.
-    ///
-    /// # Examples
+    /// `1`
+    pub season_number: Option<u32>,
+    /// Synopsis of the episode.
+    pub synopsis: Option<String>,
+    /// Links to the episode's thumbnail.
+    pub thumbnail: Option<Image>,
+}
+
+/// A [`User`] following another user.
+///
+/// [`User`]: struct.User.html
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct Follow {
+    /// Information about the follow.
+    pub attributes: FollowAttributes,
+    /// The id of the follow.
+    #[serde(deserialize_with="string_or_number")]
+    pub id: String,
+    /// The type of item this is. Should always be [`Type::Follows`].
     ///
-    /// `1985-07-26T22:13:20.223Z`
-    pub created_at: String,
-    /// The user's Facebook id if they have signed in with Facebook.
+    /// [`Type::Follows`]: enum.Type.html#variant.Follows
+    #[serde(rename="type")]
+    pub kind: Type,
+    /// Links related to the follow.
+    pub links: HashMap<String, String>,
+    /// The users on each side of the follow.
+    pub relationships: Option<FollowRelationships>,
+}
+
+/// Information about a [`Follow`].
+///
+/// [`Follow`]: struct.Follow.html
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all="camelCase")]
+pub struct FollowAttributes {
+    /// When the user started following the other user.
     ///
     /// # Examples
     ///
-    /// `1234567890`
-    pub facebook_id: Option<String>,
-    /// The number of media items the user has favorited.
-    pub favorites_count: u64,
-    /// Whether the user's feed is completed.
-    pub feed_completed: bool,
-    /// Number of people following this user.
+    /// `2017-05-22T06:00:10.247Z`
+    pub created_at: Option<String>,
+    /// When the follow was last updated.
     ///
     /// # Examples
     ///
-    /// `12`
-    pub followers_count: u64,
-    /// Number of people this user is following.
-    ///
-    /// # Examples
+    /// `2017-05-22T06:00:10.247Z`
+    pub updated_at: Option<String>,
+}
+
+/// Relationships for a [`Follow`].
+///
+/// [`Follow`]: struct.Follow.html
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct FollowRelationships {
+    /// The user who is followed.
+    pub followed: Option<ToOneRelationship>,
+    /// The user who follows the other user.
+    pub follower: Option<ToOneRelationship>,
+}
+
+/// Information about a franchise, which groups related media together, such
+/// as every entry of the Fate series.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct Franchise {
+    /// Information about the franchise.
+    pub attributes: FranchiseAttributes,
+    /// The id of the franchise.
+    #[serde(deserialize_with="string_or_number")]
+    pub id: String,
+    /// The type of item this is. Should always be [`Type::Franchises`].
     ///
-    /// `300`
-    pub following_count: u64,
-    /// The user's gender, if provided.
+    /// [`Type::Franchises`]: enum.Type.html#variant.Franchises
+    #[serde(rename="type")]
+    pub kind: Type,
+    /// Links related to the franchise.
+    pub links: HashMap<String, String>,
+}
+
+/// Information about a [`Franchise`].
+///
+/// [`Franchise`]: struct.Franchise.html
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all="camelCase")]
+pub struct FranchiseAttributes {
+    /// The canonical title of the franchise.
     ///
     /// # Examples
     ///
-    /// `female`
-    pub gender: Option<String>,
-    /// Number of minutes of anime watched.
+    /// `Fate`
+    pub canonical_title: String,
+    /// Unique slug of the franchise.
     ///
     /// # Examples
     ///
-    /// `550`
-    pub life_spent_on_anime: u64,
-    /// Number of posts user has liked.
-    ///
-    /// # Examples
+    /// `fate`
+    pub slug: String,
+    /// The titles of the franchise, keyed by their language.
+    #[serde(default)]
+    pub titles: HashMap<String, String>,
+}
+
+/// Information about a genre.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct Genre {
+    /// Information about the genre.
+    pub attributes: GenreAttributes,
+    /// The id of the genre.
+    #[serde(deserialize_with="string_or_number")]
+    pub id: String,
+    /// The type of item this is. Should always be [`Type::Genres`].
     ///
-    /// `12`
-    pub likes_given_count: u64,
-    /// Number of likes the user's post has received.
+    /// [`Type::Genres`]: enum.Type.html#variant.Genres
+    #[serde(rename="type")]
+    pub kind: Type,
+    /// Links related to the genre.
+    pub links: HashMap<String, String>,
+}
+
+/// Information about a [`Genre`].
+///
+/// [`Genre`]: struct.Genre.html
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct GenreAttributes {
+    /// Description of the genre.
+    pub description: Option<String>,
+    /// The name of the genre.
     ///
     /// # Examples
     ///
-    /// `45`
-    pub likes_received_count: u64,
-    /// A user-provided location.
+    /// `Action`
+    pub name: String,
+    /// Unique slug of the genre.
     ///
     /// # Examples
     ///
-    /// `The Internet`
-    pub location: Option<String>,
-    /// The user's current username.
-    ///
-    /// # Examples
+    /// `action`
+    pub slug: String,
+}
+
+/// Information about a group, which is a community of users around a shared
+/// interest.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct Group {
+    /// Information about the group.
+    pub attributes: GroupAttributes,
+    /// The id of the group.
+    #[serde(deserialize_with="string_or_number")]
+    pub id: String,
+    /// The type of item this is. Should always be [`Type::Groups`].
     ///
-    /// `chitanda`
-    pub name: String,
-    /// An array of previous names the user has gone by, in
-    /// reverse-chronological order.
+    /// [`Type::Groups`]: enum.Type.html#variant.Groups
+    #[serde(rename="type")]
+    pub kind: Type,
+    /// Links related to the group.
+    pub links: HashMap<String, String>,
+}
+
+/// Information about a [`Group`].
+///
+/// [`Group`]: struct.Group.html
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all="camelCase")]
+pub struct GroupAttributes {
+    /// A description of the group, as written by its leaders.
+    pub about: Option<String>,
+    /// The number of users who are members of the group.
     ///
     /// # Examples
     ///
-    /// ```rs
-    /// vec![
-    ///     "oldn ame".to_owned(),
-    ///     "older name".to_owned()
-    /// ]
-    /// ```
-    pub past_names: Vec<String>,
-    /// Number of posts user has submitted.
+    /// `1234`
+    pub members_count: u64,
+    /// The name of the group.
     ///
     /// # Examples
     ///
-    /// `3`
-    pub posts_count: u64,
-    /// Whether the user has finished completing their profile.
-    pub profile_completed: bool,
-    /// When the user's pro subscripten expires.
-    pub pro_expires_at: Option<String>,
-    /// Number of media user has rated.
+    /// `Mecha Fans`
+    pub name: String,
+    /// Whether the group is marked as being not safe for work.
+    pub nsfw: bool,
+    /// Who is able to join the group.
+    pub privacy: GroupPrivacy,
+    /// Unique slug of the group.
     ///
     /// # Examples
     ///
-    /// `1`
-    pub ratings_count: u64,
-    /// The number of reviews the user has posted.
-    pub reviews_count: u64,
-    /// The user's title.
-    pub title: Option<String>,
-    /// When the user last updated their profile.
-    ///
+    /// `mecha-fans`
+    pub slug: String,
+}
+
+/// A list of links to the media's relevant images.
+///
+/// Some legacy entries provide the image as a lone URL rather than an object
+/// of sizes, in which case it is deserialized into the [`original`] field.
+///
+/// # Examples
+///
+/// ```rust
+/// extern crate kitsu_io;
+/// extern crate serde_json;
+///
+/// use kitsu_io::model::Image;
+///
+/// # fn main() {
+/// let url = "https://media.kitsu.io/anime/poster_images/1/original.jpg";
+/// let image: Image = serde_json::from_str(&format!("\"{}\"", url)).unwrap();
+///
+/// assert_eq!(image.largest().unwrap(), url);
+/// # }
+/// ```
+///
+/// [`original`]: #structfield.original
+#[derive(Clone, Debug, Serialize)]
+pub struct Image {
+    /// Link to a large size of the image.
+    pub large: Option<String>,
+    /// Link to a medium size of the image.
+    pub medium: Option<String>,
+    /// Link to an original size of the image.
+    pub original: Option<String>,
+    /// Link to a small size of the image.
+    pub small: Option<String>,
+    /// Link to a tiny size of the image.
+    pub tiny: Option<String>,
+}
+
+impl Image {
+    /// Retrieves the URL to the largest image in descending order where
+    /// available, if any.
+    ///
+    /// This places priority on the [`original`] field.
+    ///
+    /// [`original`]: #structfield.original
+    pub fn largest(&self) -> Option<&String> {
+        self.original.as_ref()
+            .or(self.large.as_ref())
+            .or(self.medium.as_ref())
+            .or(self.small.as_ref())
+            .or(self.tiny.as_ref())
+    }
+
+    /// Retrieves a usable URL to the image in the given size, if available.
+    ///
+    /// Kitsu sometimes gives image URLs as templates containing `$1` in place
+    /// of the size, which is substituted with the name of the size. If there
+    /// is no link for the size, a template in the [`original`] field is used
+    /// instead.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// extern crate kitsu_io;
+    /// extern crate serde_json;
+    ///
+    /// use kitsu_io::model::{Image, ImageSize};
+    ///
+    /// # fn main() {
+    /// let url = "https://media.kitsu.io/anime/poster_images/1/$1.jpg";
+    /// let image: Image = serde_json::from_str(&format!("\"{}\"", url)).unwrap();
+    ///
+    /// assert_eq!(
+    ///     image.url_for_size(ImageSize::Tiny).unwrap(),
+    ///     "https://media.kitsu.io/anime/poster_images/1/tiny.jpg",
+    /// );
+    /// # }
+    /// ```
+    ///
+    /// [`original`]: #structfield.original
+    pub fn url_for_size(&self, size: ImageSize) -> Option<String> {
+        let url = match size {
+            ImageSize::Large => self.large.as_ref(),
+            ImageSize::Medium => self.medium.as_ref(),
+            ImageSize::Original => self.original.as_ref(),
+            ImageSize::Small => self.small.as_ref(),
+            ImageSize::Tiny => self.tiny.as_ref(),
+        };
+
+        image_url(url, self.original.as_ref(), size)
+    }
+}
+
+impl<'de> Deserialize<'de> for Image {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> StdResult<Self, D::Error> {
+        #[derive(Deserialize)]
+        struct Sizes {
+            large: Option<String>,
+            medium: Option<String>,
+            original: Option<String>,
+            small: Option<String>,
+            tiny: Option<String>,
+        }
+
+        Ok(match ImageOrUrl::deserialize(deserializer)? {
+            ImageOrUrl::Image(Sizes { large, medium, original, small, tiny }) => Image {
+                large,
+                medium,
+                original,
+                small,
+                tiny,
+            },
+            ImageOrUrl::Url(url) => Image {
+                large: None,
+                medium: None,
+                original: Some(url),
+                small: None,
+                tiny: None,
+            },
+        })
+    }
+}
+
+/// An image which is either an object of sizes or a lone URL.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum ImageOrUrl<T> {
+    Image(T),
+    Url(String),
+}
+
+/// Deserializes a list of strings which may instead be given as a lone string,
+/// normalizing it into a list of one string.
+fn string_or_vec<'de, D: Deserializer<'de>>(deserializer: D)
+    -> StdResult<Option<Vec<String>>, D::Error> {
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum StringOrVec {
+        String(String),
+        Vec(Vec<String>),
+    }
+
+    Ok(match Option::<StringOrVec>::deserialize(deserializer)? {
+        Some(StringOrVec::String(value)) => Some(vec![value]),
+        Some(StringOrVec::Vec(values)) => Some(values),
+        None => None,
+    })
+}
+
+/// Deserializes an id which may be given as a number rather than a string, as
+/// by some endpoints, normalizing it into a string.
+fn string_or_number<'de, D: Deserializer<'de>>(deserializer: D)
+    -> StdResult<String, D::Error> {
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum StringOrNumber {
+        String(String),
+        Number(u64),
+    }
+
+    Ok(match StringOrNumber::deserialize(deserializer)? {
+        StringOrNumber::String(value) => value,
+        StringOrNumber::Number(value) => value.to_string(),
+    })
+}
+
+/// An entry of a media item in a [`Franchise`], such as its position in the
+/// franchise's main story.
+///
+/// [`Franchise`]: struct.Franchise.html
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct Installment {
+    /// Information about the installment.
+    pub attributes: InstallmentAttributes,
+    /// The id of the installment.
+    #[serde(deserialize_with="string_or_number")]
+    pub id: String,
+    /// The type of item this is. Should always be [`Type::Installments`].
+    ///
+    /// [`Type::Installments`]: enum.Type.html#variant.Installments
+    #[serde(rename="type")]
+    pub kind: Type,
+    /// Links related to the installment.
+    pub links: HashMap<String, String>,
+}
+
+/// Information about an [`Installment`].
+///
+/// [`Installment`]: struct.Installment.html
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct InstallmentAttributes {
+    /// The position of the media item in the franchise.
+    ///
+    /// # Examples
+    ///
+    /// `1`
+    pub position: u32,
+    /// The part of the franchise which the media item is in.
+    ///
+    /// # Examples
+    ///
+    /// `Side Story`
+    pub tag: Option<String>,
+}
+
+/// Information about an entry in a [`User`]'s library, which tracks their
+/// progress through a media item.
+///
+/// [`User`]: struct.User.html
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct LibraryEntry {
+    /// Information about the library entry.
+    pub attributes: LibraryEntryAttributes,
+    /// The id of the library entry.
+    #[serde(deserialize_with="string_or_number")]
+    pub id: String,
+    /// The type of item this is. Should always be [`Type::LibraryEntries`].
+    ///
+    /// [`Type::LibraryEntries`]: enum.Type.html#variant.LibraryEntries
+    #[serde(rename="type")]
+    pub kind: Type,
+    /// Links related to the library entry.
+    pub links: HashMap<String, String>,
+}
+
+/// Information about a [`LibraryEntry`].
+///
+/// [`LibraryEntry`]: struct.LibraryEntry.html
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all="camelCase")]
+pub struct LibraryEntryAttributes {
+    /// When the user finished the media.
+    ///
+    /// # Examples
+    ///
+    /// `2017-05-22T06:00:10.247Z`
+    pub finished_at: Option<String>,
+    /// The user's notes about the media.
+    pub notes: Option<String>,
+    /// Whether the library entry is hidden from other users.
+    pub private: bool,
+    /// The number of episodes or chapters of the media that the user has
+    /// watched or read.
+    ///
+    /// # Examples
+    ///
+    /// `26`
+    pub progress: u32,
+    /// The rating that the user gave the media.
+    ///
+    /// # Examples
+    ///
+    /// `4.5`
+    pub rating: Option<String>,
+    /// The number of times the user has rewatched or reread the media.
+    ///
+    /// # Examples
+    ///
+    /// `1`
+    pub reconsume_count: u32,
+    /// When the user started the media.
+    ///
+    /// # Examples
+    ///
+    /// `2017-04-03T06:00:10.247Z`
+    pub started_at: Option<String>,
+    /// The status of the media in the user's library.
+    pub status: LibraryEntryStatus,
+}
+
+/// An event in a [`User`]'s library, such as when they progressed through a
+/// media item or changed its status.
+///
+/// [`User`]: struct.User.html
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct LibraryEvent {
+    /// Information about the library event.
+    pub attributes: LibraryEventAttributes,
+    /// The id of the library event.
+    #[serde(deserialize_with="string_or_number")]
+    pub id: String,
+    /// The type of item this is. Should always be [`Type::LibraryEvents`].
+    ///
+    /// [`Type::LibraryEvents`]: enum.Type.html#variant.LibraryEvents
+    #[serde(rename="type")]
+    pub kind: Type,
+    /// Links related to the library event.
+    pub links: HashMap<String, String>,
+}
+
+/// Information about a [`LibraryEvent`].
+///
+/// [`LibraryEvent`]: struct.LibraryEvent.html
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all="camelCase")]
+pub struct LibraryEventAttributes {
+    /// The attributes of the library entry which were changed, keyed by their
+    /// name. Each value is usually a list of the previous and new values.
+    ///
+    /// # Examples
+    ///
+    /// `{"progress": [3, 4]}`
+    #[serde(default)]
+    pub changed_data: HashMap<String, Value>,
+    /// When the event occurred.
+    ///
+    /// # Examples
+    ///
+    /// `2017-05-22T06:00:10.247Z`
+    pub created_at: String,
+    /// The kind of change to the library entry.
+    pub kind: LibraryEventKind,
+}
+
+impl LibraryEventAttributes {
+    /// Parses the [`created_at`] timestamp of the event.
+    ///
+    /// Returns `None` if the timestamp is not valid RFC 3339.
+    ///
+    /// [`created_at`]: #structfield.created_at
+    #[cfg(feature = "chrono")]
+    pub fn created_at_parsed(&self) -> Option<DateTime<Utc>> {
+        parse_datetime(&self.created_at)
+    }
+}
+
+/// Information about a manga.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct Manga {
+    /// Information about the manga.
+    pub attributes: MangaAttributes,
+    /// The id of the manga.
+    #[serde(deserialize_with="string_or_number")]
+    pub id: String,
+    /// The type of item this is. Should always be [`Type::Manga`].
+    ///
+    /// [`Type::Manga`]: enum.Type.html#variant.Manga
+    #[serde(rename="type")]
+    pub kind: Type,
+    /// Links related to the manga.
+    pub links: HashMap<String, String>,
+}
+
+impl Manga {
+    /// The current airing status of the manga.
+    #[inline]
+    pub fn airing_status(&self) -> AiringStatus {
+        self.attributes.airing_status()
+    }
+
+    /// The identity of the manga, made of its type and id.
+    #[inline]
+    pub fn media_id(&self) -> MediaId {
+        MediaId::new(self.kind, self.id.clone())
+    }
+
+    /// Generates a URL to the Kitsu page for the manga.
+    #[inline]
+    pub fn url(&self) -> String {
+        self.attributes.url()
+    }
+
+    /// Generates a formatted URL to the youtube video.
+    #[inline]
+    pub fn youtube_url(&self) -> Option<String> {
+        self.attributes.youtube_url()
+    }
+}
+
+/// Information about a [`Manga`].
+///
+/// [`Manga`]: struct.Manga.html
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all="camelCase")]
+pub struct MangaAttributes {
+    /// Shortened nicknames for the manga.
+    #[serde(default, deserialize_with="string_or_vec")]
+    pub abbreviated_titles: Option<Vec<String>>,
+    /// The average of all user ratings for the manga.
+    ///
+    /// # Examples
+    ///
+    /// `4.34926964198231`
+    pub average_rating: Option<String>,
+    /// Canonical title for the manga.
+    ///
+    /// # Examples
+    ///
+    /// `Horimiya`
+    pub canonical_title: String,
+    /// The number of chapters released.
+    pub chapter_count: Option<u64>,
+    /// The URL template for the cover.
+    ///
+    /// # Examples
+    ///
+    /// `https://static.hummingbird.me/manga/22352/cover/$1.png`
+    pub cover_image: Option<CoverImage>,
+    /// The cover's offset from the top, if the media has a cover.
+    ///
+    /// # Examples
+    ///
+    /// `60`
+    #[serde(default)]
+    pub cover_image_top_offset: Option<u16>,
+    /// When the manga was added to Kitsu.
+    ///
+    /// # Examples
+    ///
+    /// `2013-02-20T16:00:13.609Z`
+    pub created_at: Option<String>,
+    /// Date the manga finished.
+    ///
+    /// # Examples
+    ///
+    /// `2013-09-28`
+    pub end_date: Option<String>,
+    /// Show format of the manga.
+    ///
+    /// # Examples
+    ///
+    /// [`MangaType::Novel`]
+    ///
+    /// [`MangaType::Novel`]: enum.MangaType.html#variant.Novel
+    #[serde(rename="mangaType")]
+    pub kind: MangaType,
+    /// Whether the manga is Not Safe For Work.
+    ///
+    /// Kitsu does not provide this for every manga, in which case it is
+    /// `false`.
+    #[serde(default)]
+    pub nsfw: bool,
+    /// The rank based on the popularity of the manga.
+    ///
+    /// # Examples
+    ///
+    /// `10`
+    pub popularity_rank: Option<u32>,
+    /// The URL template for the poster.
+    ///
+    /// # Examples
+    ///
+    /// `https://static.hummingbird.me/manga/22352/poster/$1.png`
+    pub poster_image: Image,
+    /// How many times each rating has been given to the manga.
+    pub rating_frequencies: RatingFrequencies,
+    /// The rank of the manga based on its overall rating.
+    ///
+    /// # Examples
+    ///
+    /// `13`
+    pub rating_rank: Option<u32>,
+    /// Name of media of serialization.
+    pub serialization: Option<String>,
+    /// Unique slug used for page URLs.
+    ///
+    /// # Examples
+    ///
+    /// `horimiya`
+    pub slug: String,
+    /// Date the manga was serialized.
+    ///
+    /// # Examples
+    ///
+    /// `2013-04-07`
+    pub start_date: Option<String>,
+    /// Synopsis of the manga.
+    ///
+    /// # Examples
+    ///
+    /// `Hori may seem like a normal teenage girl, but she's a completely...`
+    pub synopsis: String,
+    /// The titles of the manga.
+    pub titles: MangaTitles,
+    /// When the manga was last updated.
+    ///
+    /// # Examples
+    ///
+    /// `2017-02-27T14:26:54.672Z`
+    pub updated_at: Option<String>,
+    /// The number of volumes released for the manga.
+    pub volume_count: Option<u64>,
+    /// The id of the related YouTube video.
+    pub youtube_video_id: Option<String>,
+}
+
+impl MangaAttributes {
+    /// The current airing status of the manga.
+    pub fn airing_status(&self) -> AiringStatus {
+        if self.end_date.is_some() {
+            AiringStatus::Finished
+        } else {
+            AiringStatus::Airing
+        }
+    }
+
+    /// Parses the [`average_rating`] of the manga, which is given by Kitsu as a
+    /// string.
+    ///
+    /// Returns `None` if the manga has not been rated, or if the rating could
+    /// not be parsed.
+    ///
+    /// [`average_rating`]: #structfield.average_rating
+    pub fn average_rating_f64(&self) -> Option<f64> {
+        self.average_rating.as_ref().and_then(|rating| rating.trim().parse().ok())
+    }
+
+    /// Parses the [`created_at`] timestamp of the manga.
+    ///
+    /// Returns `None` if the timestamp is not present or is not valid
+    /// RFC 3339.
+    ///
+    /// [`created_at`]: #structfield.created_at
+    #[cfg(feature = "chrono")]
+    pub fn created_at_parsed(&self) -> Option<DateTime<Utc>> {
+        self.created_at.as_deref().and_then(parse_datetime)
+    }
+
+    /// Parses the [`end_date`] of the manga.
+    ///
+    /// Returns `None` if the date is not present or is not a valid date, such
+    /// as when it is empty.
+    ///
+    /// [`end_date`]: #structfield.end_date
+    #[cfg(feature = "chrono")]
+    pub fn end_date_parsed(&self) -> Option<NaiveDate> {
+        self.end_date.as_deref().and_then(parse_date)
+    }
+
+    /// Parses the [`start_date`] of the manga.
+    ///
+    /// Returns `None` if the date is not present or is not a valid date, such
+    /// as when it is empty.
+    ///
+    /// [`start_date`]: #structfield.start_date
+    #[cfg(feature = "chrono")]
+    pub fn start_date_parsed(&self) -> Option<NaiveDate> {
+        self.start_date.as_deref().and_then(parse_date)
+    }
+
+    /// Parses the [`updated_at`] timestamp of the manga.
+    ///
+    /// Returns `None` if the timestamp is not present or is not valid
+    /// RFC 3339.
+    ///
+    /// [`updated_at`]: #structfield.updated_at
+    #[cfg(feature = "chrono")]
+    pub fn updated_at_parsed(&self) -> Option<DateTime<Utc>> {
+        self.updated_at.as_deref().and_then(parse_datetime)
+    }
+
+    /// Generates a URL to the Kitsu page for the manga.
+    #[inline]
+    pub fn url(&self) -> String {
+        format!("https://kitsu.io/manga/{}", self.slug)
+    }
+
+    /// Generates a formatted URL to the youtube video.
+    #[inline]
+    pub fn youtube_url(&self) -> Option<String> {
+        self.youtube_video_id.as_ref().map(youtube_url)
+    }
+}
+
+/// A mapping of a media item to its id on an external site, such as
+/// MyAnimeList or AniList.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct Mapping {
+    /// Information about the mapping.
+    pub attributes: MappingAttributes,
+    /// The id of the mapping.
+    #[serde(deserialize_with="string_or_number")]
+    pub id: String,
+    /// The type of item this is. Should always be [`Type::Mappings`].
+    ///
+    /// [`Type::Mappings`]: enum.Type.html#variant.Mappings
+    #[serde(rename="type")]
+    pub kind: Type,
+    /// Links related to the mapping.
+    pub links: HashMap<String, String>,
+}
+
+/// Information about a [`Mapping`].
+///
+/// [`Mapping`]: struct.Mapping.html
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all="camelCase")]
+pub struct MappingAttributes {
+    /// The id of the media item on the external site.
+    ///
+    /// # Examples
+    ///
+    /// `1`
+    pub external_id: String,
+    /// The external site which the id is for.
+    ///
+    /// # Examples
+    ///
+    /// `myanimelist/anime`
+    pub external_site: ExternalSite,
+}
+
+/// The identity of a resource, made of its type and id, such as for use as the
+/// key of a cache or to deduplicate resources.
+///
+/// This is retrieved using methods such as [`Anime::media_id`].
+///
+/// # Examples
+///
+/// ```rust
+/// use kitsu_io::model::{MediaId, Type};
+/// use std::collections::HashSet;
+///
+/// let mut seen = HashSet::new();
+///
+/// assert!(seen.insert(MediaId::new(Type::Anime, "1")));
+/// assert!(seen.insert(MediaId::new(Type::Manga, "1")));
+/// assert!(!seen.insert(MediaId::new(Type::Anime, "1")));
+/// ```
+///
+/// [`Anime::media_id`]: struct.Anime.html#method.media_id
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct MediaId {
+    /// The type of the resource.
+    pub kind: Type,
+    /// The id of the resource.
+    pub id: String,
+}
+
+impl MediaId {
+    /// Creates the identity of a resource from its type and id.
+    pub fn new<S: Into<String>>(kind: Type, id: S) -> Self {
+        MediaId {
+            kind,
+            id: id.into(),
+        }
+    }
+}
+
+/// A short reaction of a [`User`] to a media item, which is a lighter form
+/// of a [`Review`].
+///
+/// [`Review`]: struct.Review.html
+/// [`User`]: struct.User.html
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct MediaReaction {
+    /// Information about the reaction.
+    pub attributes: MediaReactionAttributes,
+    /// The id of the reaction.
+    #[serde(deserialize_with="string_or_number")]
+    pub id: String,
+    /// The type of item this is. Should always be [`Type::MediaReactions`].
+    ///
+    /// [`Type::MediaReactions`]: enum.Type.html#variant.MediaReactions
+    #[serde(rename="type")]
+    pub kind: Type,
+    /// Links related to the reaction.
+    pub links: HashMap<String, String>,
+}
+
+/// Information about a [`MediaReaction`].
+///
+/// [`MediaReaction`]: struct.MediaReaction.html
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all="camelCase")]
+pub struct MediaReactionAttributes {
+    /// The reaction, as written by the user.
+    ///
+    /// # Examples
+    ///
+    /// `The soundtrack alone is worth it.`
+    pub reaction: String,
+    /// The number of users who upvoted the reaction.
+    ///
+    /// # Examples
+    ///
+    /// `42`
+    pub up_votes_count: u64,
+}
+
+/// A relationship between a media item and another related media item, such
+/// as its sequel or adaptation.
+///
+/// The related media item is the relationship's `destination`.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct MediaRelationship {
+    /// Information about the media relationship.
+    pub attributes: MediaRelationshipAttributes,
+    /// The id of the media relationship.
+    #[serde(deserialize_with="string_or_number")]
+    pub id: String,
+    /// The type of item this is. Should always be
+    /// [`Type::MediaRelationships`].
+    ///
+    /// [`Type::MediaRelationships`]: enum.Type.html#variant.MediaRelationships
+    #[serde(rename="type")]
+    pub kind: Type,
+    /// Links related to the media relationship.
+    pub links: HashMap<String, String>,
+}
+
+/// Information about a [`MediaRelationship`].
+///
+/// [`MediaRelationship`]: struct.MediaRelationship.html
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct MediaRelationshipAttributes {
+    /// How the related media item relates to the media item.
+    ///
+    /// # Examples
+    ///
+    /// `sequel`
+    pub role: MediaRelationshipRole,
+}
+
+/// Information about a person involved in the production of media, such as
+/// a voice actor or a member of staff.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct Person {
+    /// Information about the person.
+    pub attributes: PersonAttributes,
+    /// The id of the person.
+    #[serde(deserialize_with="string_or_number")]
+    pub id: String,
+    /// The type of item this is. Should always be [`Type::People`].
+    ///
+    /// [`Type::People`]: enum.Type.html#variant.People
+    #[serde(rename="type")]
+    pub kind: Type,
+    /// Links related to the person.
+    pub links: HashMap<String, String>,
+}
+
+/// Information about a [`Person`].
+///
+/// [`Person`]: struct.Person.html
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all="camelCase")]
+pub struct PersonAttributes {
+    /// Links to the person's image.
+    pub image: Option<Image>,
+    /// The id of the person on MyAnimeList.
+    ///
+    /// # Examples
+    ///
+    /// `11`
+    pub mal_id: Option<u32>,
+    /// The name of the person.
+    ///
+    /// # Examples
+    ///
+    /// `Kouichi Yamadera`
+    pub name: String,
+}
+
+/// A post made by a [`User`] to their profile or a feed.
+///
+/// [`User`]: struct.User.html
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct Post {
+    /// Information about the post.
+    pub attributes: PostAttributes,
+    /// The id of the post.
+    #[serde(deserialize_with="string_or_number")]
+    pub id: String,
+    /// The type of item this is. Should always be [`Type::Posts`].
+    ///
+    /// [`Type::Posts`]: enum.Type.html#variant.Posts
+    #[serde(rename="type")]
+    pub kind: Type,
+    /// Links related to the post.
+    pub links: HashMap<String, String>,
+}
+
+/// Information about a [`Post`].
+///
+/// [`Post`]: struct.Post.html
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all="camelCase")]
+pub struct PostAttributes {
+    /// The number of comments on the post, including replies.
+    ///
+    /// # Examples
+    ///
+    /// `12`
+    #[serde(default)]
+    pub comments_count: u64,
+    /// The content of the post, as written.
+    ///
+    /// # Examples
+    ///
+    /// `Just finished Cowboy Bebop!`
+    pub content: String,
+    /// The content of the post, formatted as HTML.
+    ///
+    /// # Examples
+    ///
+    /// `<p>Just finished Cowboy Bebop!</p>`
+    pub content_formatted: Option<String>,
+    /// When the post was created.
+    ///
+    /// # Examples
+    ///
+    /// `2017-05-22T06:00:10.247Z`
+    pub created_at: String,
+    /// When the post was last edited, if it has been.
+    ///
+    /// # Examples
+    ///
+    /// `2017-05-22T06:00:10.247Z`
+    #[serde(default)]
+    pub edited_at: Option<String>,
+    /// Whether the post is not safe for work.
+    #[serde(default)]
+    pub nsfw: bool,
+    /// The number of likes of the post.
+    ///
+    /// # Examples
+    ///
+    /// `40`
+    #[serde(default)]
+    pub post_likes_count: u64,
+    /// Whether the post contains spoilers.
+    #[serde(default)]
+    pub spoiler: bool,
+    /// When the post was last updated.
+    ///
+    /// # Examples
+    ///
+    /// `2017-05-22T06:00:10.247Z`
+    pub updated_at: Option<String>,
+}
+
+impl PostAttributes {
+    /// Parses the [`created_at`] timestamp of the post.
+    ///
+    /// Returns `None` if the timestamp is not valid RFC 3339.
+    ///
+    /// [`created_at`]: #structfield.created_at
+    #[cfg(feature = "chrono")]
+    pub fn created_at_parsed(&self) -> Option<DateTime<Utc>> {
+        parse_datetime(&self.created_at)
+    }
+}
+
+/// Information about a producer, such as an animation studio or a licensor.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct Producer {
+    /// Information about the producer.
+    pub attributes: ProducerAttributes,
+    /// The id of the producer.
+    #[serde(deserialize_with="string_or_number")]
+    pub id: String,
+    /// The type of item this is. Should always be [`Type::Producers`].
+    ///
+    /// [`Type::Producers`]: enum.Type.html#variant.Producers
+    #[serde(rename="type")]
+    pub kind: Type,
+    /// Links related to the producer.
+    pub links: HashMap<String, String>,
+}
+
+/// Information about a [`Producer`].
+///
+/// [`Producer`]: struct.Producer.html
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct ProducerAttributes {
+    /// The name of the producer.
+    ///
+    /// # Examples
+    ///
+    /// `Kyoto Animation`
+    pub name: String,
+    /// Unique slug of the producer.
+    ///
+    /// # Examples
+    ///
+    /// `kyoto-animation`
+    pub slug: String,
+}
+
+/// How many times each rating has been given to the media item.
+#[derive(Clone, Copy, Debug, Deserialize, Serialize)]
+pub struct RatingFrequencies {
+    /// Number of 0 stars given.
+    #[serde(default, rename="0.0")]
+    pub rating_0_0: u64,
+    /// Number of 0.5 stars given.
+    #[serde(default, rename="0.5")]
+    pub rating_0_5: u64,
+    /// Number of 1.0 stars given.
+    #[serde(default, rename="1.0")]
+    pub rating_1_0: u64,
+    /// Nubmer of 1.5 stars given.
+    #[serde(default, rename="1.5")]
+    pub rating_1_5: u64,
+    /// Number of 2.0 stars given.
+    #[serde(default, rename="2.0")]
+    pub rating_2_0: u64,
+    /// Number of 2.5 stars given.
+    #[serde(default, rename="2.5")]
+    pub rating_2_5: u64,
+    /// Number of 3.0 stars given.
+    #[serde(default, rename="3.0")]
+    pub rating_3_0: u64,
+    /// Number of 3.5 stars given.
+    #[serde(default, rename="3.5")]
+    pub rating_3_5: u64,
+    /// Number of 4.0 stars given.
+    #[serde(default, rename="4.0")]
+    pub rating_4_0: u64,
+    /// Number of 4.5 stars given.
+    #[serde(default, rename="4.5")]
+    pub rating_4_5: u64,
+    /// Number of 5.0 stars given.
+    #[serde(default, rename="5.0")]
+    pub rating_5_0: u64,
+}
+
+impl RatingFrequencies {
+    /// The total number of ratings given, across all of the ratings.
+    pub fn total_votes(&self) -> u64 {
+        self.counts().iter().map(|&(_, count)| count).sum()
+    }
+
+    /// The mean of the ratings given, on a 0-5 star scale and weighted by the
+    /// number of times each rating was given.
+    ///
+    /// Returns `None` if no ratings have been given.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use kitsu_io::model::RatingFrequencies;
+    ///
+    /// let frequencies = RatingFrequencies {
+    ///     rating_0_0: 0,
+    ///     rating_0_5: 0,
+    ///     rating_1_0: 0,
+    ///     rating_1_5: 0,
+    ///     rating_2_0: 0,
+    ///     rating_2_5: 0,
+    ///     rating_3_0: 1,
+    ///     rating_3_5: 0,
+    ///     rating_4_0: 0,
+    ///     rating_4_5: 0,
+    ///     rating_5_0: 3,
+    /// };
+    ///
+    /// assert_eq!(frequencies.total_votes(), 4);
+    /// assert_eq!(frequencies.weighted_average(), Some(4.5));
+    /// ```
+    pub fn weighted_average(&self) -> Option<f64> {
+        let total = self.total_votes();
+
+        if total == 0 {
+            return None;
+        }
+
+        let sum = self.counts().iter()
+            .map(|&(stars, count)| stars * count as f64)
+            .sum::<f64>();
+
+        Some(sum / total as f64)
+    }
+
+    /// The number of times each rating was given, alongside the rating in
+    /// stars.
+    fn counts(&self) -> [(f64, u64); 11] {
+        [
+            (0.0, self.rating_0_0),
+            (0.5, self.rating_0_5),
+            (1.0, self.rating_1_0),
+            (1.5, self.rating_1_5),
+            (2.0, self.rating_2_0),
+            (2.5, self.rating_2_5),
+            (3.0, self.rating_3_0),
+            (3.5, self.rating_3_5),
+            (4.0, self.rating_4_0),
+            (4.5, self.rating_4_5),
+            (5.0, self.rating_5_0),
+        ]
+    }
+}
+
+/// The titles of the anime.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct AnimeTitles {
+    /// The English title of the anime.
+    ///
+    /// # Examples
+    ///
+    /// `Attack on Titan`
+    pub en: Option<String>,
+    /// The romaji title of the anime.
+    ///
+    /// # Examples
+    ///
+    /// `Shingeki no Kyojin`
+    pub en_jp: Option<String>,
+    /// The Japanese title of the anime.
+    ///
+    /// # Examples
+    ///
+    /// `進撃の巨人`
+    pub ja_jp: Option<String>,
+}
+
+impl AnimeTitles {
+    /// All of the available titles of the anime, in the order of English,
+    /// romaji, and then Japanese.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use kitsu_io::model::AnimeTitles;
+    ///
+    /// let titles = AnimeTitles {
+    ///     en: Some("Attack on Titan".to_owned()),
+    ///     en_jp: Some("Shingeki no Kyojin".to_owned()),
+    ///     ja_jp: None,
+    /// };
+    ///
+    /// assert_eq!(titles.all(), vec!["Attack on Titan", "Shingeki no Kyojin"]);
+    /// ```
+    pub fn all(&self) -> Vec<&str> {
+        [&self.en, &self.en_jp, &self.ja_jp].iter()
+            .filter_map(|title| title.as_ref().map(|title| title.as_str()))
+            .collect()
+    }
+
+    /// The first title which is present from a list of language tags in order
+    /// of preference, such as `en` or `en_jp`.
+    ///
+    /// Tags which are not one of `en`, `en_jp`, or `ja_jp` are skipped.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use kitsu_io::model::AnimeTitles;
+    ///
+    /// let titles = AnimeTitles {
+    ///     en: None,
+    ///     en_jp: Some("Shingeki no Kyojin".to_owned()),
+    ///     ja_jp: Some("進撃の巨人".to_owned()),
+    /// };
+    ///
+    /// assert_eq!(titles.best(&["ja_jp", "en_jp"]), Some("進撃の巨人"));
+    /// assert_eq!(titles.best(&["en", "en_jp"]), Some("Shingeki no Kyojin"));
+    /// assert_eq!(titles.best(&["en"]), None);
+    /// ```
+    pub fn best(&self, prefer: &[&str]) -> Option<&str> {
+        prefer.iter().find_map(|tag| {
+            match *tag {
+                "en" => self.en.as_deref(),
+                "en_jp" => self.en_jp.as_deref(),
+                "ja_jp" => self.ja_jp.as_deref(),
+                _ => None,
+            }
+        })
+    }
+
+    /// The English title, falling back to the romaji and then the Japanese
+    /// title.
+    pub fn preferred_english(&self) -> Option<&str> {
+        self.best(&["en", "en_jp", "ja_jp"])
+    }
+}
+
+/// The titles of the manga.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct MangaTitles {
+    /// The English title of the manga.
+    ///
+    /// # Examples
+    ///
+    /// `Attack on Titan`
+    pub en: Option<String>,
+    /// The romaji title of the manga.
+    ///
+    /// # Examples
+    ///
+    /// `Shingeki no Kyojin`
+    pub en_jp: Option<String>,
+}
+
+impl MangaTitles {
+    /// All of the available titles of the manga, in the order of English and
+    /// then romaji.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use kitsu_io::model::MangaTitles;
+    ///
+    /// let titles = MangaTitles {
+    ///     en: None,
+    ///     en_jp: Some("Horimiya".to_owned()),
+    /// };
+    ///
+    /// assert_eq!(titles.all(), vec!["Horimiya"]);
+    /// ```
+    pub fn all(&self) -> Vec<&str> {
+        [&self.en, &self.en_jp].iter()
+            .filter_map(|title| title.as_ref().map(|title| title.as_str()))
+            .collect()
+    }
+
+    /// The first title which is present from a list of language tags in order
+    /// of preference, such as `en` or `en_jp`.
+    ///
+    /// Tags which are not one of `en` or `en_jp` are skipped.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use kitsu_io::model::MangaTitles;
+    ///
+    /// let titles = MangaTitles {
+    ///     en: None,
+    ///     en_jp: Some("Horimiya".to_owned()),
+    /// };
+    ///
+    /// assert_eq!(titles.best(&["en", "en_jp"]), Some("Horimiya"));
+    /// assert_eq!(titles.best(&["ja_jp"]), None);
+    /// ```
+    pub fn best(&self, prefer: &[&str]) -> Option<&str> {
+        prefer.iter().find_map(|tag| {
+            match *tag {
+                "en" => self.en.as_deref(),
+                "en_jp" => self.en_jp.as_deref(),
+                _ => None,
+            }
+        })
+    }
+
+    /// The English title, falling back to the romaji title.
+    pub fn preferred_english(&self) -> Option<&str> {
+        self.best(&["en", "en_jp"])
+    }
+}
+
+/// A resource sideloaded into the `included` array of a [`Response`].
+///
+/// The variant is determined by the `type` of the resource. Resources of a
+/// type which is not modeled are kept as their raw JSON value in the
+/// [`Other`] variant.
+///
+/// # Examples
+///
+/// ```rust
+/// extern crate kitsu_io;
+/// extern crate serde_json;
+///
+/// use kitsu_io::model::Included;
+///
+/// # fn main() {
+/// let json = r#"{"id": "1", "type": "quotes"}"#;
+/// let included: Included = serde_json::from_str(json).unwrap();
+///
+/// assert!(matches!(included, Included::Other(_)));
+/// # }
+/// ```
+///
+/// [`Other`]: #variant.Other
+/// [`Response`]: struct.Response.html
+#[derive(Clone, Debug)]
+pub enum Included {
+    /// An included [`Anime`].
+    ///
+    /// [`Anime`]: struct.Anime.html
+    Anime(Anime),
+    /// An included [`Category`].
+    ///
+    /// [`Category`]: struct.Category.html
+    Category(Category),
+    /// An included [`Character`].
+    ///
+    /// [`Character`]: struct.Character.html
+    Character(Character),
+    /// An included [`Franchise`].
+    ///
+    /// [`Franchise`]: struct.Franchise.html
+    Franchise(Franchise),
+    /// An included [`Genre`].
+    ///
+    /// [`Genre`]: struct.Genre.html
+    Genre(Genre),
+    /// An included [`Manga`].
+    ///
+    /// [`Manga`]: struct.Manga.html
+    Manga(Manga),
+    /// An included [`Person`].
+    ///
+    /// [`Person`]: struct.Person.html
+    Person(Person),
+    /// An included [`Producer`].
+    ///
+    /// [`Producer`]: struct.Producer.html
+    Producer(Producer),
+    /// An included [`User`].
+    ///
+    /// [`User`]: struct.User.html
+    User(User),
+    /// An included resource of a type which is not modeled.
+    Other(Value),
+}
+
+impl<'de> Deserialize<'de> for Included {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> StdResult<Self, D::Error> {
+        let value = Value::deserialize(deserializer)?;
+        let kind = value.get("type").and_then(Value::as_str).map(str::to_owned);
+
+        let included = match kind.as_deref() {
+            Some("anime") => serde_json::from_value(value).map(Included::Anime),
+            Some("categories") => serde_json::from_value(value).map(Included::Category),
+            Some("characters") => serde_json::from_value(value).map(Included::Character),
+            Some("franchises") => serde_json::from_value(value).map(Included::Franchise),
+            Some("genres") => serde_json::from_value(value).map(Included::Genre),
+            Some("manga") => serde_json::from_value(value).map(Included::Manga),
+            Some("people") => serde_json::from_value(value).map(Included::Person),
+            Some("producers") => serde_json::from_value(value).map(Included::Producer),
+            Some("users") => serde_json::from_value(value).map(Included::User),
+            _ => return Ok(Included::Other(value)),
+        };
+
+        included.map_err(DeError::custom)
+    }
+}
+
+impl Serialize for Included {
+    fn serialize<S: Serializer>(&self, serializer: S) -> StdResult<S::Ok, S::Error> {
+        match *self {
+            Included::Anime(ref anime) => anime.serialize(serializer),
+            Included::Category(ref category) => category.serialize(serializer),
+            Included::Character(ref character) => character.serialize(serializer),
+            Included::Franchise(ref franchise) => franchise.serialize(serializer),
+            Included::Genre(ref genre) => genre.serialize(serializer),
+            Included::Manga(ref manga) => manga.serialize(serializer),
+            Included::Person(ref person) => person.serialize(serializer),
+            Included::Producer(ref producer) => producer.serialize(serializer),
+            Included::User(ref user) => user.serialize(serializer),
+            Included::Other(ref value) => value.serialize(serializer),
+        }
+    }
+}
+
+/// Metadata about a response.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct Meta {
+    /// The total number of results matching a search, regardless of
+    /// pagination.
+    ///
+    /// Kitsu includes the count in the response to every search of a
+    /// collection, so there is no parameter to request it.
+    ///
+    /// # Examples
+    ///
+    /// `13652`
+    pub count: Option<u64>,
+}
+
+/// Data from a response.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct Response<T> {
+    /// The full data from a response.
+    pub data: T,
+    /// Resources related to the data which were sideloaded into the response.
+    #[serde(default)]
+    pub included: Vec<Included>,
+    /// Links relevant to the search.
+    #[serde(default)]
+    pub links: HashMap<String, String>,
+    /// Metadata about the response.
+    #[serde(default)]
+    pub meta: Meta,
+}
+
+impl<T> Response<T> {
+    /// Transforms the data of the response, preserving its included resources,
+    /// links, and metadata.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use kitsu_io::model::Response;
+    ///
+    /// let response = Response {
+    ///     data: vec![1, 2, 3],
+    ///     included: Vec::new(),
+    ///     links: Default::default(),
+    ///     meta: Default::default(),
+    /// };
+    ///
+    /// let doubled = response.map(|data| {
+    ///     data.into_iter().map(|x| x * 2).collect::<Vec<_>>()
+    /// });
+    ///
+    /// assert_eq!(doubled.data, vec![2, 4, 6]);
+    /// ```
+    pub fn map<U, F: FnOnce(T) -> U>(self, f: F) -> Response<U> {
+        Response {
+            data: f(self.data),
+            included: self.included,
+            links: self.links,
+            meta: self.meta,
+        }
+    }
+
+    /// The URL of the first page of results, if the response is paginated.
+    pub fn first_page(&self) -> Option<&str> {
+        self.link("first")
+    }
+
+    /// The URL of the last page of results, if the response is paginated.
+    pub fn last_page(&self) -> Option<&str> {
+        self.link("last")
+    }
+
+    /// The URL of the next page of results, if there is one.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use kitsu_io::model::Response;
+    ///
+    /// let mut response = Response {
+    ///     data: Vec::<u64>::new(),
+    ///     included: Vec::new(),
+    ///     links: Default::default(),
+    ///     meta: Default::default(),
+    /// };
+    ///
+    /// assert_eq!(response.next_page(), None);
+    ///
+    /// let next = "https://kitsu.io/api/edge/anime?page[limit]=10&page[offset]=10";
+    /// response.links.insert("next".to_owned(), next.to_owned());
+    ///
+    /// assert_eq!(response.next_page(), Some(next));
+    /// ```
+    pub fn next_page(&self) -> Option<&str> {
+        self.link("next")
+    }
+
+    /// The URL of the previous page of results, if there is one.
+    pub fn prev_page(&self) -> Option<&str> {
+        self.link("prev")
+    }
+
+    /// The total number of results matching a search, regardless of
+    /// pagination, if the API gave it in the response's [`meta`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use kitsu_io::model::Response;
+    ///
+    /// let json = r#"{"data": [], "meta": {"count": 1234}}"#;
+    /// let response: Response<Vec<u64>> = serde_json::from_str(json).unwrap();
+    ///
+    /// assert_eq!(response.total_count(), Some(1234));
+    ///
+    /// let response: Response<Vec<u64>> = serde_json::from_str(r#"{"data": []}"#).unwrap();
+    ///
+    /// assert_eq!(response.total_count(), None);
+    /// ```
+    ///
+    /// [`meta`]: #structfield.meta
+    pub fn total_count(&self) -> Option<u64> {
+        self.meta.count
+    }
+
+    fn link(&self, key: &str) -> Option<&str> {
+        self.links.get(key).map(String::as_str)
+    }
+}
+
+/// Information about a user's review of an [`Anime`].
+///
+/// [`Anime`]: struct.Anime.html
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct Review {
+    /// Information about the review.
+    pub attributes: ReviewAttributes,
+    /// The id of the review.
+    #[serde(deserialize_with="string_or_number")]
+    pub id: String,
+    /// The type of item this is. Should always be [`Type::Reviews`].
+    ///
+    /// [`Type::Reviews`]: enum.Type.html#variant.Reviews
+    #[serde(rename="type")]
+    pub kind: Type,
+    /// Links related to the review.
+    pub links: HashMap<String, String>,
+}
+
+/// Information about a [`Review`].
+///
+/// [`Review`]: struct.Review.html
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all="camelCase")]
+pub struct ReviewAttributes {
+    /// The content of the review, as written by the user.
+    pub content: String,
+    /// The content of the review, formatted as HTML.
+    pub content_formatted: String,
+    /// The number of users who liked the review.
+    ///
+    /// # Examples
+    ///
+    /// `12`
+    pub likes_count: u32,
+    /// How far the user had progressed through the media when reviewing it.
+    ///
+    /// # Examples
+    ///
+    /// `26`
+    pub progress: Option<String>,
+    /// The rating that the user gave the media.
+    ///
+    /// # Examples
+    ///
+    /// `9`
+    pub rating: u32,
+    /// Where the review was originally written, if it was imported.
+    ///
+    /// # Examples
+    ///
+    /// `hummingbird`
+    pub source: Option<String>,
+    /// Whether the review contains spoilers.
+    pub spoiler: bool,
+}
+
+/// Information about where an [`Anime`] can be legally streamed.
+///
+/// [`Anime`]: struct.Anime.html
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct StreamingLink {
+    /// Information about the streaming link.
+    pub attributes: StreamingLinkAttributes,
+    /// The id of the streaming link.
+    #[serde(deserialize_with="string_or_number")]
+    pub id: String,
+    /// The type of item this is. Should always be [`Type::StreamingLinks`].
+    ///
+    /// [`Type::StreamingLinks`]: enum.Type.html#variant.StreamingLinks
+    #[serde(rename="type")]
+    pub kind: Type,
+    /// Links related to the streaming link.
+    pub links: HashMap<String, String>,
+}
+
+/// Information about a [`StreamingLink`].
+///
+/// [`StreamingLink`]: struct.StreamingLink.html
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct StreamingLinkAttributes {
+    /// The languages that dubs are available in.
+    ///
+    /// # Examples
+    ///
+    /// `["en", "ja"]`
+    #[serde(default)]
+    pub dubs: Vec<String>,
+    /// The languages that subtitles are available in.
+    ///
+    /// # Examples
+    ///
+    /// `["en"]`
+    #[serde(default)]
+    pub subs: Vec<String>,
+    /// Link to the anime on the streaming service.
+    ///
+    /// # Examples
+    ///
+    /// `http://www.hulu.com/cowboy-bebop`
+    pub url: String,
+}
+
+/// Information about a user.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct User {
+    /// Information about the user.
+    pub attributes: UserAttributes,
+    /// The id of the user.
+    #[serde(deserialize_with="string_or_number")]
+    pub id: String,
+    /// The type of item this is. Should always be [`Type::User`].
+    ///
+    /// [`Type::User`]: enum.Type.html#variant.User
+    #[serde(rename="type")]
+    pub kind: Type,
+    /// Links related to the user.
+    pub links: HashMap<String, String>,
+    /// List of the user's relationships.
+    pub relationships: UserRelationships,
+}
+
+/// Information about a [`User`].
+///
+/// [`User`]: struct.User.html
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all="camelCase")]
+pub struct UserAttributes {
+    /// The raw markdown for the user's long-form about text.
+    ///
+    /// # Examples
+    ///
+    /// `I'm curious about https://kitsu.io/anime/nichijou!`
+    pub about: String,
+    /// The processed and sanitized HTML for the user's long form about text.
+    ///
+    /// # Examples
+    ///
+    /// `I'm curious about <a href="https://kitsu.io/anime/nichijou">Nichijou</a>!`
+    pub about_formatted: Option<String>,
+    /// Links to the user's avatar.
+    pub avatar: Option<Image>,
+    /// A short (140 character) biographical blurb about the user.
+    ///
+    /// # Examples
+    ///
+    /// `私、気になります!`
+    pub bio: Option<String>,
+    /// The user's birthday.
+    ///
+    /// # Examples
+    ///
+    /// `1985-07-26`
+    pub birthday: Option<String>,
+    /// Number of comments user has submitted.
+    ///
+    /// # Examples
+    ///
+    /// `15`
+    pub comments_count: u64,
+    /// Links to the user's cover image.
+    pub cover_image: Option<Image>,
+    /// When the user signed up.
+    ///
+    /// # Examples
+    ///
+    /// `1985-07-26T22:13:20.223Z`
+    pub created_at: String,
+    /// The user's Facebook id if they have signed in with Facebook.
+    ///
+    /// # Examples
+    ///
+    /// `1234567890`
+    pub facebook_id: Option<String>,
+    /// The number of media items the user has favorited.
+    pub favorites_count: u64,
+    /// Whether the user's feed is completed.
+    pub feed_completed: bool,
+    /// Number of people following this user.
+    ///
+    /// # Examples
+    ///
+    /// `12`
+    pub followers_count: u64,
+    /// Number of people this user is following.
+    ///
+    /// # Examples
+    ///
+    /// `300`
+    pub following_count: u64,
+    /// The user's gender, if provided.
+    ///
+    /// This is free-form, so use [`gender_typed`] to match on it.
+    ///
+    /// # Examples
+    ///
+    /// `female`
+    ///
+    /// [`gender_typed`]: #method.gender_typed
+    pub gender: Option<String>,
+    /// Number of minutes of anime watched.
+    ///
+    /// # Examples
+    ///
+    /// `550`
+    pub life_spent_on_anime: u64,
+    /// Number of posts user has liked.
+    ///
+    /// # Examples
+    ///
+    /// `12`
+    pub likes_given_count: u64,
+    /// Number of likes the user's post has received.
+    ///
+    /// # Examples
+    ///
+    /// `45`
+    pub likes_received_count: u64,
+    /// A user-provided location.
+    ///
+    /// # Examples
+    ///
+    /// `The Internet`
+    pub location: Option<String>,
+    /// The user's current username.
+    ///
+    /// # Examples
+    ///
+    /// `chitanda`
+    pub name: String,
+    /// An array of previous names the user has gone by, in
+    /// reverse-chronological order.
+    ///
+    /// # Examples
+    ///
+    /// ```rs
+    /// vec![
+    ///     "oldn ame".to_owned(),
+    ///     "older name".to_owned()
+    /// ]
+    /// ```
+    pub past_names: Vec<String>,
+    /// Number of posts user has submitted.
+    ///
+    /// # Examples
+    ///
+    /// `3`
+    pub posts_count: u64,
+    /// Whether the user has finished completing their profile.
+    pub profile_completed: bool,
+    /// When the user's pro subscripten expires.
+    pub pro_expires_at: Option<String>,
+    /// Number of media user has rated.
+    ///
+    /// # Examples
+    ///
+    /// `1`
+    pub ratings_count: u64,
+    /// The number of reviews the user has posted.
+    pub reviews_count: u64,
+    /// The user's title.
+    pub title: Option<String>,
+    /// When the user last updated their profile.
+    ///
     /// **Note**: This _can_ be the same as the [`created_at`] field, which
     /// indicates that the profile has not been "updated" since creation.
     ///
     /// # Examples
     ///
-    /// `1985-07-26T22:13:20.223Z`
-    ///
-    /// [`created_at`]: #structfield.created_at
-    pub updated_at: String,
-    /// Whether the user has a waifu or husbando.
+    /// `1985-07-26T22:13:20.223Z`
+    ///
+    /// [`created_at`]: #structfield.created_at
+    pub updated_at: String,
+    /// Whether the user has a waifu or husbando.
+    ///
+    /// # Examples
+    ///
+    /// `None`
+    pub waifu_or_husbando: Option<WaifuOrHusbando>,
+    /// The user's website.
+    ///
+    /// # Examples
+    ///
+    /// `https://en.wikipedia.org/wiki/Nichijou`
+    pub website: Option<String>,
+}
+
+impl User {
+    /// The identity of the user, made of its type and id.
+    #[inline]
+    pub fn media_id(&self) -> MediaId {
+        MediaId::new(self.kind, self.id.clone())
+    }
+
+    /// Generates a URL to the Kitsu page for the user.
+    #[inline]
+    pub fn url(&self) -> String {
+        self.attributes.url()
+    }
+}
+
+impl UserAttributes {
+    /// The user's [`gender`] as a [`Gender`], if provided.
+    ///
+    /// Kitsu allows users to enter their gender freely, so values other than
+    /// `female` and `male` are [`Gender::Other`], with the original value kept
+    /// in the field. Returns `None` if the gender is empty or is `secret`.
+    ///
+    /// [`Gender`]: enum.Gender.html
+    /// [`Gender::Other`]: enum.Gender.html#variant.Other
+    /// [`gender`]: #structfield.gender
+    pub fn gender_typed(&self) -> Option<Gender> {
+        self.gender.as_deref().and_then(Gender::parse)
+    }
+
+    /// Parses the [`birthday`] of the user.
+    ///
+    /// Returns `None` if the birthday is not present or is not a valid date,
+    /// such as when it is empty.
+    ///
+    /// [`birthday`]: #structfield.birthday
+    #[cfg(feature = "chrono")]
+    pub fn birthday_parsed(&self) -> Option<NaiveDate> {
+        self.birthday.as_deref().and_then(parse_date)
+    }
+
+    /// Parses the [`created_at`] timestamp of the user.
+    ///
+    /// Returns `None` if the timestamp is not valid RFC 3339.
+    ///
+    /// [`created_at`]: #structfield.created_at
+    #[cfg(feature = "chrono")]
+    pub fn created_at_parsed(&self) -> Option<DateTime<Utc>> {
+        parse_datetime(&self.created_at)
+    }
+
+    /// Parses the [`updated_at`] timestamp of the user.
+    ///
+    /// Returns `None` if the timestamp is not valid RFC 3339.
+    ///
+    /// As with the field, this is the same as the parsed [`created_at`]
+    /// timestamp if the profile has not been updated since creation.
+    ///
+    /// [`created_at`]: #structfield.created_at
+    /// [`updated_at`]: #structfield.updated_at
+    #[cfg(feature = "chrono")]
+    pub fn updated_at_parsed(&self) -> Option<DateTime<Utc>> {
+        parse_datetime(&self.updated_at)
+    }
+
+    /// Generates a URL to the Kitsu page for the user.
+    #[inline]
+    pub fn url(&self) -> String {
+        format!("https://kitsu.io/users/{}", self.name)
+    }
+}
+
+/// Relationships for a [`User`].
+///
+/// [`User`]: struct.User.html
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all="camelCase")]
+pub struct UserRelationships {
+    /// Links to users the user blocks.
+    pub blocks: Option<Relationship>,
+    /// Links to the user's favorite media entries.
+    pub favorites: Relationship,
+    /// Links to users following the user.
+    pub followers: Relationship,
+    /// Links to users the user follows.
+    pub following: Relationship,
+    /// Links to the user's library entries.
+    pub library_entries: Relationship,
+    /// Links to profiles linked to the user.
+    pub linked_profiles: Option<Relationship>,
+    /// Links to the user's media.
+    pub media_follows: Option<Relationship>,
+    /// Links to the user's pinned post on their profile.
+    pub pinned_post: Option<Relationship>,
+    /// Links to the user's reviews.
+    pub reviews: Relationship,
+    /// Links to the user's roles.
+    pub user_roles: Option<Relationship>,
+    /// Links to the user's waifu or husbando.
+    pub waifu: Option<Relationship>,
+}
+
+/// The age rating of the [`Anime`].
+///
+/// [`Anime`]: struct.Anime.html
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Ord, PartialEq, PartialOrd, Serialize)]
+pub enum AgeRating {
+    /// Indicator that the anime is rated G.
+    G,
+    /// Indicator that the anime is rated PG.
+    PG,
+    /// Indicator that the anime is rated PG-13.
+    #[serde(rename="PG-13")]
+    PG13,
+    /// Indicator that the anime is rated R.
+    R,
+    /// Indicator that the anime is rated R-17.
+    R17,
+    /// Indicator that the anime is rated R-17+.
+    #[serde(rename="R17+")]
+    R17Plus,
+    /// Indicator that the anime is rated R18.
+    R18,
+    /// Indicator that the anime is rated R-18+.
+    #[serde(rename="R18+")]
+    R18Plus,
+    /// Indicator that the anime is rated TV-Y7.
+    #[serde(rename="TV-Y7")]
+    TvY7,
+    /// Indicator that the age rating is one which is not yet known to this
+    /// library.
+    ///
+    /// This is deserialized from any unrecognized value, so that a new age
+    /// rating added by Kitsu does not cause the whole response to fail to
+    /// deserialize. The original value is not kept, and the variant is
+    /// displayed, parsed and serialized as `unknown`.
+    #[serde(other, rename = "unknown")]
+    Unknown,
+}
+
+impl AgeRating {
+    /// The name of the age rating.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use kitsu_io::model::AgeRating;
+    ///
+    /// assert_eq!(AgeRating::PG.name().unwrap(), "PG");
+    /// ```
+    pub fn name(&self) -> Result<String> {
+        Ok(self.to_string())
+    }
+}
+
+impl Display for AgeRating {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        f.write_str(match *self {
+            AgeRating::G => "G",
+            AgeRating::PG => "PG",
+            AgeRating::PG13 => "PG-13",
+            AgeRating::R => "R",
+            AgeRating::R17 => "R17",
+            AgeRating::R17Plus => "R17+",
+            AgeRating::R18 => "R18",
+            AgeRating::R18Plus => "R18+",
+            AgeRating::TvY7 => "TV-Y7",
+            AgeRating::Unknown => "unknown",
+        })
+    }
+}
+
+impl FromStr for AgeRating {
+    type Err = ParseEnumError;
+
+    fn from_str(s: &str) -> StdResult<Self, Self::Err> {
+        Ok(match s {
+            "G" => AgeRating::G,
+            "PG" => AgeRating::PG,
+            "PG-13" => AgeRating::PG13,
+            "R" => AgeRating::R,
+            "R17" => AgeRating::R17,
+            "R17+" => AgeRating::R17Plus,
+            "R18" => AgeRating::R18,
+            "R18+" => AgeRating::R18Plus,
+            "TV-Y7" => AgeRating::TvY7,
+            "unknown" => AgeRating::Unknown,
+            _ => return Err(ParseEnumError::new("age rating", s)),
+        })
+    }
+}
+
+/// The airing status of an [`Anime`].
+///
+/// [`Anime`]: struct.Anime.html
+pub enum AiringStatus {
+    /// Indicator that the anime is currently airing.
+    Airing,
+    /// Indicator that the anime has finished airing.
+    Finished,
+    /// Indicator that the anime has not started airing.
+    Upcoming,
+}
+
+impl AiringStatus {
+    /// The name of the airing status.
+    pub fn name(&self) -> &str {
+        match *self {
+            AiringStatus::Airing => "airing",
+            AiringStatus::Finished => "finished",
+            AiringStatus::Upcoming => "upcoming",
+        }
+    }
+}
+
+/// The release status of an [`Anime`], as given by the API in its [`status`].
+///
+/// [`Anime`]: struct.Anime.html
+/// [`status`]: struct.AnimeAttributes.html#structfield.status
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+#[serde(rename_all="lowercase")]
+pub enum AiringStatusKind {
+    /// Indicator that the anime is currently airing.
+    Current,
+    /// Indicator that the anime has finished airing.
+    Finished,
+    /// Indicator that the release date of the anime is yet to be announced.
+    Tba,
+    /// Indicator that the anime has not been released, and has no expected
+    /// release date.
+    Unreleased,
+    /// Indicator that the anime has a release date in the near future.
+    Upcoming,
+    /// Indicator that the status is not yet known to this library.
+    #[serde(other)]
+    Unknown,
+}
+
+impl AiringStatusKind {
+    /// The name of the status, as used by the API.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use kitsu_io::model::AiringStatusKind;
+    ///
+    /// assert_eq!(AiringStatusKind::Tba.name(), "tba");
+    /// ```
+    pub fn name(&self) -> &str {
+        match *self {
+            AiringStatusKind::Current => "current",
+            AiringStatusKind::Finished => "finished",
+            AiringStatusKind::Tba => "tba",
+            AiringStatusKind::Unreleased => "unreleased",
+            AiringStatusKind::Upcoming => "upcoming",
+            AiringStatusKind::Unknown => "unknown",
+        }
+    }
+}
+
+/// The type of [`Anime`].
+///
+/// [`Anime`]: struct.Anime.html
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Ord, PartialEq, PartialOrd, Serialize)]
+pub enum AnimeType {
+    /// Indicator that the anime is a movie.
+    #[serde(rename = "movie")]
+    Movie,
+    /// Indicator that the anime is music.
+    #[serde(rename = "music")]
+    Music,
+    /// Indicator that the anime is an Original Net Animation.
+    ONA,
+    /// Indicator that the anime is an Original Video Animation.
+    OVA,
+    /// Indicator that the anime is a special.
+    #[serde(rename = "special")]
+    Special,
+    /// Indicator that the anime is a TV show.
+    TV,
+    /// Indicator that the anime type is one which is not yet known to this
+    /// library.
+    ///
+    /// This is deserialized from any unrecognized value, so that a new type
+    /// added by Kitsu does not cause the whole response to fail to
+    /// deserialize. The original value is not kept, and the variant is
+    /// displayed, parsed and serialized as `unknown`.
+    #[serde(other, rename = "unknown")]
+    Unknown,
+}
+
+impl AnimeType {
+    /// The name of the [anime][`Anime`] type.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use kitsu_io::model::AnimeType;
+    ///
+    /// assert_eq!(AnimeType::Movie.name().unwrap(), "movie");
+    /// assert_eq!(AnimeType::TV.name().unwrap(), "TV");
+    /// ```
+    ///
+    /// [`Anime`]: struct.Anime.html
+    pub fn name(&self) -> Result<String> {
+        Ok(self.to_string())
+    }
+}
+
+impl Display for AnimeType {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        f.write_str(match *self {
+            AnimeType::Movie => "movie",
+            AnimeType::Music => "music",
+            AnimeType::ONA => "ONA",
+            AnimeType::OVA => "OVA",
+            AnimeType::Special => "special",
+            AnimeType::TV => "TV",
+            AnimeType::Unknown => "unknown",
+        })
+    }
+}
+
+impl FromStr for AnimeType {
+    type Err = ParseEnumError;
+
+    fn from_str(s: &str) -> StdResult<Self, Self::Err> {
+        Ok(match s {
+            "movie" => AnimeType::Movie,
+            "music" => AnimeType::Music,
+            "ONA" => AnimeType::ONA,
+            "OVA" => AnimeType::OVA,
+            "special" => AnimeType::Special,
+            "TV" => AnimeType::TV,
+            "unknown" => AnimeType::Unknown,
+            _ => return Err(ParseEnumError::new("anime type", s)),
+        })
+    }
+}
+
+/// The role of a [`Producer`] in an [`AnimeProduction`].
+///
+/// [`AnimeProduction`]: struct.AnimeProduction.html
+/// [`Producer`]: struct.Producer.html
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+#[serde(rename_all="lowercase")]
+pub enum AnimeProductionRole {
+    /// Indicator that the producer licensed the anime.
+    Licensor,
+    /// Indicator that the producer produced the anime.
+    Producer,
+    /// Indicator that the producer is the studio which animated the anime.
+    Studio,
+    /// Indicator that the role is not yet known to this library.
+    #[serde(other)]
+    Unknown,
+}
+
+impl AnimeProductionRole {
+    /// The name of the role, as used by the API.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use kitsu_io::model::AnimeProductionRole;
+    ///
+    /// assert_eq!(AnimeProductionRole::Studio.name(), "studio");
+    /// ```
+    pub fn name(&self) -> &str {
+        match *self {
+            AnimeProductionRole::Licensor => "licensor",
+            AnimeProductionRole::Producer => "producer",
+            AnimeProductionRole::Studio => "studio",
+            AnimeProductionRole::Unknown => "unknown",
+        }
+    }
+}
+
+/// The sub type of an [`Anime`], as given by its [`sub_type`].
+///
+/// [`Anime`]: struct.Anime.html
+/// [`sub_type`]: struct.AnimeAttributes.html#structfield.sub_type
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum AnimeSubType {
+    /// Indicator that the anime is a movie.
+    Movie,
+    /// Indicator that the anime is music.
+    Music,
+    /// Indicator that the anime is an Original Net Animation.
+    ONA,
+    /// Indicator that the anime is an Original Video Animation.
+    OVA,
+    /// Indicator that the anime is a special.
+    Special,
+    /// Indicator that the anime is a TV show.
+    TV,
+    /// Indicator that the sub type is one which is not yet known to this
+    /// library, with the original value kept.
+    Unknown(String),
+}
+
+impl AnimeSubType {
+    /// Parses a sub type, as given by the API.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use kitsu_io::model::AnimeSubType;
+    ///
+    /// assert_eq!(AnimeSubType::parse("TV"), AnimeSubType::TV);
+    /// assert_eq!(AnimeSubType::parse("movie"), AnimeSubType::Movie);
+    /// assert_eq!(AnimeSubType::parse("CM"), AnimeSubType::Unknown("CM".to_owned()));
+    /// ```
+    pub fn parse(sub_type: &str) -> AnimeSubType {
+        match sub_type {
+            "movie" => AnimeSubType::Movie,
+            "music" => AnimeSubType::Music,
+            "ONA" => AnimeSubType::ONA,
+            "OVA" => AnimeSubType::OVA,
+            "special" => AnimeSubType::Special,
+            "TV" => AnimeSubType::TV,
+            other => AnimeSubType::Unknown(other.to_owned()),
+        }
+    }
+
+    /// The name of the sub type, as used by the API.
+    pub fn name(&self) -> &str {
+        match *self {
+            AnimeSubType::Movie => "movie",
+            AnimeSubType::Music => "music",
+            AnimeSubType::ONA => "ONA",
+            AnimeSubType::OVA => "OVA",
+            AnimeSubType::Special => "special",
+            AnimeSubType::TV => "TV",
+            AnimeSubType::Unknown(ref name) => name,
+        }
+    }
+}
+
+impl Display for AnimeSubType {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        f.write_str(self.name())
+    }
+}
+
+/// An external site which a [`Mapping`] is for.
+///
+/// [`Mapping`]: struct.Mapping.html
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+pub enum ExternalSite {
+    /// Indicator that the mapping is to AniDB.
+    #[serde(rename="anidb")]
+    AniDb,
+    /// Indicator that the mapping is to AniList, for an anime.
+    #[serde(rename="anilist/anime")]
+    AniListAnime,
+    /// Indicator that the mapping is to AniList, for a manga.
+    #[serde(rename="anilist/manga")]
+    AniListManga,
+    /// Indicator that the mapping is to Anime News Network.
+    #[serde(rename="animenewsnetwork")]
+    AnimeNewsNetwork,
+    /// Indicator that the mapping is to MangaUpdates.
+    #[serde(rename="mangaupdates")]
+    MangaUpdates,
+    /// Indicator that the mapping is to MyAnimeList, for an anime.
+    #[serde(rename="myanimelist/anime")]
+    MyAnimeListAnime,
+    /// Indicator that the mapping is to MyAnimeList, for a manga.
+    #[serde(rename="myanimelist/manga")]
+    MyAnimeListManga,
+    /// Indicator that the mapping is to TheTVDB.
+    #[serde(rename="thetvdb")]
+    TheTvdb,
+    /// Indicator that the mapping is to TheTVDB, for a season.
+    #[serde(rename="thetvdb/season")]
+    TheTvdbSeason,
+    /// Indicator that the mapping is to TheTVDB, for a series.
+    #[serde(rename="thetvdb/series")]
+    TheTvdbSeries,
+    /// Indicator that the mapping is to Trakt.
+    #[serde(rename="trakt")]
+    Trakt,
+    /// Indicator that the mapping is to a site which is not yet known to this
+    /// library.
+    #[serde(other)]
+    Unknown,
+}
+
+impl ExternalSite {
+    /// The name of the external site, as used by the API.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use kitsu_io::model::ExternalSite;
     ///
-    /// # Examples
+    /// assert_eq!(ExternalSite::MyAnimeListAnime.name(), "myanimelist/anime");
+    /// ```
+    pub fn name(&self) -> &str {
+        match *self {
+            ExternalSite::AniDb => "anidb",
+            ExternalSite::AniListAnime => "anilist/anime",
+            ExternalSite::AniListManga => "anilist/manga",
+            ExternalSite::AnimeNewsNetwork => "animenewsnetwork",
+            ExternalSite::MangaUpdates => "mangaupdates",
+            ExternalSite::MyAnimeListAnime => "myanimelist/anime",
+            ExternalSite::MyAnimeListManga => "myanimelist/manga",
+            ExternalSite::TheTvdb => "thetvdb",
+            ExternalSite::TheTvdbSeason => "thetvdb/season",
+            ExternalSite::TheTvdbSeries => "thetvdb/series",
+            ExternalSite::Trakt => "trakt",
+            ExternalSite::Unknown => "unknown",
+        }
+    }
+}
+
+/// The gender of a [`User`].
+///
+/// [`User`]: struct.User.html
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+#[serde(rename_all="lowercase")]
+pub enum Gender {
+    /// Indicator that the user is female.
+    Female,
+    /// Indicator that the user is male.
+    Male,
+    /// Indicator that the user has given a gender other than female or male.
+    #[serde(other)]
+    Other,
+}
+
+impl Gender {
+    /// Parses a free-form gender, as given by a user.
     ///
-    /// `None`
-    pub waifu_or_husbando: Option<WaifuOrHusbando>,
-    /// The user's website.
+    /// Returns `None` if the gender is empty or is `secret`.
     ///
     /// # Examples
     ///
-    /// `https://en.wikipedia.org/wiki/Nichijou`
-    pub website: Option<String>,
-}
+    /// ```rust
+    /// use kitsu_io::model::Gender;
+    ///
+    /// assert_eq!(Gender::parse("Female"), Some(Gender::Female));
+    /// assert_eq!(Gender::parse("non-binary"), Some(Gender::Other));
+    /// assert_eq!(Gender::parse("secret"), None);
+    /// assert_eq!(Gender::parse(""), None);
+    /// ```
+    pub fn parse(gender: &str) -> Option<Gender> {
+        let gender = gender.trim();
 
-impl User {
-    /// Generates a URL to the Kitsu page for the user.
-    #[inline]
-    pub fn url(&self) -> String {
-        self.attributes.url()
+        if gender.is_empty() || gender.eq_ignore_ascii_case("secret") {
+            None
+        } else if gender.eq_ignore_ascii_case("female") {
+            Some(Gender::Female)
+        } else if gender.eq_ignore_ascii_case("male") {
+            Some(Gender::Male)
+        } else {
+            Some(Gender::Other)
+        }
     }
 }
 
-impl UserAttributes {
-    /// Generates a URL to the Kitsu page for the user.
-    #[inline]
-    pub fn url(&self) -> String {
-        format!("https://kitsu.io/users/{}", self.name)
-    }
+/// Who is able to join a [`Group`].
+///
+/// [`Group`]: struct.Group.html
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+#[serde(rename_all="lowercase")]
+pub enum GroupPrivacy {
+    /// Indicator that only users who are invited are able to join the group.
+    Closed,
+    /// Indicator that any user is able to join the group.
+    Open,
+    /// Indicator that users must be approved to join the group.
+    Restricted,
+    /// Indicator that the privacy is one which is not yet known to this
+    /// library.
+    #[serde(other)]
+    Unknown,
 }
 
-/// Relationships for a [`User`].
-///
-/// [`User`]: struct.User.html
-#[derive(Clone, Debug, Deserialize)]
-#[serde(rename_all="camelCase")]
-pub struct UserRelationships {
-    /// Links to users the user blocks.
-    pub blocks: Relationship,
-    /// Links to the user's favorite media entries.
-    pub favorites: Relationship,
-    /// Links to users following the user.
-    pub followers: Relationship,
-    /// Links to users the user follows.
-    pub following: Relationship,
-    /// Links to the user's library entries.
-    pub library_entries: Relationship,
-    /// Links to profiles linked to the user.
-    pub linked_profiles: Option<Relationship>,
-    /// Links to the user's media.
-    pub media_follows: Option<Relationship>,
-    /// Links to the user's pinned post on their profile.
-    pub pinned_post: Relationship,
-    /// Links to the user's reviews.
-    pub reviews: Relationship,
-    /// Links to the user's roles.
-    pub user_roles: Relationship,
-    /// Links to the user's waifu or husbando.
-    pub waifu: Relationship,
+impl GroupPrivacy {
+    /// The name of the group privacy, as used by the API.
+    pub fn name(&self) -> &str {
+        match *self {
+            GroupPrivacy::Closed => "closed",
+            GroupPrivacy::Open => "open",
+            GroupPrivacy::Restricted => "restricted",
+            GroupPrivacy::Unknown => "unknown",
+        }
+    }
 }
 
-/// The age rating of the [`Anime`].
+/// The size of an [`Image`] or [`CoverImage`], used to retrieve a URL to the
+/// image in that size.
 ///
-/// [`Anime`]: struct.Anime.html
-#[derive(Clone, Copy, Debug, Deserialize, Eq, Ord, PartialEq, PartialOrd, Serialize)]
-pub enum AgeRating {
-    /// Indicator that the anime is rated G.
-    G,
-    /// Indicator that the anime is rated PG.
-    PG,
-    /// Indicator that the anime is rated PG-13.
-    #[serde(rename="PG-13")]
-    PG13,
-    /// Indicator that the anime is rated R.
-    R,
-    /// Indicator that the anime is rated R-17.
-    R17,
-    /// Indicator that the anime is rated R-17+.
-    #[serde(rename="R17+")]
-    R17Plus,
-    /// Indicator that the anime is rated R18.
-    R18,
-    /// Indicator that the anime is rated R-18+.
-    #[serde(rename="R18+")]
-    R18Plus,
-    /// Indicator that the anime is rated TV-Y7.
-    #[serde(rename="TV-Y7")]
-    TvY7,
+/// [`CoverImage`]: struct.CoverImage.html
+/// [`Image`]: struct.Image.html
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum ImageSize {
+    /// Indicator of the large size.
+    Large,
+    /// Indicator of the medium size.
+    Medium,
+    /// Indicator of the original size.
+    Original,
+    /// Indicator of the small size.
+    Small,
+    /// Indicator of the tiny size.
+    Tiny,
 }
 
-impl AgeRating {
-    /// The name of the age rating.
+impl ImageSize {
+    /// The name of the image size, as used in image URLs.
     ///
     /// # Examples
     ///
     /// ```rust
-    /// use kitsu_io::model::AgeRating;
+    /// use kitsu_io::model::ImageSize;
     ///
-    /// assert_eq!(AgeRating::PG.name().unwrap(), "PG");
+    /// assert_eq!(ImageSize::Tiny.name(), "tiny");
     /// ```
-    pub fn name(&self) -> Result<String> {
-        let mut name = serde_json::to_string(self)?;
-
-        // Serde wraps the encoded string in quotation marks, so remove those.
-        let _ = name.remove(0);
-        let _ = name.pop();
-
-        Ok(name)
+    pub fn name(&self) -> &str {
+        match *self {
+            ImageSize::Large => "large",
+            ImageSize::Medium => "medium",
+            ImageSize::Original => "original",
+            ImageSize::Small => "small",
+            ImageSize::Tiny => "tiny",
+        }
     }
 }
 
-/// The airing status of an [`Anime`].
+/// The status of a media item in a [`User`]'s library.
 ///
-/// [`Anime`]: struct.Anime.html
-pub enum AiringStatus {
-    /// Indicator that the anime is currently airing.
-    Airing,
-    /// Indicator that the anime has finished airing.
-    Finished,
+/// [`User`]: struct.User.html
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+#[serde(rename_all="snake_case")]
+pub enum LibraryEntryStatus {
+    /// Indicator that the user has completed the media.
+    Completed,
+    /// Indicator that the user is currently watching or reading the media.
+    Current,
+    /// Indicator that the user has dropped the media.
+    Dropped,
+    /// Indicator that the user has put the media on hold.
+    OnHold,
+    /// Indicator that the user plans to watch or read the media.
+    Planned,
 }
 
-impl AiringStatus {
-    /// The name of the airing status.
+impl LibraryEntryStatus {
+    /// The name of the library entry status, as used by the API.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use kitsu_io::model::LibraryEntryStatus;
+    ///
+    /// assert_eq!(LibraryEntryStatus::OnHold.name(), "on_hold");
+    /// ```
     pub fn name(&self) -> &str {
         match *self {
-            AiringStatus::Airing => "airing",
-            AiringStatus::Finished => "finished",
+            LibraryEntryStatus::Completed => "completed",
+            LibraryEntryStatus::Current => "current",
+            LibraryEntryStatus::Dropped => "dropped",
+            LibraryEntryStatus::OnHold => "on_hold",
+            LibraryEntryStatus::Planned => "planned",
         }
     }
 }
 
-/// The type of [`Anime`].
+/// The kind of change recorded by a [`LibraryEvent`].
 ///
-/// [`Anime`]: struct.Anime.html
-#[derive(Clone, Copy, Debug, Deserialize, Eq, Ord, PartialEq, PartialOrd, Serialize)]
-pub enum AnimeType {
-    /// Indicator that the anime is a movie.
-    #[serde(rename = "movie")]
-    Movie,
-    /// Indicator that the anime is music.
-    #[serde(rename = "music")]
-    Music,
-    /// Indicator that the anime is an Original Net Animation.
-    ONA,
-    /// Indicator that the anime is an Original Video Animation.
-    OVA,
-    /// Indicator that the anime is a special.
-    #[serde(rename = "special")]
-    Special,
-    /// Indicator that the anime is a TV show.
-    TV,
+/// [`LibraryEvent`]: struct.LibraryEvent.html
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+#[serde(rename_all="lowercase")]
+pub enum LibraryEventKind {
+    /// Indicator that the user changed the notes of the entry.
+    Annotated,
+    /// Indicator that the user progressed through the media.
+    Progressed,
+    /// Indicator that the user rated the media.
+    Rated,
+    /// Indicator that the user reacted to the media.
+    Reacted,
+    /// Indicator that the user otherwise updated the entry, such as by
+    /// changing its status.
+    Updated,
+    /// Indicator that the kind is one which is not yet known to this library.
+    #[serde(other)]
+    Unknown,
 }
 
-impl AnimeType {
-    /// The name of the [anime][`Anime`] type.
+impl LibraryEventKind {
+    /// The name of the library event kind, as used by the API.
     ///
     /// # Examples
     ///
     /// ```rust
-    /// use kitsu_io::model::AnimeType;
+    /// use kitsu_io::model::LibraryEventKind;
     ///
-    /// assert_eq!(AnimeType::Movie.name().unwrap(), "movie");
-    /// assert_eq!(AnimeType::TV.name().unwrap(), "TV");
+    /// assert_eq!(LibraryEventKind::Progressed.name(), "progressed");
     /// ```
+    pub fn name(&self) -> &str {
+        match *self {
+            LibraryEventKind::Annotated => "annotated",
+            LibraryEventKind::Progressed => "progressed",
+            LibraryEventKind::Rated => "rated",
+            LibraryEventKind::Reacted => "reacted",
+            LibraryEventKind::Updated => "updated",
+            LibraryEventKind::Unknown => "unknown",
+        }
+    }
+}
+
+/// The kind of a link of a [`Relationship`].
+///
+/// [`Relationship`]: struct.Relationship.html
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum LinkKind {
+    /// Indicator that the link is to the related resources themselves, such
+    /// as an anime's genres.
+    Related,
+    /// Indicator that the link is to the relationship itself, which only
+    /// contains the types and ids of the related resources.
+    Own,
+}
+
+impl LinkKind {
+    /// Classifies a relationship link by its URL.
     ///
-    /// [`Anime`]: struct.Anime.html
-    pub fn name(&self) -> Result<String> {
-        let mut name = serde_json::to_string(self)?;
+    /// Kitsu nests the links of relationships themselves under a
+    /// `relationships` path segment, while links to the related resources are
+    /// not.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use kitsu_io::model::LinkKind;
+    ///
+    /// assert_eq!(
+    ///     LinkKind::from_url("https://kitsu.io/api/edge/anime/1/genres"),
+    ///     LinkKind::Related,
+    /// );
+    /// assert_eq!(
+    ///     LinkKind::from_url("https://kitsu.io/api/edge/anime/1/relationships/genres"),
+    ///     LinkKind::Own,
+    /// );
+    /// ```
+    pub fn from_url(url: &str) -> Self {
+        let path = url.split(['?', '#']).next().unwrap_or(url);
 
-        let _ = name.remove(0);
-        let _ = name.pop();
+        if path.split('/').any(|segment| segment == "relationships") {
+            LinkKind::Own
+        } else {
+            LinkKind::Related
+        }
+    }
 
-        Ok(name)
+    /// The name of the link kind, as used as a key in the API's `links`
+    /// objects.
+    pub fn name(&self) -> &str {
+        match *self {
+            LinkKind::Own => "self",
+            LinkKind::Related => "related",
+        }
     }
 }
 
@@ -900,6 +3539,15 @@ pub enum MangaType {
     Novel,
     /// Indicator that the manga is a oneshot.
     Oneshot,
+    /// Indicator that the manga type is one which is not yet known to this
+    /// library.
+    ///
+    /// This is deserialized from any unrecognized value, so that a new type
+    /// added by Kitsu does not cause the whole response to fail to
+    /// deserialize. The original value is not kept, and the variant is
+    /// displayed, parsed and serialized as `unknown`.
+    #[serde(other, rename = "unknown")]
+    Unknown,
 }
 
 impl MangaType {
@@ -913,33 +3561,225 @@ impl MangaType {
     /// assert_eq!(MangaType::Novel.name().unwrap(), "novel");
     /// ```
     pub fn name(&self) -> Result<String> {
-        let mut name = serde_json::to_string(self)?;
+        Ok(self.to_string())
+    }
+}
+
+impl Display for MangaType {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        f.write_str(match *self {
+            MangaType::Doujin => "doujin",
+            MangaType::Manga => "manga",
+            MangaType::Manhua => "manhua",
+            MangaType::Novel => "novel",
+            MangaType::Oneshot => "oneshot",
+            MangaType::Unknown => "unknown",
+        })
+    }
+}
+
+impl FromStr for MangaType {
+    type Err = ParseEnumError;
+
+    fn from_str(s: &str) -> StdResult<Self, Self::Err> {
+        Ok(match s {
+            "doujin" => MangaType::Doujin,
+            "manga" => MangaType::Manga,
+            "manhua" => MangaType::Manhua,
+            "novel" => MangaType::Novel,
+            "oneshot" => MangaType::Oneshot,
+            "unknown" => MangaType::Unknown,
+            _ => return Err(ParseEnumError::new("manga type", s)),
+        })
+    }
+}
 
-        let _ = name.remove(0);
-        let _ = name.pop();
+/// How a related media item relates to a media item, in a
+/// [`MediaRelationship`].
+///
+/// [`MediaRelationship`]: struct.MediaRelationship.html
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+#[serde(rename_all="snake_case")]
+pub enum MediaRelationshipRole {
+    /// Indicator that the related media item is an adaptation.
+    Adaptation,
+    /// Indicator that the related media item is set in an alternative setting.
+    AlternativeSetting,
+    /// Indicator that the related media item is an alternative version.
+    AlternativeVersion,
+    /// Indicator that the related media item shares characters.
+    Character,
+    /// Indicator that the related media item is the full story.
+    FullStory,
+    /// Indicator that the related media item is related in some other way.
+    Other,
+    /// Indicator that the related media item is the parent story.
+    ParentStory,
+    /// Indicator that the related media item is a prequel.
+    Prequel,
+    /// Indicator that the related media item is a sequel.
+    Sequel,
+    /// Indicator that the related media item is a side story.
+    SideStory,
+    /// Indicator that the related media item is a spinoff.
+    Spinoff,
+    /// Indicator that the related media item is a summary.
+    Summary,
+    /// Indicator that the role is not yet known to this library.
+    #[serde(other)]
+    Unknown,
+}
 
-        Ok(name)
+impl MediaRelationshipRole {
+    /// The name of the role, as used by the API.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use kitsu_io::model::MediaRelationshipRole;
+    ///
+    /// assert_eq!(MediaRelationshipRole::SideStory.name(), "side_story");
+    /// ```
+    pub fn name(&self) -> &str {
+        match *self {
+            MediaRelationshipRole::Adaptation => "adaptation",
+            MediaRelationshipRole::AlternativeSetting => "alternative_setting",
+            MediaRelationshipRole::AlternativeVersion => "alternative_version",
+            MediaRelationshipRole::Character => "character",
+            MediaRelationshipRole::FullStory => "full_story",
+            MediaRelationshipRole::Other => "other",
+            MediaRelationshipRole::ParentStory => "parent_story",
+            MediaRelationshipRole::Prequel => "prequel",
+            MediaRelationshipRole::Sequel => "sequel",
+            MediaRelationshipRole::SideStory => "side_story",
+            MediaRelationshipRole::Spinoff => "spinoff",
+            MediaRelationshipRole::Summary => "summary",
+            MediaRelationshipRole::Unknown => "unknown",
+        }
     }
 }
 
 /// The type of result from a search or retrieval.
-#[derive(Clone, Copy, Debug, Deserialize, Eq, Ord, PartialEq, PartialOrd, Serialize)]
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
 #[serde(rename_all="lowercase")]
 pub enum Type {
     /// Indicator that the result is an [`Anime`].
     ///
     /// [`Anime`]: struct.Anime.html
     Anime,
+    /// Indicator that the result is an [`AnimeProduction`].
+    ///
+    /// [`AnimeProduction`]: struct.AnimeProduction.html
+    #[serde(rename="animeProductions")]
+    AnimeProductions,
+    /// Indicator that the result is a [`Casting`].
+    ///
+    /// [`Casting`]: struct.Casting.html
+    Castings,
+    /// Indicator that the result is a [`Category`].
+    ///
+    /// [`Category`]: struct.Category.html
+    Categories,
+    /// Indicator that the result is a [`Chapter`].
+    ///
+    /// [`Chapter`]: struct.Chapter.html
+    Chapters,
+    /// Indicator that the result is a [`Character`].
+    ///
+    /// [`Character`]: struct.Character.html
+    Characters,
+    /// Indicator that the result is a [`Comment`].
+    ///
+    /// [`Comment`]: struct.Comment.html
+    Comments,
     /// Indicator that the result is a drama.
     Drama,
+    /// Indicator that the result is an [`Episode`].
+    ///
+    /// [`Episode`]: struct.Episode.html
+    Episodes,
+    /// Indicator that the result is a [`Follow`].
+    ///
+    /// [`Follow`]: struct.Follow.html
+    Follows,
+    /// Indicator that the result is a [`Franchise`].
+    ///
+    /// [`Franchise`]: struct.Franchise.html
+    Franchises,
+    /// Indicator that the result is a [`Genre`].
+    ///
+    /// [`Genre`]: struct.Genre.html
+    Genres,
+    /// Indicator that the result is a [`Group`].
+    ///
+    /// [`Group`]: struct.Group.html
+    Groups,
+    /// Indicator that the result is an [`Installment`].
+    ///
+    /// [`Installment`]: struct.Installment.html
+    Installments,
+    /// Indicator that the result is a [`LibraryEntry`].
+    ///
+    /// [`LibraryEntry`]: struct.LibraryEntry.html
+    #[serde(rename="libraryEntries")]
+    LibraryEntries,
+    /// Indicator that the result is a [`LibraryEvent`].
+    ///
+    /// [`LibraryEvent`]: struct.LibraryEvent.html
+    #[serde(rename="libraryEvents")]
+    LibraryEvents,
     /// Indicator that the result is a [`Manga`].
     ///
     /// [`Manga`]: struct.Manga.html
     Manga,
+    /// Indicator that the result is a [`Mapping`].
+    ///
+    /// [`Mapping`]: struct.Mapping.html
+    Mappings,
+    /// Indicator that the result is a [`MediaReaction`].
+    ///
+    /// [`MediaReaction`]: struct.MediaReaction.html
+    #[serde(rename="mediaReactions")]
+    MediaReactions,
+    /// Indicator that the result is a [`MediaRelationship`].
+    ///
+    /// [`MediaRelationship`]: struct.MediaRelationship.html
+    #[serde(rename="mediaRelationships")]
+    MediaRelationships,
+    /// Indicator that the result is a [`Person`].
+    ///
+    /// [`Person`]: struct.Person.html
+    People,
+    /// Indicator that the result is a [`Post`].
+    ///
+    /// [`Post`]: struct.Post.html
+    Posts,
+    /// Indicator that the result is a [`Producer`].
+    ///
+    /// [`Producer`]: struct.Producer.html
+    Producers,
+    /// Indicator that the result is a [`Review`].
+    ///
+    /// [`Review`]: struct.Review.html
+    Reviews,
+    /// Indicator that the result is a [`StreamingLink`].
+    ///
+    /// [`StreamingLink`]: struct.StreamingLink.html
+    #[serde(rename="streamingLinks")]
+    StreamingLinks,
     /// Indicator that the result is a [`User`].
     ///
     /// [`User`]: struct.User.html
     Users,
+    /// Indicator that the result is of a type which is not yet known to this
+    /// library.
+    ///
+    /// This is deserialized from any unrecognized value, so that a new type
+    /// added by Kitsu does not cause the whole response to fail to
+    /// deserialize. The original value is not kept, and the variant is
+    /// displayed, parsed and serialized as `unknown`.
+    #[serde(other, rename = "unknown")]
+    Unknown,
 }
 
 impl Type {
@@ -953,15 +3793,122 @@ impl Type {
     /// assert_eq!(Type::Anime.name().unwrap(), "anime");
     /// ```
     pub fn name(&self) -> Result<String> {
-        let mut name = serde_json::to_string(self)?;
+        Ok(self.to_string())
+    }
+}
+
+impl Display for Type {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        f.write_str(match *self {
+            Type::Anime => "anime",
+            Type::AnimeProductions => "animeProductions",
+            Type::Castings => "castings",
+            Type::Categories => "categories",
+            Type::Chapters => "chapters",
+            Type::Characters => "characters",
+            Type::Comments => "comments",
+            Type::Drama => "drama",
+            Type::Episodes => "episodes",
+            Type::Follows => "follows",
+            Type::Franchises => "franchises",
+            Type::Genres => "genres",
+            Type::Groups => "groups",
+            Type::Installments => "installments",
+            Type::LibraryEntries => "libraryEntries",
+            Type::LibraryEvents => "libraryEvents",
+            Type::Manga => "manga",
+            Type::Mappings => "mappings",
+            Type::MediaReactions => "mediaReactions",
+            Type::MediaRelationships => "mediaRelationships",
+            Type::People => "people",
+            Type::Posts => "posts",
+            Type::Producers => "producers",
+            Type::Reviews => "reviews",
+            Type::StreamingLinks => "streamingLinks",
+            Type::Users => "users",
+            Type::Unknown => "unknown",
+        })
+    }
+}
+
+impl FromStr for Type {
+    type Err = ParseEnumError;
+
+    fn from_str(s: &str) -> StdResult<Self, Self::Err> {
+        Ok(match s {
+            "anime" => Type::Anime,
+            "animeProductions" => Type::AnimeProductions,
+            "castings" => Type::Castings,
+            "categories" => Type::Categories,
+            "chapters" => Type::Chapters,
+            "characters" => Type::Characters,
+            "comments" => Type::Comments,
+            "drama" => Type::Drama,
+            "episodes" => Type::Episodes,
+            "follows" => Type::Follows,
+            "franchises" => Type::Franchises,
+            "genres" => Type::Genres,
+            "groups" => Type::Groups,
+            "installments" => Type::Installments,
+            "libraryEntries" => Type::LibraryEntries,
+            "libraryEvents" => Type::LibraryEvents,
+            "manga" => Type::Manga,
+            "mappings" => Type::Mappings,
+            "mediaReactions" => Type::MediaReactions,
+            "mediaRelationships" => Type::MediaRelationships,
+            "people" => Type::People,
+            "posts" => Type::Posts,
+            "producers" => Type::Producers,
+            "reviews" => Type::Reviews,
+            "streamingLinks" => Type::StreamingLinks,
+            "users" => Type::Users,
+            "unknown" => Type::Unknown,
+            _ => return Err(ParseEnumError::new("type", s)),
+        })
+    }
+}
+
+/// An error returned when parsing one of the model's enums from a string which
+/// is not one of its values.
+///
+/// # Examples
+///
+/// ```rust
+/// use kitsu_io::model::AgeRating;
+///
+/// let error = "PG-21".parse::<AgeRating>().unwrap_err();
+///
+/// assert_eq!(error.value(), "PG-21");
+/// assert_eq!(error.to_string(), "Invalid age rating: PG-21");
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ParseEnumError {
+    kind: &'static str,
+    value: String,
+}
 
-        let _ = name.remove(0);
-        let _ = name.pop();
+impl ParseEnumError {
+    fn new(kind: &'static str, value: &str) -> Self {
+        ParseEnumError {
+            kind,
+            value: value.to_owned(),
+        }
+    }
+
+    /// The string which could not be parsed.
+    pub fn value(&self) -> &str {
+        &self.value
+    }
+}
 
-        Ok(name)
+impl Display for ParseEnumError {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        write!(f, "Invalid {}: {}", self.kind, self.value)
     }
 }
 
+impl StdError for ParseEnumError {}
+
 /// Indicator of whether a [`User`] has a waifu or husbando.
 ///
 /// [`User`]: struct.User.html
@@ -984,15 +3931,64 @@ impl WaifuOrHusbando {
     /// assert_eq!(WaifuOrHusbando::Husbando.name().unwrap(), "Husbando");
     /// ```
     pub fn name(&self) -> Result<String> {
-        let mut name = serde_json::to_string(self)?;
+        Ok(self.to_string())
+    }
+}
 
-        let _ = name.remove(0);
-        let _ = name.pop();
+impl Display for WaifuOrHusbando {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        f.write_str(match *self {
+            WaifuOrHusbando::Husbando => "Husbando",
+            WaifuOrHusbando::Waifu => "Waifu",
+        })
+    }
+}
+
+impl FromStr for WaifuOrHusbando {
+    type Err = ParseEnumError;
+
+    fn from_str(s: &str) -> StdResult<Self, Self::Err> {
+        Ok(match s {
+            "Husbando" => WaifuOrHusbando::Husbando,
+            "Waifu" => WaifuOrHusbando::Waifu,
+            _ => return Err(ParseEnumError::new("waifu or husbando", s)),
+        })
+    }
+}
+
+#[cfg(feature = "chrono")]
+#[inline]
+fn parse_date(date: &str) -> Option<NaiveDate> {
+    NaiveDate::parse_from_str(date.trim(), "%Y-%m-%d").ok()
+}
+
+#[cfg(feature = "chrono")]
+#[inline]
+fn parse_datetime(timestamp: &str) -> Option<DateTime<Utc>> {
+    DateTime::parse_from_rfc3339(timestamp.trim())
+        .ok()
+        .map(|timestamp| timestamp.with_timezone(&Utc))
+}
 
-        Ok(name)
+/// Resolves the URL to an image in a size, substituting the size into the URL
+/// if it is a template, and otherwise falling back to a template given in place
+/// of the original image.
+fn image_url(url: Option<&String>, original: Option<&String>, size: ImageSize) -> Option<String> {
+    const TEMPLATE: &str = "$1";
+
+    match url {
+        Some(url) => Some(url.replace(TEMPLATE, size.name())),
+        None => original
+            .filter(|original| original.contains(TEMPLATE))
+            .map(|template| template.replace(TEMPLATE, size.name())),
     }
 }
 
+#[inline]
+fn star_rating(percentage: f32) -> f32 {
+    (percentage / 20.0 * 2.0).round() / 2.0
+}
+
 #[inline]
 fn youtube_url(id: &String) -> String {
     format!("https://www.youtube.com/watch?v={}", id)
@@ -0,0 +1,36 @@
+//! A set of commonly used items, which can be glob imported to bring the
+//! requester methods into scope alongside the types used with them.
+//!
+//! # Examples
+//!
+//! Retrieve an anime using a [`KitsuClient`] without importing the requester
+//! trait separately:
+//!
+//! ```rust,no_run
+//! # #[cfg(feature = "reqwest")]
+//! # fn main() {
+//! use kitsu_io::prelude::*;
+//!
+//! let client = KitsuClient::new();
+//!
+//! let anime = client.get_anime(1).expect("Error getting anime");
+//! # }
+//! # #[cfg(not(feature = "reqwest"))]
+//! # fn main() { }
+//! ```
+//!
+//! [`KitsuClient`]: ../client/struct.KitsuClient.html
+
+pub use crate::builder::Search;
+pub use crate::{Error, Result};
+
+#[cfg(feature = "hyper")]
+pub use crate::bridge::hyper::KitsuRequester as KitsuHyperRequester;
+#[cfg(feature = "reqwest")]
+pub use crate::bridge::reqwest::KitsuRequester as KitsuReqwestRequester;
+#[cfg(feature = "reqwest-async")]
+pub use crate::bridge::reqwest_async::KitsuRequester as KitsuAsyncRequester;
+#[cfg(feature = "reqwest-async")]
+pub use crate::bridge::reqwest_async::KitsuAsyncClient;
+#[cfg(feature = "reqwest")]
+pub use crate::client::{KitsuClient, KitsuClientBuilder};
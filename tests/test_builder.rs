@@ -0,0 +1,243 @@
+extern crate kitsu_io;
+
+use kitsu_io::builder::{MediaStatus, Search, Season, SortField};
+
+#[test]
+fn test_fields() {
+    let search = Search::default().fields("anime", &["slug", "synopsis"]);
+
+    assert_eq!(search.as_query(), "fields[anime]=slug,synopsis");
+}
+
+#[test]
+fn test_fields_replaces_same_type() {
+    let search = Search::default()
+        .fields("anime", &["slug"])
+        .fields("manga", &["slug"])
+        .fields("anime", &["synopsis"]);
+
+    assert_eq!(search.as_query(), "fields[anime]=synopsis&fields[manga]=slug");
+}
+
+#[test]
+fn test_filter_encodes_value() {
+    let search = Search::default().filter("text", "non non biyori!");
+
+    assert!(!search.as_query().contains(' '));
+    assert_eq!(search.as_query(), "filter[text]=non+non+biyori%21");
+}
+
+#[test]
+fn test_filter_encodes_reserved_characters() {
+    let search = Search::default().filter("text", "a&b=c+d/é");
+
+    assert_eq!(search.as_query(), "filter[text]=a%26b%3Dc%2Bd%2F%C3%A9");
+}
+
+#[test]
+fn test_filter_repeated_key_merges() {
+    let search = Search::default()
+        .filter("categories", "action")
+        .limit(5)
+        .filter("categories", "comedy")
+        .filter_in("categories", &["action", "slice of life"]);
+
+    assert_eq!(search.as_query(), "filter[categories]=action,comedy,slice+of+life&page[limit]=5");
+}
+
+#[test]
+fn test_filter_range_min() {
+    let search = Search::default().filter_range("averageRating", Some(70.0), None);
+
+    assert_eq!(search.as_query(), "filter[averageRating]=70..");
+}
+
+#[test]
+fn test_filter_range_max() {
+    let search = Search::default().filter_range("averageRating", None, Some(80.5));
+
+    assert_eq!(search.as_query(), "filter[averageRating]=..80.5");
+}
+
+#[test]
+fn test_filter_range_min_max() {
+    let search = Search::default().filter_range("averageRating", Some(70.0), Some(80.0));
+
+    assert_eq!(search.as_query(), "filter[averageRating]=70..80");
+}
+
+#[test]
+fn test_filter_range_unbounded() {
+    let search = Search::default().filter_range("averageRating", None, None);
+
+    assert_eq!(search.as_query(), "");
+}
+
+#[test]
+fn test_filter_in_encodes_values() {
+    let search = Search::default().filter_in("slug", &["cowboy-bebop", "a,b", "c&d"]);
+
+    assert_eq!(search.as_query(), "filter[slug]=cowboy-bebop,a%2Cb,c%26d");
+}
+
+#[test]
+fn test_include() {
+    let search = Search::default().include(&["genres", "categories"]);
+
+    assert!(search.as_query().contains("include=genres,categories"));
+}
+
+#[test]
+fn test_include_merges_repeated_calls() {
+    let search = Search::default()
+        .include(&["genres"])
+        .filter("text", "orange")
+        .include(&["categories", "genres"]);
+
+    assert_eq!(search.as_query(), "include=genres,categories&filter[text]=orange");
+}
+
+#[test]
+fn test_raw_param_encodes_key_and_value() {
+    let search = Search::default()
+        .limit(5)
+        .raw_param("filter[a&b]", "x=y z")
+        .raw_param("experimental", "1");
+
+    assert_eq!(search.as_query(), "page[limit]=5&filter[a%26b]=x%3Dy+z&experimental=1");
+}
+
+#[test]
+fn test_sort_encodes_value() {
+    let search = Search::default().sort("-updatedAt,id");
+
+    assert_eq!(search.as_query(), "sort=-updatedAt%2Cid");
+}
+
+#[test]
+fn test_sort_by() {
+    let search = Search::default().sort_by(&[
+        SortField {
+            field: "updatedAt".to_owned(),
+            descending: true,
+        },
+        SortField {
+            field: "id".to_owned(),
+            descending: false,
+        },
+    ]);
+
+    assert_eq!(search.as_query(), "sort=-updatedAt,id");
+    assert_eq!(Search::default().sort_by(&[]).as_query(), "");
+}
+
+#[test]
+fn test_scalars_replace() {
+    let search = Search::default()
+        .limit(5)
+        .sort("id")
+        .offset(10)
+        .filter_range("averageRating", Some(70.0), None)
+        .season_year(1998)
+        .limit(20)
+        .sort_by(&[SortField {
+            field: "startDate".to_owned(),
+            descending: true,
+        }])
+        .offset(30)
+        .filter_range("averageRating", None, Some(90.0))
+        .season_year(2001);
+
+    assert_eq!(
+        search.as_query(),
+        "page[limit]=20&sort=-startDate&page[offset]=30&filter[averageRating]=..90&\
+         filter[seasonYear]=2001",
+    );
+
+    let search = Search::default().page_size(10).page_number(1).page_number(2).page_size(20);
+
+    assert_eq!(search.as_query(), "page[size]=20&page[number]=2");
+}
+
+#[test]
+fn test_raw_param_appends() {
+    let search = Search::default()
+        .raw_param("page[limit]", "5")
+        .raw_param("page[limit]", "10");
+
+    assert_eq!(search.as_query(), "page[limit]=5&page[limit]=10");
+    assert_eq!(search.limit(20).as_query(), "page[limit]=20");
+}
+
+#[test]
+fn test_clone_is_independent() {
+    let base = Search::new().filter("genres", "mecha").limit(10);
+
+    let ascending = base.clone().sort("averageRating");
+    let descending = base.clone().sort("-averageRating");
+
+    assert_eq!(base.as_query(), "filter[genres]=mecha&page[limit]=10");
+    assert!(ascending.as_query().ends_with("&sort=averageRating"));
+    assert!(descending.as_query().ends_with("&sort=-averageRating"));
+    assert!(!descending.as_query().contains("sort=averageRating"));
+}
+
+#[test]
+fn test_validate_pagination_styles() {
+    assert!(Search::default().limit(10).offset(10).validate().is_ok());
+    assert!(Search::default().page_size(10).page_number(2).validate().is_ok());
+    assert!(Search::default().offset(10).page_size(10).validate().is_err());
+    assert!(Search::default().limit(10).page_number(2).validate().is_err());
+}
+
+#[test]
+fn test_query_has_no_leading_ampersand() {
+    let searches = vec![
+        Search::default().filter("text", "orange"),
+        Search::default().fields("anime", &["slug"]),
+        Search::default().include(&["genres"]).limit(5),
+        Search::default().page_size(20).page_number(3),
+        Search::default().sort("-updatedAt").offset(10),
+    ];
+
+    for search in searches {
+        assert!(!search.as_query().starts_with('&'), "{}", search.as_query());
+        assert!(!search.as_query().contains("&&"), "{}", search.as_query());
+    }
+}
+
+#[test]
+fn test_canonical_query_sorts_values() {
+    let a = Search::default()
+        .filter_in("subtype", &["TV", "movie"])
+        .include(&["genres", "categories"]);
+    let b = Search::default()
+        .include(&["categories", "genres"])
+        .filter_in("subtype", &["movie", "TV"]);
+
+    assert_eq!(a.canonical_query(), b.canonical_query());
+    assert_eq!(a.canonical_query(), "filter[subtype]=TV,movie&include=categories,genres");
+}
+
+#[test]
+fn test_canonical_query_keeps_sort_order() {
+    let search = Search::default().sort_by(&[
+        SortField {
+            field: "startDate".to_owned(),
+            descending: false,
+        },
+        SortField {
+            field: "averageRating".to_owned(),
+            descending: true,
+        },
+    ]);
+
+    assert_eq!(search.canonical_query(), "sort=startDate,-averageRating");
+}
+
+#[test]
+fn test_names_are_static() {
+    let names: [&'static str; 2] = [MediaStatus::Current.name(), Season::Spring.name()];
+
+    assert_eq!(names, ["current", "spring"]);
+}
@@ -0,0 +1,889 @@
+#![cfg(feature = "reqwest-support")]
+
+extern crate kitsu_io;
+extern crate reqwest;
+
+use kitsu_io::bridge::reqwest::{retry_with_backoff, RetryPolicy};
+use kitsu_io::builder::Search;
+use kitsu_io::client::{ETAG_CACHE_CAPACITY, USER_AGENT};
+use kitsu_io::model::{Category, GroupPrivacy, LibraryEventKind};
+use kitsu_io::{Error, KitsuClient, API_URL};
+use std::io::{BufRead, BufReader, Write};
+use std::net::{TcpListener, TcpStream};
+use std::sync::mpsc::{self, Receiver};
+use std::thread;
+use std::time::Duration;
+
+const CATEGORIES: &str = r#"{
+    "data": [
+        {
+            "id": "1",
+            "type": "categories",
+            "links": {
+                "self": "https://kitsu.io/api/edge/categories/1"
+            },
+            "attributes": {
+                "childCount": 0,
+                "description": null,
+                "nsfw": false,
+                "slug": "space",
+                "title": "Space"
+            }
+        }
+    ]
+}"#;
+
+/// A request received by the mock server.
+struct Request {
+    headers: Vec<(String, String)>,
+    path: String,
+}
+
+impl Request {
+    fn header(&self, name: &str) -> Option<&str> {
+        self.headers.iter()
+            .find(|(key, _)| key.eq_ignore_ascii_case(name))
+            .map(|(_, value)| value.as_str())
+    }
+}
+
+/// Serves a single response with the given body on a local port, returning
+/// the base URL of the server and a receiver of the request that was made.
+fn serve(body: &'static str) -> (String, Receiver<Request>) {
+    serve_response("200 OK", body, Duration::from_secs(0))
+}
+
+/// Serves a single response as in [`serve`], waiting for a delay before
+/// responding.
+fn serve_after(body: &'static str, delay: Duration) -> (String, Receiver<Request>) {
+    serve_response("200 OK", body, delay)
+}
+
+/// Serves a single response as in [`serve`] with the given status line,
+/// such as `404 Not Found`.
+fn serve_status(status: &'static str, body: &'static str) -> (String, Receiver<Request>) {
+    serve_response(status, body, Duration::from_secs(0))
+}
+
+fn serve_response(
+    status: &'static str,
+    body: &'static str,
+    delay: Duration,
+) -> (String, Receiver<Request>) {
+    serve_responses(vec![(status, body)], delay)
+}
+
+/// Serves each of the given statuses and bodies in turn, one per request.
+fn serve_responses(
+    responses: Vec<(&'static str, &'static str)>,
+    delay: Duration,
+) -> (String, Receiver<Request>) {
+    let responses = responses.into_iter()
+        .map(|(status, body)| (status, "", body))
+        .collect();
+
+    serve_responses_with_headers(responses, delay)
+}
+
+/// Serves each of the given statuses, extra header lines, and bodies in turn,
+/// as in [`serve_responses`].
+fn serve_responses_with_headers(
+    responses: Vec<(&'static str, &'static str, &'static str)>,
+    delay: Duration,
+) -> (String, Receiver<Request>) {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let base_url = format!("http://{}/api/edge", listener.local_addr().unwrap());
+    let (tx, rx) = mpsc::channel();
+
+    thread::spawn(move || {
+        for (status, headers, body) in responses {
+            let (mut stream, _) = listener.accept().unwrap();
+            let request = read_request(&stream);
+
+            thread::sleep(delay);
+
+            // The client may have given up waiting, so ignore errors writing.
+            let _ = write!(
+                stream,
+                "HTTP/1.1 {}\r\nContent-Type: application/vnd.api+json\r\n{}\
+                 Content-Length: {}\r\nConnection: close\r\n\r\n{}",
+                status,
+                headers,
+                body.len(),
+                body,
+            );
+
+            let _ = tx.send(request);
+        }
+    });
+
+    (base_url, rx)
+}
+
+/// Serves a single response with a gzip encoded body, as in [`serve`].
+fn serve_gzip(body: &'static [u8]) -> (String, Receiver<Request>) {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let base_url = format!("http://{}/api/edge", listener.local_addr().unwrap());
+    let (tx, rx) = mpsc::channel();
+
+    thread::spawn(move || {
+        let (mut stream, _) = listener.accept().unwrap();
+        let request = read_request(&stream);
+
+        let _ = write!(
+            stream,
+            "HTTP/1.1 200 OK\r\nContent-Type: application/vnd.api+json\r\n\
+             Content-Encoding: gzip\r\nContent-Length: {}\r\nConnection: close\r\n\r\n",
+            body.len(),
+        );
+        let _ = stream.write_all(body);
+
+        let _ = tx.send(request);
+    });
+
+    (base_url, rx)
+}
+
+/// Reads the request line and headers of a request.
+fn read_request(stream: &TcpStream) -> Request {
+    let mut reader = BufReader::new(stream.try_clone().unwrap());
+    let mut line = String::new();
+    reader.read_line(&mut line).unwrap();
+
+    let path = line.split_whitespace().nth(1).unwrap_or_default().to_owned();
+    let mut headers = Vec::new();
+
+    loop {
+        let mut header = String::new();
+
+        if reader.read_line(&mut header).unwrap() <= 2 {
+            break;
+        }
+
+        if let Some(idx) = header.find(':') {
+            let (key, value) = header.split_at(idx);
+            headers.push((key.to_owned(), value[1..].trim().to_owned()));
+        }
+    }
+
+    Request { headers, path }
+}
+
+#[test]
+fn test_default_base_url() {
+    assert_eq!(KitsuClient::new().base_url(), API_URL);
+    assert_eq!(KitsuClient::default().base_url(), API_URL);
+}
+
+#[test]
+fn test_prelude() {
+    // The trait is not imported, so the inherent methods of the client are called.
+    use kitsu_io::prelude::KitsuClient as PreludeClient;
+
+    let (base_url, request) = serve(CATEGORIES);
+    let client = PreludeClient::new().with_base_url(&base_url);
+
+    assert!(client.get_anime_categories(1).is_ok());
+    assert_eq!(request.recv().unwrap().path, "/api/edge/anime/1/categories");
+}
+
+#[test]
+fn test_with_base_url() {
+    let (base_url, request) = serve(CATEGORIES);
+    let client = KitsuClient::new().with_base_url(&base_url);
+
+    let res = client.get_anime_categories(1).unwrap();
+
+    assert_eq!(request.recv().unwrap().path, "/api/edge/anime/1/categories");
+    assert_eq!(res.data[0].attributes.slug, "space");
+}
+
+#[test]
+fn test_fetch_with_base_url() {
+    let (base_url, request) = serve(CATEGORIES);
+    let client = KitsuClient::new().with_base_url(&base_url);
+
+    let url = format!("{}/categories", base_url);
+    let res = client.fetch::<Vec<Category>>(&url).unwrap();
+
+    assert_eq!(request.recv().unwrap().path, "/api/edge/categories");
+    assert_eq!(res.data[0].attributes.title, "Space");
+
+    let foreign = client.fetch::<Vec<Category>>("https://kitsu.io/api/edge/categories");
+
+    assert!(foreign.is_err());
+}
+
+#[test]
+fn test_default_user_agent() {
+    let (base_url, request) = serve(CATEGORIES);
+    let client = KitsuClient::new().with_base_url(&base_url);
+
+    client.get_anime_categories(1).unwrap();
+
+    assert_eq!(request.recv().unwrap().header("User-Agent"), Some(USER_AGENT));
+}
+
+#[test]
+fn test_accept_json_api() {
+    let (base_url, request) = serve(CATEGORIES);
+    let client = KitsuClient::new().with_base_url(&base_url);
+
+    client.get_anime_categories(1).unwrap();
+
+    assert_eq!(request.recv().unwrap().header("Accept"), Some("application/vnd.api+json"));
+}
+
+#[test]
+fn test_builder_user_agent() {
+    let (base_url, request) = serve(CATEGORIES);
+    let client = KitsuClient::builder()
+        .base_url(&base_url)
+        .user_agent("kitsu_io-tests/1.0")
+        .build()
+        .unwrap();
+
+    client.get_anime_categories(1).unwrap();
+
+    assert_eq!(request.recv().unwrap().header("User-Agent"), Some("kitsu_io-tests/1.0"));
+}
+
+#[test]
+fn test_timeout() {
+    let (base_url, _) = serve_after(CATEGORIES, Duration::from_secs(2));
+    let client = KitsuClient::builder()
+        .base_url(&base_url)
+        .timeout(Duration::from_millis(100))
+        .build()
+        .unwrap();
+
+    match client.get_anime_categories(1) {
+        Err(Error::Timeout) => {},
+        other => panic!("Expected a timeout, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_builder_proxy() {
+    let (base_url, request) = serve(CATEGORIES);
+    let proxy_url = base_url.trim_end_matches("/api/edge");
+    let client = KitsuClient::builder()
+        .base_url("http://kitsu.invalid/api/edge")
+        .proxy(reqwest::Proxy::http(proxy_url).unwrap())
+        .build()
+        .unwrap();
+
+    client.get_anime_categories(1).unwrap();
+
+    assert_eq!(
+        request.recv().unwrap().path,
+        "http://kitsu.invalid/api/edge/anime/1/categories",
+    );
+}
+
+#[test]
+fn test_get_anime_by_slug_missing() {
+    let (base_url, request) = serve(r#"{"data": []}"#);
+    let client = KitsuClient::new().with_base_url(&base_url);
+
+    assert!(client.get_anime_by_slug("cowboy-bebop").unwrap().is_none());
+    assert_eq!(
+        request.recv().unwrap().path,
+        "/api/edge/anime?filter[slug]=cowboy-bebop&page[limit]=1",
+    );
+}
+
+#[test]
+fn test_try_get_anime_not_found() {
+    let (base_url, request) = serve_status(
+        "404 Not Found",
+        r#"{"errors": [{"title": "Record not found", "status": "404", "code": "404"}]}"#,
+    );
+    let client = KitsuClient::new().with_base_url(&base_url);
+
+    assert!(client.try_get_anime(1).unwrap().is_none());
+    assert_eq!(request.recv().unwrap().path, "/api/edge/anime/1");
+}
+
+#[test]
+fn test_try_get_anime_server_error() {
+    let (base_url, _) = serve_status("500 Internal Server Error", "");
+    let client = KitsuClient::new().with_base_url(&base_url);
+
+    match client.try_get_anime(1) {
+        Err(Error::ReqwestInvalid(status, _)) => assert_eq!(status.as_u16(), 500),
+        other => panic!("Expected an invalid response, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_search_anime_with() {
+    let (base_url, request) = serve(r#"{"data": []}"#);
+    let client = KitsuClient::new().with_base_url(&base_url);
+
+    let filters = [("text", Some("bebop")), ("genres", None), ("subtype", Some("TV"))];
+    let mut search = Search::default();
+
+    for &(key, value) in &filters {
+        if let Some(value) = value {
+            search = search.filter(key, value);
+        }
+    }
+
+    client.search_anime_with(search).unwrap();
+
+    assert_eq!(
+        request.recv().unwrap().path,
+        "/api/edge/anime?filter[text]=bebop&filter[subtype]=TV",
+    );
+}
+
+#[test]
+fn test_request_id_of_api_error() {
+    let (base_url, request) = serve_status(
+        "404 Not Found",
+        r#"{"errors": [{"title": "Record not found", "status": "404", "code": "404"}]}"#,
+    );
+    let client = KitsuClient::new().with_base_url(&base_url);
+
+    let error = client.get_anime_categories(1).unwrap_err();
+    let request = request.recv().unwrap();
+
+    assert!(matches!(error, Error::Api(..)));
+    assert!(error.request_id().is_some());
+    assert_eq!(error.request_id(), request.header("X-Request-Id"));
+}
+
+#[test]
+fn test_request_id_of_rate_limited() {
+    let (base_url, request) = serve_status("429 Too Many Requests", "");
+    let client = KitsuClient::new().with_base_url(&base_url);
+
+    let error = client.get_anime_categories(1).unwrap_err();
+    let request = request.recv().unwrap();
+
+    assert!(matches!(error, Error::RateLimited { .. }));
+    assert!(error.request_id().is_some());
+    assert_eq!(error.request_id(), request.header("X-Request-Id"));
+}
+
+#[test]
+fn test_request_id_of_malformed_body() {
+    let (base_url, request) = serve("<html><body>Bad Gateway</body></html>");
+    let client = KitsuClient::new().with_base_url(&base_url);
+
+    let error = client.get_anime_categories(1).unwrap_err();
+    let request = request.recv().unwrap();
+
+    assert!(matches!(error, Error::Json { .. }));
+    assert!(error.request_id().is_some());
+    assert_eq!(error.request_id(), request.header("X-Request-Id"));
+}
+
+#[test]
+fn test_retrying_server_error() {
+    let (base_url, request) = serve_responses(vec![
+        ("503 Service Unavailable", ""),
+        ("429 Too Many Requests", ""),
+        ("200 OK", CATEGORIES),
+    ], Duration::from_secs(0));
+    let client = KitsuClient::new().with_base_url(&base_url);
+    let policy = RetryPolicy::new(3, Duration::from_millis(1));
+
+    let res = retry_with_backoff(policy, || client.get_anime_categories(1)).unwrap();
+
+    assert_eq!(res.data[0].attributes.slug, "space");
+    assert_eq!(request.iter().count(), 3);
+}
+
+#[test]
+fn test_retrying_gives_up() {
+    let (base_url, request) = serve_responses(vec![
+        ("500 Internal Server Error", ""),
+        ("502 Bad Gateway", ""),
+    ], Duration::from_secs(0));
+    let client = KitsuClient::new().with_base_url(&base_url);
+    let policy = RetryPolicy::new(2, Duration::from_millis(1));
+
+    match client.get_anime_retrying(1, policy) {
+        Err(Error::ReqwestInvalid(status, _)) => assert_eq!(status.as_u16(), 502),
+        other => panic!("Expected an invalid response, got {:?}", other),
+    }
+
+    assert_eq!(request.iter().count(), 2);
+}
+
+#[test]
+fn test_retrying_not_found() {
+    let (base_url, request) = serve_status("404 Not Found", "");
+    let client = KitsuClient::new().with_base_url(&base_url);
+    let policy = RetryPolicy::new(3, Duration::from_millis(1));
+
+    match client.get_anime_retrying(1, policy) {
+        Err(Error::ReqwestInvalid(status, _)) => assert_eq!(status.as_u16(), 404),
+        other => panic!("Expected an invalid response, got {:?}", other),
+    }
+
+    assert_eq!(request.iter().count(), 1);
+}
+
+#[test]
+fn test_retry_backoff_saturates() {
+    let policy = RetryPolicy::new(40, Duration::from_secs(1 << 40));
+
+    assert_eq!(policy.backoff(0), Duration::from_secs(1 << 40));
+    assert_eq!(policy.backoff(39), Duration::MAX);
+}
+
+#[test]
+fn test_gzip_response() {
+    // `{"data": []}`, compressed with gzip.
+    let body = &[
+        31, 139, 8, 0, 0, 0, 0, 0, 2, 3, 171, 86, 74, 73, 44, 73, 84, 178, 82, 136, 142, 173, 5,
+        0, 184, 120, 189, 38, 12, 0, 0, 0,
+    ];
+    let (base_url, request) = serve_gzip(body);
+    let client = KitsuClient::new().with_base_url(&base_url);
+
+    let res = client.get_anime_categories(1).unwrap();
+
+    assert!(res.data.is_empty());
+
+    let request = request.recv().unwrap();
+    let accept_encoding = request.header("Accept-Encoding").unwrap_or_default();
+
+    assert!(accept_encoding.contains("gzip"));
+}
+
+#[test]
+fn test_json_error_body() {
+    let (base_url, _) = serve("<html><body>Bad Gateway</body></html>");
+    let client = KitsuClient::new().with_base_url(&base_url);
+
+    match client.get_anime_categories(1) {
+        Err(Error::Json { body, .. }) => {
+            assert_eq!(body, "<html><body>Bad Gateway</body></html>");
+        },
+        other => panic!("Expected a JSON error, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_invalid_search_not_sent() {
+    let (base_url, request) = serve(CATEGORIES);
+    let client = KitsuClient::new().with_base_url(&base_url);
+
+    match client.search_anime(|f| f.limit(10).page_number(2)) {
+        Err(Error::InvalidSearch(_)) => {},
+        other => panic!("Expected an invalid search, got {:?}", other),
+    }
+
+    match client.search_anime_all(|f| f.offset(10).page_size(10)).next() {
+        Some(Err(Error::InvalidSearch(_))) => {},
+        other => panic!("Expected an invalid search, got {:?}", other),
+    }
+
+    assert!(request.recv_timeout(Duration::from_millis(100)).is_err());
+}
+
+#[test]
+fn test_get_anime_reactions() {
+    let (base_url, request) = serve(r#"{
+        "data": [
+            {
+                "id": "1",
+                "type": "mediaReactions",
+                "links": {},
+                "attributes": {
+                    "reaction": "The soundtrack alone is worth it.",
+                    "upVotesCount": 42
+                }
+            }
+        ]
+    }"#);
+    let client = KitsuClient::new().with_base_url(&base_url);
+
+    let res = client.get_anime_reactions(1).unwrap();
+
+    assert_eq!(request.recv().unwrap().path, "/api/edge/anime/1/media-reactions");
+    assert_eq!(res.data[0].attributes.up_votes_count, 42);
+}
+
+#[test]
+fn test_search_groups() {
+    let (base_url, request) = serve(r#"{
+        "data": [
+            {
+                "id": "1",
+                "type": "groups",
+                "links": {},
+                "attributes": {
+                    "about": null,
+                    "membersCount": 1234,
+                    "name": "Mecha Fans",
+                    "nsfw": false,
+                    "privacy": "open",
+                    "slug": "mecha-fans"
+                }
+            }
+        ]
+    }"#);
+    let client = KitsuClient::new().with_base_url(&base_url);
+
+    let res = client.search_groups(|f| f.filter("query", "mecha")).unwrap();
+
+    assert_eq!(request.recv().unwrap().path, "/api/edge/groups?filter[query]=mecha");
+    assert_eq!(res.data[0].attributes.privacy, GroupPrivacy::Open);
+    assert_eq!(res.data[0].attributes.members_count, 1234);
+}
+
+#[test]
+fn test_get_user_waifu() {
+    let (base_url, request) = serve(r#"{
+        "data": {
+            "id": "1",
+            "type": "characters",
+            "links": {},
+            "attributes": {
+                "description": null,
+                "image": null,
+                "name": "Spike Spiegel",
+                "slug": "spike-spiegel"
+            }
+        }
+    }"#);
+    let client = KitsuClient::new().with_base_url(&base_url);
+
+    let res = client.get_user_waifu(1).unwrap();
+
+    assert_eq!(request.recv().unwrap().path, "/api/edge/users/1/waifu");
+    assert_eq!(res.data.unwrap().attributes.slug, "spike-spiegel");
+}
+
+#[test]
+fn test_get_user_waifu_none() {
+    let (base_url, _) = serve(r#"{"data": null}"#);
+    let client = KitsuClient::new().with_base_url(&base_url);
+
+    assert!(client.get_user_waifu(1).unwrap().data.is_none());
+}
+
+#[test]
+fn test_get_user_library_events() {
+    let (base_url, request) = serve(r#"{
+        "data": [
+            {
+                "id": "1",
+                "type": "libraryEvents",
+                "links": {},
+                "attributes": {
+                    "changedData": {"progress": [3, 4]},
+                    "createdAt": "2017-05-22T06:00:10.247Z",
+                    "kind": "progressed"
+                }
+            }
+        ]
+    }"#);
+    let client = KitsuClient::new().with_base_url(&base_url);
+
+    let res = client.get_user_library_events(1, |f| f.limit(1)).unwrap();
+    let event = &res.data[0].attributes;
+
+    assert_eq!(request.recv().unwrap().path, "/api/edge/users/1/library-events?page[limit]=1");
+    assert_eq!(event.kind, LibraryEventKind::Progressed);
+    assert_eq!(event.changed_data["progress"][1], 4);
+}
+
+#[test]
+fn test_get_anime_many_chunks_ids() {
+    let (base_url, request) = serve_responses(vec![
+        ("200 OK", r#"{"data": []}"#),
+        ("200 OK", r#"{"data": []}"#),
+    ], Duration::from_secs(0));
+    let client = KitsuClient::new().with_base_url(&base_url);
+    let ids = (1..=25).collect::<Vec<u64>>();
+
+    let res = client.get_anime_many(&ids).unwrap();
+    let paths = request.iter().map(|request| request.path).collect::<Vec<_>>();
+
+    assert!(res.data.is_empty());
+    assert_eq!(paths, vec![
+        "/api/edge/anime?filter[id]=1,2,3,4,5,6,7,8,9,10,11,12,13,14,15,16,17,18,19,20\
+         &page[limit]=20",
+        "/api/edge/anime?filter[id]=21,22,23,24,25&page[limit]=5",
+    ]);
+}
+
+#[test]
+fn test_get_anime_many_empty() {
+    let client = KitsuClient::new().with_base_url("http://127.0.0.1:9/api/edge");
+
+    assert!(client.get_anime_many(&[]).unwrap().data.is_empty());
+}
+
+#[test]
+fn test_get_user_followers() {
+    let (base_url, request) = serve(r#"{
+        "data": [
+            {
+                "id": "1",
+                "type": "follows",
+                "links": {},
+                "attributes": {
+                    "createdAt": "2017-05-22T06:00:10.247Z",
+                    "updatedAt": "2017-05-22T06:00:10.247Z"
+                }
+            }
+        ],
+        "included": [
+            {
+                "id": "1",
+                "type": "groups"
+            }
+        ]
+    }"#);
+    let client = KitsuClient::new().with_base_url(&base_url);
+
+    let res = client.get_user_followers(1, |f| f.limit(20)).unwrap();
+
+    assert_eq!(
+        request.recv().unwrap().path,
+        "/api/edge/follows?page[limit]=20&filter[followed]=1&include=follower",
+    );
+    assert!(res.data.is_empty());
+    assert_eq!(res.included.len(), 1);
+}
+
+/// A user with the given id and name, as a JSON resource.
+fn user(id: u64, name: &str) -> String {
+    let link = r#"{"links": {"self": "", "related": ""}}"#;
+
+    format!(r#"{{
+        "id": "{id}",
+        "type": "users",
+        "links": {{}},
+        "attributes": {{
+            "about": "", "commentsCount": 0, "createdAt": "", "favoritesCount": 0,
+            "feedCompleted": false, "followersCount": 0, "followingCount": 0,
+            "lifeSpentOnAnime": 0, "likesGivenCount": 0, "likesReceivedCount": 0,
+            "name": "{name}", "pastNames": [], "postsCount": 0, "profileCompleted": false,
+            "ratingsCount": 0, "reviewsCount": 0, "updatedAt": ""
+        }},
+        "relationships": {{
+            "favorites": {link}, "followers": {link}, "following": {link},
+            "libraryEntries": {link}, "reviews": {link}
+        }}
+    }}"#, id = id, name = name, link = link)
+}
+
+/// A follow of a user by another user, as a JSON resource.
+fn follow(id: u64, follower: u64, followed: u64) -> String {
+    format!(r#"{{
+        "id": "{}",
+        "type": "follows",
+        "links": {{}},
+        "attributes": {{}},
+        "relationships": {{
+            "follower": {{"data": {{"type": "users", "id": "{}"}}}},
+            "followed": {{"data": {{"type": "users", "id": "{}"}}}}
+        }}
+    }}"#, id, follower, followed)
+}
+
+#[test]
+fn test_get_user_followers_in_order() {
+    let body = format!(
+        r#"{{"data": [{}, {}, {}], "included": [{}, {}, {{"id": "1", "type": "groups"}}]}}"#,
+        follow(1, 3, 1),
+        follow(2, 2, 1),
+        follow(3, 4, 1),
+        user(2, "second"),
+        user(3, "third"),
+    );
+    let (base_url, _) = serve(Box::leak(body.into_boxed_str()));
+    let client = KitsuClient::new().with_base_url(&base_url);
+
+    let res = client.get_user_followers(1, |f| f).unwrap();
+    let names = res.data.iter().map(|user| &user.attributes.name[..]).collect::<Vec<_>>();
+
+    // The follower of the third follow was not included, so it is skipped.
+    assert_eq!(names, vec!["third", "second"]);
+    assert_eq!(res.included.len(), 1);
+}
+
+#[test]
+fn test_get_user_following_uses_followed() {
+    let body = format!(
+        r#"{{"data": [{}], "included": [{}, {}]}}"#,
+        follow(1, 1, 2),
+        user(1, "follower"),
+        user(2, "followed"),
+    );
+    let (base_url, _) = serve(Box::leak(body.into_boxed_str()));
+    let client = KitsuClient::new().with_base_url(&base_url);
+
+    let res = client.get_user_following(1, |f| f).unwrap();
+
+    assert_eq!(res.data.len(), 1);
+    assert_eq!(res.data[0].attributes.name, "followed");
+}
+
+#[test]
+fn test_get_user_following_all() {
+    let (base_url, request) = serve(r#"{"data": []}"#);
+    let client = KitsuClient::new().with_base_url(&base_url);
+
+    assert!(client.get_user_following_all(1, |f| f).next().is_none());
+    assert_eq!(
+        request.recv().unwrap().path,
+        "/api/edge/follows?filter[follower]=1&include=followed",
+    );
+}
+
+#[test]
+fn test_etag_cache() {
+    let (base_url, request) = serve_responses_with_headers(vec![
+        ("200 OK", "ETag: \"abc\"\r\n", CATEGORIES),
+        ("304 Not Modified", "", ""),
+    ], Duration::from_secs(0));
+    let client = KitsuClient::new().with_base_url(&base_url).with_etag_cache(true);
+
+    client.get_anime_categories(1).unwrap();
+    let res = client.get_anime_categories(1).unwrap();
+    let requests = request.iter().collect::<Vec<_>>();
+
+    assert_eq!(res.data[0].attributes.slug, "space");
+    assert_eq!(requests[0].header("If-None-Match"), None);
+    assert_eq!(requests[1].header("If-None-Match"), Some("\"abc\""));
+}
+
+#[test]
+fn test_etag_cache_disabled() {
+    let (base_url, request) = serve_responses_with_headers(vec![
+        ("200 OK", "ETag: \"abc\"\r\n", CATEGORIES),
+        ("200 OK", "ETag: \"abc\"\r\n", CATEGORIES),
+    ], Duration::from_secs(0));
+    let client = KitsuClient::builder()
+        .base_url(&base_url)
+        .etag_cache(false)
+        .build()
+        .unwrap();
+
+    client.get_anime_categories(1).unwrap();
+    client.get_anime_categories(1).unwrap();
+
+    assert!(request.iter().all(|request| request.header("If-None-Match").is_none()));
+}
+
+#[test]
+fn test_etag_cache_disabled_by_default() {
+    let (base_url, request) = serve_responses_with_headers(vec![
+        ("200 OK", "ETag: \"abc\"\r\n", CATEGORIES),
+        ("200 OK", "ETag: \"abc\"\r\n", CATEGORIES),
+    ], Duration::from_secs(0));
+    let client = KitsuClient::new().with_base_url(&base_url);
+
+    client.get_anime_categories(1).unwrap();
+    client.get_anime_categories(1).unwrap();
+
+    assert!(request.iter().all(|request| request.header("If-None-Match").is_none()));
+}
+
+#[test]
+fn test_etag_cache_skips_searches() {
+    let (base_url, request) = serve_responses_with_headers(vec![
+        ("200 OK", "ETag: \"abc\"\r\n", CATEGORIES),
+        ("200 OK", "ETag: \"abc\"\r\n", CATEGORIES),
+    ], Duration::from_secs(0));
+    let client = KitsuClient::new().with_base_url(&base_url).with_etag_cache(true);
+
+    client.get_categories(|f| f.limit(1)).unwrap();
+    client.get_categories(|f| f.limit(1)).unwrap();
+
+    assert!(request.iter().all(|request| request.header("If-None-Match").is_none()));
+}
+
+#[test]
+fn test_etag_cache_evicts_least_recently_used() {
+    let mut responses = Vec::new();
+
+    for _ in 0..ETAG_CACHE_CAPACITY + 3 {
+        responses.push(("200 OK", "ETag: \"abc\"\r\n", CATEGORIES));
+    }
+
+    let (base_url, request) = serve_responses_with_headers(responses, Duration::from_secs(0));
+    let client = KitsuClient::new().with_base_url(&base_url).with_etag_cache(true);
+
+    // Fill the cache, using the first anime again so that the second is the
+    // least recently used when the cache overflows.
+    for id in 1..=ETAG_CACHE_CAPACITY as u64 {
+        client.get_anime_categories(id).unwrap();
+    }
+
+    client.get_anime_categories(1).unwrap();
+    client.get_anime_categories(ETAG_CACHE_CAPACITY as u64 + 1).unwrap();
+    client.get_anime_categories(2).unwrap();
+
+    let requests = request.iter().collect::<Vec<_>>();
+    let last = &requests[requests.len() - 3..];
+
+    assert_eq!(last[0].header("If-None-Match"), Some("\"abc\""));
+    assert_eq!(last[1].header("If-None-Match"), None);
+    assert_eq!(last[2].header("If-None-Match"), None);
+}
+
+#[test]
+fn test_get_user_posts() {
+    let (base_url, request) = serve(r#"{
+        "data": [
+            {
+                "id": "1",
+                "type": "posts",
+                "links": {},
+                "attributes": {
+                    "commentsCount": 2,
+                    "content": "Just finished Cowboy Bebop!",
+                    "contentFormatted": "<p>Just finished Cowboy Bebop!</p>",
+                    "createdAt": "2017-05-22T06:00:10.247Z",
+                    "nsfw": false,
+                    "updatedAt": "2017-05-22T06:00:10.247Z"
+                }
+            }
+        ]
+    }"#);
+    let client = KitsuClient::new().with_base_url(&base_url);
+
+    let res = client.get_user_posts(1, |f| f.limit(1)).unwrap();
+    let post = &res.data[0].attributes;
+
+    assert_eq!(request.recv().unwrap().path, "/api/edge/users/1/posts?page[limit]=1");
+    assert_eq!(post.content, "Just finished Cowboy Bebop!");
+    assert_eq!(post.comments_count, 2);
+}
+
+#[test]
+fn test_get_post_comments() {
+    let (base_url, request) = serve(r#"{
+        "data": [
+            {
+                "id": "1",
+                "type": "comments",
+                "links": {},
+                "attributes": {
+                    "content": "See you space cowboy",
+                    "contentFormatted": "<p>See you space cowboy</p>",
+                    "createdAt": "2017-05-22T06:00:10.247Z",
+                    "likesCount": 3,
+                    "repliesCount": 0,
+                    "updatedAt": "2017-05-22T06:00:10.247Z"
+                }
+            }
+        ]
+    }"#);
+    let client = KitsuClient::new().with_base_url(&base_url);
+
+    let res = client.get_post_comments(1, |f| f).unwrap();
+
+    assert_eq!(request.recv().unwrap().path, "/api/edge/posts/1/comments?");
+    assert_eq!(res.data[0].attributes.likes_count, 3);
+}
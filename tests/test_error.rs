@@ -0,0 +1,160 @@
+#![cfg(feature = "reqwest-support")]
+
+extern crate kitsu_io;
+extern crate reqwest;
+extern crate serde_json;
+extern crate url;
+
+use kitsu_io::{ApiError, Error};
+use reqwest::StatusCode;
+use std::error::Error as StdError;
+use std::time::Duration;
+
+fn api_error() -> ApiError {
+    ApiError {
+        code: Some("102".to_owned()),
+        detail: Some("seasonYear is not a valid filter".to_owned()),
+        status: Some("400".to_owned()),
+        title: Some("Filter not allowed".to_owned()),
+    }
+}
+
+#[test]
+fn test_display_api() {
+    let error = Error::Api(vec![api_error(), api_error()], None);
+
+    assert_eq!(
+        error.to_string(),
+        "API error: Filter not allowed: seasonYear is not a valid filter; \
+         Filter not allowed: seasonYear is not a valid filter",
+    );
+}
+
+#[test]
+fn test_display_json() {
+    let inner = serde_json::from_str::<u64>("not json").unwrap_err();
+
+    let error = Error::Json {
+        source: inner,
+        body: "not json".to_owned(),
+        request_id: None,
+    };
+
+    assert!(error.to_string().starts_with("JSON error: "));
+}
+
+#[test]
+fn test_display_invalid_search() {
+    let error = Error::InvalidSearch("mixed pagination".to_owned());
+
+    assert_eq!(error.to_string(), "Invalid search: mixed pagination");
+}
+
+#[test]
+fn test_display_foreign_url() {
+    let error = Error::ForeignUrl("https://example.com/anime/1".to_owned());
+
+    assert_eq!(error.to_string(), "URL is not on the Kitsu API: https://example.com/anime/1");
+}
+
+#[test]
+fn test_display_invalid_encoding() {
+    let inner = String::from_utf8(vec![0xff, 0xfe]).unwrap_err().utf8_error();
+
+    assert!(Error::InvalidEncoding(inner).to_string().contains("UTF-8"));
+}
+
+#[test]
+fn test_display_rate_limited() {
+    let error = Error::RateLimited {
+        retry_after: Some(Duration::from_secs(30)),
+        request_id: None,
+    };
+
+    assert_eq!(error.to_string(), "Rate limited, retry after 30s");
+
+    let error = Error::RateLimited {
+        retry_after: None,
+        request_id: Some("abc".to_owned()),
+    };
+
+    assert_eq!(error.to_string(), "Rate limited (request id abc)");
+}
+
+#[test]
+fn test_display_reqwest() {
+    let inner = reqwest::blocking::get("not a url").unwrap_err();
+
+    assert!(Error::Reqwest(inner).to_string().starts_with("Reqwest error: "));
+}
+
+#[test]
+fn test_display_status() {
+    let id = "abc".to_owned();
+
+    assert!(Error::ReqwestBad(id.clone()).to_string().contains("abc"));
+    assert!(Error::ReqwestInvalid(StatusCode::NOT_FOUND, id.clone()).to_string().contains("abc"));
+    assert!(Error::ReqwestUnauthorized(id).to_string().contains("abc"));
+}
+
+#[test]
+fn test_display_invalid_status() {
+    let error = Error::ReqwestInvalid(StatusCode::SERVICE_UNAVAILABLE, "abc".to_owned());
+
+    assert_eq!(
+        error.to_string(),
+        "Invalid response with status 503 Service Unavailable (request id abc)",
+    );
+}
+
+#[test]
+fn test_status() {
+    let mut not_found = api_error();
+    not_found.status = Some("404".to_owned());
+
+    assert_eq!(Error::Api(vec![not_found], None).status(), Some(404));
+    assert_eq!(Error::Api(vec![api_error()], None).status(), Some(400));
+    assert_eq!(
+        Error::ReqwestInvalid(StatusCode::SERVICE_UNAVAILABLE, "abc".to_owned()).status(),
+        Some(503),
+    );
+    assert_eq!(Error::ReqwestUnauthorized("abc".to_owned()).status(), Some(401));
+    assert_eq!(Error::Timeout.status(), None);
+}
+
+#[test]
+fn test_display_timeout() {
+    assert_eq!(Error::Timeout.to_string(), "Request timed out");
+}
+
+#[test]
+fn test_display_parse_error() {
+    let inner = url::Url::parse("").unwrap_err();
+
+    assert!(Error::ParseError(inner).to_string().starts_with("URL parse error: "));
+}
+
+#[test]
+fn test_source() {
+    let inner = serde_json::from_str::<u64>("not json").unwrap_err();
+    let message = inner.to_string();
+    let error = Error::Json {
+        source: inner,
+        body: "not json".to_owned(),
+        request_id: None,
+    };
+
+    assert_eq!(error.source().unwrap().to_string(), message);
+    assert!(Error::ReqwestBad("abc".to_owned()).source().is_none());
+}
+
+#[test]
+fn test_boxed() {
+    fn fails() -> Result<(), Box<dyn StdError>> {
+        Err(Error::ReqwestInvalid(StatusCode::NOT_FOUND, "abc".to_owned()))?;
+
+        Ok(())
+    }
+
+    assert!(fails().is_err());
+}
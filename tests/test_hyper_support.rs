@@ -1,144 +1,175 @@
 #![cfg(feature = "hyper-support")]
 
-extern crate futures;
 extern crate hyper;
 extern crate hyper_tls;
 extern crate kitsu_io;
-extern crate tokio_core;
 
-use futures::Future;
-use futures::stream::Stream;
-use hyper::Client;
+use hyper::client::HttpConnector;
+use hyper::{Body, Client, StatusCode};
 use hyper_tls::HttpsConnector;
-use kitsu_io::KitsuHyperRequester;
+use kitsu_io::{Error, KitsuHyperRequester};
 use std::io::{self, Write};
-use tokio_core::reactor::Core;
 
-#[ignore]
+fn client() -> Client<HttpsConnector<HttpConnector>, Body> {
+    Client::builder().build(HttpsConnector::new())
+}
+
 #[test]
-fn test_get_anime() {
-    let mut core = Core::new().unwrap();
+fn test_invalid_status() {
+    let error = Error::HyperInvalid(StatusCode::SERVICE_UNAVAILABLE);
 
-    let connector = HttpsConnector::new(1, &core.handle()).unwrap();
-    let client = Client::configure()
-        .connector(connector)
-        .build(&core.handle());
+    assert_eq!(error.status(), Some(503));
+}
 
-    let runner = client.get_anime(1).unwrap().and_then(|res| {
-        res.body().for_each(|chunk| {
-            io::stdout().write_all(&chunk).map_err(From::from)
-        })
-    }).map(|_| {
-        println!("Done")
-    });
+#[ignore]
+#[tokio::test]
+async fn test_get_anime() {
+    let client = client();
+    let res = client.get_anime(1).unwrap().await.unwrap();
+    let body = hyper::body::to_bytes(res.into_body()).await.unwrap();
 
-    core.run(runner).unwrap();
+    io::stdout().write_all(&body).unwrap();
 }
 
 #[ignore]
-#[test]
-fn test_get_manga() {
-    let mut core = Core::new().unwrap();
+#[tokio::test]
+async fn test_get_manga() {
+    let client = client();
+    let res = client.get_manga(1).unwrap().await.unwrap();
+    let body = hyper::body::to_bytes(res.into_body()).await.unwrap();
 
-    let connector = HttpsConnector::new(1, &core.handle()).unwrap();
-    let client = Client::configure()
-        .connector(connector)
-        .build(&core.handle());
+    io::stdout().write_all(&body).unwrap();
+}
 
-    let runner = client.get_manga(1).unwrap().and_then(|res| {
-        res.body().for_each(|chunk| {
-            io::stdout().write_all(&chunk).map_err(From::from)
-        })
-    }).map(|_| {
-        println!("Done")
-    });
+#[ignore]
+#[tokio::test]
+async fn test_get_user() {
+    let client = client();
+    let res = client.get_user(1).unwrap().await.unwrap();
+    let body = hyper::body::to_bytes(res.into_body()).await.unwrap();
 
-    core.run(runner).unwrap();
+    io::stdout().write_all(&body).unwrap();
 }
 
 #[ignore]
-#[test]
-fn test_get_user() {
-    let mut core = Core::new().unwrap();
+#[tokio::test]
+async fn test_search_anime() {
+    let client = client();
+    let res = client.search_anime(|f| f.filter("text", "non non biyori")).unwrap().await.unwrap();
+    let body = hyper::body::to_bytes(res.into_body()).await.unwrap();
 
-    let connector = HttpsConnector::new(1, &core.handle()).unwrap();
-    let client = Client::configure()
-        .connector(connector)
-        .build(&core.handle());
+    io::stdout().write_all(&body).unwrap();
+}
 
-    let runner = client.get_user(1).unwrap().and_then(|res| {
-        res.body().for_each(|chunk| {
-            io::stdout().write_all(&chunk).map_err(From::from)
-        })
-    }).map(|_| {
-        println!("Done")
-    });
+#[ignore]
+#[tokio::test]
+async fn test_search_manga() {
+    let client = client();
+    let res = client.search_manga(|f| f.filter("text", "orange")).unwrap().await.unwrap();
+    let body = hyper::body::to_bytes(res.into_body()).await.unwrap();
 
-    core.run(runner).unwrap();
+    io::stdout().write_all(&body).unwrap();
 }
 
 #[ignore]
-#[test]
-fn test_search_anime() {
-    let mut core = Core::new().unwrap();
+#[tokio::test]
+async fn test_search_users() {
+    let client = client();
+    let res = client.search_users(|f| f.filter("name", "vikhyat")).unwrap().await.unwrap();
+    let body = hyper::body::to_bytes(res.into_body()).await.unwrap();
 
-    let connector = HttpsConnector::new(1, &core.handle()).unwrap();
-    let client = Client::configure()
-        .connector(connector)
-        .build(&core.handle());
+    io::stdout().write_all(&body).unwrap();
+}
 
-    let runner = client.search_anime(|f| f.filter("text", "non non biyori"))
-        .unwrap().and_then(|res| {
-            res.body().for_each(|chunk| {
-            io::stdout().write_all(&chunk).map_err(From::from)
-            })
-        }).map(|_| {
-            println!("Done")
-        });
+#[ignore]
+#[tokio::test]
+async fn test_get_anime_episodes() {
+    let client = client();
+    let res = client.get_anime_episodes(1).unwrap().await.unwrap();
+    let body = hyper::body::to_bytes(res.into_body()).await.unwrap();
 
-    core.run(runner).unwrap();
+    io::stdout().write_all(&body).unwrap();
 }
 
 #[ignore]
-#[test]
-fn test_search_manga() {
-    let mut core = Core::new().unwrap();
+#[tokio::test]
+async fn test_get_manga_chapters() {
+    let client = client();
+    let res = client.get_manga_chapters(1).unwrap().await.unwrap();
+    let body = hyper::body::to_bytes(res.into_body()).await.unwrap();
 
-    let connector = HttpsConnector::new(1, &core.handle()).unwrap();
-    let client = Client::configure()
-        .connector(connector)
-        .build(&core.handle());
+    io::stdout().write_all(&body).unwrap();
+}
 
-    let runner = client.search_manga(|f| f.filter("text", "orange"))
-        .unwrap().and_then(|res| {
-            res.body().for_each(|chunk| {
-            io::stdout().write_all(&chunk).map_err(From::from)
-            })
-        }).map(|_| {
-            println!("Done")
-        });
+#[ignore]
+#[tokio::test]
+async fn test_get_anime_streaming_links() {
+    let client = client();
+    let res = client.get_anime_streaming_links(1).unwrap().await.unwrap();
+    let body = hyper::body::to_bytes(res.into_body()).await.unwrap();
 
-    core.run(runner).unwrap();
+    io::stdout().write_all(&body).unwrap();
 }
 
 #[ignore]
-#[test]
-fn test_search_users() {
-    let mut core = Core::new().unwrap();
-
-    let connector = HttpsConnector::new(1, &core.handle()).unwrap();
-    let client = Client::configure()
-        .connector(connector)
-        .build(&core.handle());
-
-    let runner = client.search_users(|f| f.filter("name", "vikhyat"))
-        .unwrap().and_then(|res| {
-            res.body().for_each(|chunk| {
-            io::stdout().write_all(&chunk).map_err(From::from)
-            })
-        }).map(|_| {
-            println!("Done")
-        });
-
-    core.run(runner).unwrap();
+#[tokio::test]
+async fn test_get_anime_categories() {
+    let client = client();
+    let res = client.get_anime_categories(1).unwrap().await.unwrap();
+    let body = hyper::body::to_bytes(res.into_body()).await.unwrap();
+
+    io::stdout().write_all(&body).unwrap();
+}
+
+#[ignore]
+#[tokio::test]
+async fn test_get_categories() {
+    let client = client();
+    let res = client.get_categories(|f| f.filter("slug", "space")).unwrap().await.unwrap();
+    let body = hyper::body::to_bytes(res.into_body()).await.unwrap();
+
+    io::stdout().write_all(&body).unwrap();
+}
+
+#[ignore]
+#[tokio::test]
+async fn test_get_trending_anime() {
+    let client = client();
+    let res = client.get_trending_anime().unwrap().await.unwrap();
+    let body = hyper::body::to_bytes(res.into_body()).await.unwrap();
+
+    io::stdout().write_all(&body).unwrap();
+}
+
+#[ignore]
+#[tokio::test]
+async fn test_get_trending_manga() {
+    let client = client();
+    let res = client.get_trending_manga().unwrap().await.unwrap();
+    let body = hyper::body::to_bytes(res.into_body()).await.unwrap();
+
+    io::stdout().write_all(&body).unwrap();
+}
+
+#[ignore]
+#[tokio::test]
+async fn test_get_anime_parsed() {
+    let client = client();
+    let anime = client.get_anime_parsed(1).await.unwrap();
+
+    assert_eq!(anime.data.id, "1");
+}
+
+#[tokio::test]
+async fn test_search_rejects_mixed_pagination() {
+    let client = client();
+
+    assert!(matches!(
+        client.search_anime(|f| f.offset(10).page_number(2)),
+        Err(Error::InvalidSearch(_))
+    ));
+    assert!(matches!(
+        client.search_users_parsed(|f| f.limit(10).page_size(10)).await,
+        Err(Error::InvalidSearch(_))
+    ));
 }
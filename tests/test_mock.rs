@@ -0,0 +1,174 @@
+#![cfg(feature = "mock")]
+
+extern crate kitsu_io;
+extern crate reqwest;
+extern crate serde_json;
+
+use kitsu_io::builder::Search;
+use kitsu_io::mock::MockRequester;
+use kitsu_io::model::{Category, Response};
+use kitsu_io::{Error, KitsuReqwestRequester};
+use reqwest::StatusCode;
+
+const CATEGORIES: &str = r#"{
+    "data": [
+        {
+            "id": "1",
+            "type": "categories",
+            "links": {},
+            "attributes": {
+                "childCount": 0,
+                "description": null,
+                "nsfw": false,
+                "slug": "space",
+                "title": "Space"
+            }
+        }
+    ]
+}"#;
+
+fn anime_page(ids: &[u64]) -> String {
+    let link = r#"{"links": {"self": "", "related": ""}}"#;
+    let data = ids.iter().map(|id| format!(r#"{{
+        "id": "{id}",
+        "type": "anime",
+        "links": {{}},
+        "attributes": {{
+            "canonicalTitle": "Anime {id}",
+            "nsfw": false,
+            "posterImage": {{"original": ""}},
+            "ratingFrequencies": {{}},
+            "showType": "TV",
+            "slug": "anime-{id}",
+            "synopsis": "",
+            "titles": {{}}
+        }},
+        "relationships": {{"episodes": {link}, "genres": {link}}}
+    }}"#, id = id, link = link)).collect::<Vec<_>>();
+
+    format!(r#"{{"data": [{}]}}"#, data.join(","))
+}
+
+#[test]
+fn test_mock_respond() {
+    let mock = MockRequester::new();
+    mock.respond("/categories", CATEGORIES);
+
+    let res = mock.get_categories(|f| f.limit(1)).unwrap();
+
+    assert_eq!(res.data[0].attributes.slug, "space");
+    assert_eq!(mock.requests(), vec!["/categories?page[limit]=1"]);
+}
+
+#[test]
+fn test_mock_respond_value() {
+    let categories: Response<Vec<Category>> = serde_json::from_str(CATEGORIES).unwrap();
+    let mock = MockRequester::new();
+    mock.respond_value("/anime/1/categories", &categories);
+
+    let res = mock.get_anime_categories(1).unwrap();
+
+    assert_eq!(res.data.len(), 1);
+    assert_eq!(res.data[0].attributes.title, "Space");
+}
+
+#[test]
+fn test_mock_exact_query_preferred() {
+    let mock = MockRequester::new();
+    mock.respond("/categories", r#"{"data": []}"#)
+        .respond("/categories?page[limit]=1", CATEGORIES);
+
+    assert_eq!(mock.get_categories(|f| f.limit(1)).unwrap().data.len(), 1);
+    assert!(mock.get_categories(|f| f.limit(2)).unwrap().data.is_empty());
+}
+
+#[test]
+fn test_mock_not_found() {
+    let mock = MockRequester::new();
+
+    assert!(!mock.anime_exists(1).unwrap());
+    assert_eq!(mock.requests(), vec!["/anime/1"]);
+}
+
+#[test]
+fn test_mock_status() {
+    let mock = MockRequester::new();
+    mock.respond_with_status("/categories", StatusCode::UNAUTHORIZED, "");
+
+    match mock.get_categories(|f| f) {
+        Err(Error::ReqwestUnauthorized(_)) => {},
+        other => panic!("Expected an unauthorized error, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_mock_reset() {
+    let mock = MockRequester::new();
+    mock.respond("/categories", CATEGORIES);
+    mock.get_categories(|f| f).unwrap();
+    mock.reset();
+
+    assert!(mock.requests().is_empty());
+    assert!(mock.get_categories(|f| f).is_err());
+}
+
+#[test]
+fn test_mock_trait_object() {
+    let mock = MockRequester::new();
+    mock.respond("/anime", r#"{"data": []}"#);
+
+    let requester: Box<dyn KitsuReqwestRequester> = Box::new(mock);
+    let res = requester.search_anime_with(Search::new().limit(1)).unwrap();
+
+    assert!(res.data.is_empty());
+    assert!(requester.anime_exists(1).is_ok());
+}
+
+#[test]
+fn test_mock_search_anime_fuzzy_merges() {
+    let mock = MockRequester::new();
+    mock.respond("/anime?filter[text]=bebop", &anime_page(&[1, 2]))
+        .respond("/anime?filter[canonicalTitle]=bebop", &anime_page(&[2, 3]));
+
+    let anime = mock.search_anime_fuzzy("bebop").unwrap();
+    let ids = anime.iter().map(|anime| anime.id.as_str()).collect::<Vec<_>>();
+
+    assert_eq!(ids, vec!["1", "2", "3"]);
+    assert_eq!(mock.requests().len(), 2);
+}
+
+#[test]
+fn test_mock_search_anime_all_foreign_next() {
+    let mock = MockRequester::new();
+    let page = anime_page(&[1]).replacen(
+        r#"{"data""#,
+        r#"{"links": {"next": "https://example.com/api/edge/anime?page[offset]=1"}, "data""#,
+        1,
+    );
+    mock.respond("/anime", &page);
+
+    let mut anime = mock.search_anime_all(|f| f);
+
+    assert_eq!(anime.next().unwrap().unwrap().id, "1");
+
+    match anime.next() {
+        Some(Err(Error::ForeignUrl(url))) => assert!(url.starts_with("https://example.com/")),
+        other => panic!("Expected a foreign URL error, got {:?}", other.map(|res| res.map(|a| a.id))),
+    }
+
+    assert!(anime.next().is_none());
+    assert_eq!(mock.requests().len(), 1);
+}
+
+#[test]
+fn test_mock_get_anime_many_orders_by_ids() {
+    let mock = MockRequester::new();
+    mock.respond("/anime?filter[id]=3,1,2&page[limit]=3", &anime_page(&[1, 2, 3]));
+
+    let anime = mock.get_anime_many(&[3, 1, 3, 2]).unwrap();
+    let ids = anime.data.iter().map(|anime| anime.id.as_str()).collect::<Vec<_>>();
+
+    assert_eq!(ids, vec!["3", "1", "2"]);
+    assert!(anime.links.is_empty());
+    assert_eq!(mock.requests(), vec!["/anime?filter[id]=3,1,2&page[limit]=3"]);
+}
@@ -0,0 +1,694 @@
+#![cfg(feature = "serde-items")]
+
+#[cfg(feature = "chrono")]
+extern crate chrono;
+extern crate kitsu_io;
+extern crate serde_json;
+
+use kitsu_io::model::{AgeRating, AiringStatusKind, Anime, AnimeProduction, AnimeProductionRole, AnimeSubType, AnimeType, CoverImage, ExternalSite, ImageSize, Included, Installment, LibraryEntry, LibraryEntryStatus, LinkKind, Manga, Mapping, MangaType, MediaId, MediaRelationship, MediaRelationshipRole, Response, Type, WaifuOrHusbando};
+use serde_json::Value;
+use std::collections::HashSet;
+
+const ANIME: &str = r#"{
+    "data": {
+        "id": "1",
+        "type": "anime",
+        "links": {
+            "self": "https://kitsu.io/api/edge/anime/1"
+        },
+        "attributes": {
+            "abbreviatedTitles": ["COWBOY BEBOP"],
+            "ageRating": "R",
+            "ageRatingGuide": "17+ (violence & profanity)",
+            "averageRating": "82.64",
+            "canonicalTitle": "Cowboy Bebop",
+            "coverImage": {
+                "large": "https://media.kitsu.io/anime/cover_images/1/large.jpg",
+                "original": "https://media.kitsu.io/anime/cover_images/1/original.jpg",
+                "small": "https://media.kitsu.io/anime/cover_images/1/small.jpg"
+            },
+            "coverImageTopOffset": 400,
+            "createdAt": "2013-02-20T16:00:13.609Z",
+            "endDate": "1999-04-24",
+            "episodeCount": 26,
+            "episodeLength": 25,
+            "favouritesCount": 3953,
+            "showType": "TV",
+            "nsfw": false,
+            "popularityRank": 28,
+            "posterImage": {
+                "large": "https://media.kitsu.io/anime/poster_images/1/large.jpg",
+                "medium": "https://media.kitsu.io/anime/poster_images/1/medium.jpg",
+                "original": "https://media.kitsu.io/anime/poster_images/1/original.jpg",
+                "small": "https://media.kitsu.io/anime/poster_images/1/small.jpg",
+                "tiny": "https://media.kitsu.io/anime/poster_images/1/tiny.jpg"
+            },
+            "ratingFrequencies": {
+                "0.0": 0,
+                "0.5": 11,
+                "1.0": 50,
+                "1.5": 6,
+                "2.0": 156,
+                "2.5": 33,
+                "3.0": 439,
+                "3.5": 178,
+                "4.0": 1261,
+                "4.5": 638,
+                "5.0": 4928
+            },
+            "ratingRank": 26,
+            "slug": "cowboy-bebop",
+            "startDate": "1998-04-03",
+            "status": "finished",
+            "subType": "TV",
+            "synopsis": "In the year 2071, humanity has colonized several of the planets and moons of the solar system.",
+            "tba": null,
+            "titles": {
+                "en": "Cowboy Bebop",
+                "en_jp": "Cowboy Bebop",
+                "ja_jp": "カウボーイビバップ"
+            },
+            "updatedAt": "2017-05-22T06:00:10.247Z",
+            "userCount": 76425,
+            "youtubeVideoId": "qig4KOK2R2g"
+        },
+        "relationships": {
+            "castings": {
+                "links": {
+                    "related": "https://kitsu.io/api/edge/anime/1/castings",
+                    "self": "https://kitsu.io/api/edge/anime/1/relationships/castings"
+                }
+            },
+            "episodes": {
+                "links": {
+                    "related": "https://kitsu.io/api/edge/anime/1/episodes",
+                    "self": "https://kitsu.io/api/edge/anime/1/relationships/episodes"
+                }
+            },
+            "genres": {
+                "links": {
+                    "related": "https://kitsu.io/api/edge/anime/1/genres",
+                    "self": "https://kitsu.io/api/edge/anime/1/relationships/genres"
+                }
+            },
+            "installments": {
+                "links": {
+                    "related": "https://kitsu.io/api/edge/anime/1/installments",
+                    "self": "https://kitsu.io/api/edge/anime/1/relationships/installments"
+                }
+            },
+            "mappings": {
+                "links": {
+                    "related": "https://kitsu.io/api/edge/anime/1/mappings",
+                    "self": "https://kitsu.io/api/edge/anime/1/relationships/mappings"
+                }
+            },
+            "reviews": {
+                "links": {
+                    "related": "https://kitsu.io/api/edge/anime/1/reviews",
+                    "self": "https://kitsu.io/api/edge/anime/1/relationships/reviews"
+                }
+            },
+            "streamingLinks": {
+                "links": {
+                    "related": "https://kitsu.io/api/edge/anime/1/streaming-links",
+                    "self": "https://kitsu.io/api/edge/anime/1/relationships/streaming-links"
+                }
+            }
+        }
+    },
+    "included": [
+        {
+            "id": "1",
+            "type": "categories",
+            "links": {
+                "self": "https://kitsu.io/api/edge/categories/1"
+            },
+            "attributes": {
+                "childCount": 0,
+                "description": "Anime set in outer space.",
+                "nsfw": false,
+                "slug": "space",
+                "title": "Space"
+            }
+        },
+        {
+            "id": "1",
+            "type": "quotes"
+        }
+    ],
+    "links": {},
+    "meta": {
+        "count": null
+    }
+}"#;
+
+#[test]
+fn test_anime_round_trip() {
+    let response: Response<Anime> = serde_json::from_str(ANIME).unwrap();
+
+    let expected: Value = serde_json::from_str(ANIME).unwrap();
+    let serialized = serde_json::to_value(&response).unwrap();
+
+    assert_eq!(serialized, expected);
+}
+
+#[test]
+fn test_anime_serializes_camel_case() {
+    let response: Response<Anime> = serde_json::from_str(ANIME).unwrap();
+    let serialized = serde_json::to_value(&response.data).unwrap();
+    let attributes = &serialized["attributes"];
+
+    assert_eq!(attributes["canonicalTitle"], "Cowboy Bebop");
+    assert_eq!(attributes["showType"], "TV");
+    assert!(attributes.get("canonical_title").is_none());
+    assert_eq!(serialized["relationships"]["streamingLinks"]["links"]["self"],
+               "https://kitsu.io/api/edge/anime/1/relationships/streaming-links");
+}
+
+#[test]
+fn test_included_category() {
+    let response: Response<Anime> = serde_json::from_str(ANIME).unwrap();
+
+    match response.included[0] {
+        Included::Category(ref category) => {
+            assert_eq!(category.attributes.slug, "space");
+        },
+        ref other => panic!("Expected a category, got {:?}", other),
+    }
+
+    assert!(matches!(response.included[1], Included::Other(_)));
+}
+
+#[test]
+fn test_unknown_enum_values() {
+    let json = ANIME
+        .replace(r#""ageRating": "R""#, r#""ageRating": "NC-17""#)
+        .replace(r#""showType": "TV""#, r#""showType": "hologram""#)
+        .replace(r#""type": "anime""#, r#""type": "dramas""#);
+    let response: Response<Anime> = serde_json::from_str(&json).unwrap();
+    let attributes = &response.data.attributes;
+
+    assert_eq!(attributes.age_rating, Some(AgeRating::Unknown));
+    assert_eq!(attributes.kind, AnimeType::Unknown);
+    assert_eq!(response.data.kind, Type::Unknown);
+    assert_eq!(serde_json::from_str::<MangaType>(r#""webtoon""#).unwrap(), MangaType::Unknown);
+}
+
+#[test]
+fn test_average_rating_f64() {
+    let response: Response<Anime> = serde_json::from_str(ANIME).unwrap();
+    let mut attributes = response.data.attributes;
+
+    assert_eq!(attributes.average_rating_f64(), Some(82.64));
+
+    attributes.average_rating = Some("not a rating".to_owned());
+    assert_eq!(attributes.average_rating_f64(), None);
+
+    attributes.average_rating = None;
+    assert_eq!(attributes.average_rating_f64(), None);
+}
+
+#[test]
+fn test_url_for_size() {
+    let response: Response<Anime> = serde_json::from_str(ANIME).unwrap();
+    let attributes = &response.data.attributes;
+
+    assert_eq!(
+        attributes.poster_image.url_for_size(ImageSize::Medium).unwrap(),
+        "https://media.kitsu.io/anime/poster_images/1/medium.jpg",
+    );
+
+    let cover = attributes.cover_image.as_ref().unwrap();
+
+    assert_eq!(
+        cover.url_for_size(ImageSize::Small).unwrap(),
+        "https://media.kitsu.io/anime/cover_images/1/small.jpg",
+    );
+    assert!(cover.url_for_size(ImageSize::Tiny).is_none());
+}
+
+#[test]
+fn test_url_for_size_template() {
+    let url = "https://media.kitsu.io/anime/cover_images/1/$1.jpg";
+    let cover: CoverImage = serde_json::from_str(&format!("\"{}\"", url)).unwrap();
+
+    assert_eq!(
+        cover.url_for_size(ImageSize::Tiny).unwrap(),
+        "https://media.kitsu.io/anime/cover_images/1/tiny.jpg",
+    );
+    assert_eq!(
+        cover.url_for_size(ImageSize::Original).unwrap(),
+        "https://media.kitsu.io/anime/cover_images/1/original.jpg",
+    );
+}
+
+#[test]
+fn test_library_entry_status() {
+    let json = r#"{
+        "id": "1",
+        "type": "libraryEntries",
+        "links": {},
+        "attributes": {
+            "finishedAt": null,
+            "notes": null,
+            "private": false,
+            "progress": 12,
+            "rating": "4.0",
+            "reconsumeCount": 0,
+            "startedAt": "2017-04-03T06:00:10.247Z",
+            "status": "on_hold"
+        }
+    }"#;
+    let entry: LibraryEntry = serde_json::from_str(json).unwrap();
+
+    assert_eq!(entry.kind, Type::LibraryEntries);
+    assert_eq!(entry.attributes.status, LibraryEntryStatus::OnHold);
+}
+
+#[test]
+fn test_enum_display_matches_serde() {
+    for rating in &[AgeRating::PG13, AgeRating::R17Plus, AgeRating::TvY7] {
+        let name = rating.to_string();
+
+        assert_eq!(serde_json::to_string(rating).unwrap(), format!("\"{}\"", name));
+        assert_eq!(name.parse::<AgeRating>().unwrap(), *rating);
+    }
+
+    for kind in &[Type::Anime, Type::LibraryEntries, Type::StreamingLinks] {
+        let name = kind.to_string();
+
+        assert_eq!(serde_json::to_string(kind).unwrap(), format!("\"{}\"", name));
+        assert_eq!(name.parse::<Type>().unwrap(), *kind);
+    }
+
+    assert_eq!("ONA".parse::<AnimeType>().unwrap(), AnimeType::ONA);
+    assert_eq!("oneshot".parse::<MangaType>().unwrap(), MangaType::Oneshot);
+    assert_eq!("Waifu".parse::<WaifuOrHusbando>().unwrap(), WaifuOrHusbando::Waifu);
+}
+
+#[test]
+fn test_enum_unknown_round_trips() {
+    assert_eq!(AgeRating::Unknown.to_string(), "unknown");
+    assert_eq!(AnimeType::Unknown.to_string(), "unknown");
+    assert_eq!(MangaType::Unknown.to_string(), "unknown");
+    assert_eq!(Type::Unknown.to_string(), "unknown");
+
+    assert_eq!("unknown".parse::<AgeRating>().unwrap(), AgeRating::Unknown);
+    assert_eq!("unknown".parse::<AnimeType>().unwrap(), AnimeType::Unknown);
+    assert_eq!("unknown".parse::<MangaType>().unwrap(), MangaType::Unknown);
+    assert_eq!("unknown".parse::<Type>().unwrap(), Type::Unknown);
+
+    assert_eq!(serde_json::to_string(&AgeRating::Unknown).unwrap(), r#""unknown""#);
+    assert_eq!(serde_json::to_string(&AnimeType::Unknown).unwrap(), r#""unknown""#);
+}
+
+#[test]
+fn test_enum_from_str_invalid() {
+    assert!("PG-21".parse::<AgeRating>().is_err());
+    assert!("Unknown".parse::<AgeRating>().is_err());
+    assert!("tv".parse::<AnimeType>().is_err());
+}
+
+#[test]
+fn test_mapping_external_site() {
+    let json = r#"{
+        "id": "1",
+        "type": "mappings",
+        "links": {},
+        "attributes": {
+            "externalId": "1",
+            "externalSite": "myanimelist/anime"
+        }
+    }"#;
+    let mapping: Mapping = serde_json::from_str(json).unwrap();
+
+    assert_eq!(mapping.kind, Type::Mappings);
+    assert_eq!(mapping.attributes.external_id, "1");
+    assert_eq!(mapping.attributes.external_site, ExternalSite::MyAnimeListAnime);
+
+    let json = json.replace("myanimelist/anime", "kitsu/anime");
+    let mapping: Mapping = serde_json::from_str(&json).unwrap();
+
+    assert_eq!(mapping.attributes.external_site, ExternalSite::Unknown);
+}
+
+#[test]
+fn test_pagination_links() {
+    let json = ANIME.replace(r#""links": {},"#, r#""links": {
+        "first": "https://kitsu.io/api/edge/anime?page[offset]=0",
+        "next": "https://kitsu.io/api/edge/anime?page[offset]=10",
+        "last": "https://kitsu.io/api/edge/anime?page[offset]=90"
+    },"#);
+    let response: Response<Anime> = serde_json::from_str(&json).unwrap();
+
+    assert_eq!(response.first_page(), Some("https://kitsu.io/api/edge/anime?page[offset]=0"));
+    assert_eq!(response.next_page(), Some("https://kitsu.io/api/edge/anime?page[offset]=10"));
+    assert_eq!(response.last_page(), Some("https://kitsu.io/api/edge/anime?page[offset]=90"));
+    assert_eq!(response.prev_page(), None);
+}
+
+#[test]
+fn test_media_relationship_role() {
+    let json = r#"{
+        "id": "1",
+        "type": "mediaRelationships",
+        "links": {},
+        "attributes": {
+            "role": "side_story"
+        }
+    }"#;
+    let relationship: MediaRelationship = serde_json::from_str(json).unwrap();
+
+    assert_eq!(relationship.kind, Type::MediaRelationships);
+    assert_eq!(relationship.attributes.role, MediaRelationshipRole::SideStory);
+
+    let json = json.replace("side_story", "remake");
+    let relationship: MediaRelationship = serde_json::from_str(&json).unwrap();
+
+    assert_eq!(relationship.attributes.role, MediaRelationshipRole::Unknown);
+}
+
+#[test]
+fn test_missing_relationships() {
+    let mut value: Value = serde_json::from_str(ANIME).unwrap();
+    let relationships = value["data"]["relationships"].as_object_mut().unwrap();
+    relationships.remove("castings");
+    relationships.remove("streamingLinks");
+
+    let response: Response<Anime> = serde_json::from_value(value).unwrap();
+    let relationships = &response.data.relationships;
+
+    assert!(relationships.castings.is_none());
+    assert!(relationships.streaming_links.is_none());
+    assert!(relationships.mappings.is_some());
+}
+
+#[test]
+fn test_relationship_links() {
+    let response = serde_json::from_str::<Response<Anime>>(ANIME).unwrap();
+    let genres = &response.data.relationships.genres;
+
+    assert_eq!(genres.related_url(), "https://kitsu.io/api/edge/anime/1/genres");
+    assert_eq!(LinkKind::from_url(genres.related_url()), LinkKind::Related);
+    assert_eq!(LinkKind::from_url(genres.self_url()), LinkKind::Own);
+    assert_eq!(LinkKind::Own.name(), "self");
+}
+
+#[test]
+fn test_manga_nsfw_and_ranks() {
+    let json = r#"{
+        "id": "1",
+        "type": "manga",
+        "links": {
+            "self": "https://kitsu.io/api/edge/manga/1"
+        },
+        "attributes": {
+            "abbreviatedTitles": null,
+            "averageRating": "80.92",
+            "canonicalTitle": "Monster",
+            "chapterCount": 162,
+            "coverImage": null,
+            "coverImageTopOffset": 0,
+            "createdAt": "2013-12-18T13:48:28.504Z",
+            "endDate": "2001-12-20",
+            "mangaType": "manga",
+            "nsfw": true,
+            "popularityRank": 194,
+            "posterImage": {
+                "original": "https://media.kitsu.io/manga/poster_images/1/original.jpg"
+            },
+            "ratingFrequencies": {},
+            "ratingRank": 12,
+            "serialization": "Big Comic Original",
+            "slug": "monster",
+            "startDate": "1994-12-05",
+            "synopsis": "Kenzou Tenma, a renowned Japanese neurosurgeon working in post-war Germany.",
+            "titles": {
+                "en": "Monster",
+                "en_jp": "Monster"
+            },
+            "updatedAt": "2017-05-22T06:00:10.247Z",
+            "volumeCount": 18,
+            "youtubeVideoId": null
+        }
+    }"#;
+    let manga: Manga = serde_json::from_str(json).unwrap();
+    let attributes = &manga.attributes;
+
+    assert!(attributes.nsfw);
+    assert_eq!(attributes.popularity_rank, Some(194));
+    assert_eq!(attributes.rating_rank, Some(12));
+
+    let mut value: Value = serde_json::from_str(json).unwrap();
+    value["attributes"].as_object_mut().unwrap().remove("nsfw");
+    let manga: Manga = serde_json::from_value(value).unwrap();
+
+    assert!(!manga.attributes.nsfw);
+}
+
+#[test]
+fn test_included_franchise() {
+    let json = r#"{
+        "data": [
+            {
+                "id": "1",
+                "type": "installments",
+                "links": {},
+                "attributes": {
+                    "position": 2,
+                    "tag": null
+                }
+            }
+        ],
+        "included": [
+            {
+                "id": "1",
+                "type": "franchises",
+                "links": {},
+                "attributes": {
+                    "canonicalTitle": "Fate",
+                    "slug": "fate",
+                    "titles": {
+                        "en": "Fate"
+                    }
+                }
+            }
+        ]
+    }"#;
+    let response: Response<Vec<Installment>> = serde_json::from_str(json).unwrap();
+
+    assert_eq!(response.data[0].kind, Type::Installments);
+    assert_eq!(response.data[0].attributes.position, 2);
+
+    match response.included[0] {
+        Included::Franchise(ref franchise) => assert_eq!(franchise.attributes.slug, "fate"),
+        ref other => panic!("Expected a franchise, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_abbreviated_titles_shapes() {
+    let response: Response<Anime> = serde_json::from_str(ANIME).unwrap();
+
+    assert_eq!(response.data.attributes.abbreviated_titles, Some(vec!["COWBOY BEBOP".to_owned()]));
+
+    let json = ANIME.replace(r#"["COWBOY BEBOP"]"#, r#""COWBOY BEBOP""#);
+    let response: Response<Anime> = serde_json::from_str(&json).unwrap();
+
+    assert_eq!(response.data.attributes.abbreviated_titles, Some(vec!["COWBOY BEBOP".to_owned()]));
+
+    let json = ANIME.replace(r#"["COWBOY BEBOP"]"#, "null");
+    let response: Response<Anime> = serde_json::from_str(&json).unwrap();
+
+    assert_eq!(response.data.attributes.abbreviated_titles, None);
+
+    let mut value: Value = serde_json::from_str(ANIME).unwrap();
+    value["data"]["attributes"].as_object_mut().unwrap().remove("abbreviatedTitles");
+    let response: Response<Anime> = serde_json::from_value(value).unwrap();
+
+    assert_eq!(response.data.attributes.abbreviated_titles, None);
+}
+
+#[test]
+fn test_preferred_english_title() {
+    let response: Response<Anime> = serde_json::from_str(ANIME).unwrap();
+    let mut titles = response.data.attributes.titles;
+
+    assert_eq!(titles.preferred_english(), Some("Cowboy Bebop"));
+
+    titles.en = None;
+    titles.en_jp = None;
+    assert_eq!(titles.preferred_english(), Some("カウボーイビバップ"));
+
+    titles.ja_jp = None;
+    assert_eq!(titles.preferred_english(), None);
+}
+
+#[test]
+fn test_included_producer() {
+    let json = r#"{
+        "data": [
+            {
+                "id": "1",
+                "type": "animeProductions",
+                "links": {},
+                "attributes": {
+                    "role": "studio"
+                }
+            }
+        ],
+        "included": [
+            {
+                "id": "1",
+                "type": "producers",
+                "links": {},
+                "attributes": {
+                    "name": "Sunrise",
+                    "slug": "sunrise"
+                }
+            }
+        ]
+    }"#;
+    let response: Response<Vec<AnimeProduction>> = serde_json::from_str(json).unwrap();
+
+    assert_eq!(response.data[0].kind, Type::AnimeProductions);
+    assert_eq!(response.data[0].attributes.role, AnimeProductionRole::Studio);
+
+    match response.included[0] {
+        Included::Producer(ref producer) => assert_eq!(producer.attributes.name, "Sunrise"),
+        ref other => panic!("Expected a producer, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_id_string_or_number() {
+    let response: Response<Anime> = serde_json::from_str(ANIME).unwrap();
+
+    assert_eq!(response.data.id, "1");
+
+    let json = ANIME.replacen(r#""id": "1""#, r#""id": 1"#, 1);
+    let response: Response<Anime> = serde_json::from_str(&json).unwrap();
+
+    assert_eq!(response.data.id, "1");
+
+    let serialized = serde_json::to_value(&response.data).unwrap();
+
+    assert_eq!(serialized["id"], "1");
+}
+
+#[test]
+fn test_anime_sub_type_typed() {
+    let response: Response<Anime> = serde_json::from_str(ANIME).unwrap();
+    let mut attributes = response.data.attributes;
+
+    assert_eq!(attributes.sub_type_typed(), Some(AnimeSubType::TV));
+
+    attributes.sub_type = Some("CM".to_owned());
+    assert_eq!(attributes.sub_type_typed(), Some(AnimeSubType::Unknown("CM".to_owned())));
+    assert_eq!(attributes.sub_type_typed().unwrap().to_string(), "CM");
+
+    attributes.sub_type = None;
+    assert_eq!(attributes.sub_type_typed(), None);
+}
+
+#[test]
+fn test_anime_media_id() {
+    let response: Response<Anime> = serde_json::from_str(ANIME).unwrap();
+    let mut cache = HashSet::new();
+
+    assert_eq!(response.data.media_id(), MediaId::new(Type::Anime, "1"));
+    assert!(cache.insert(response.data.media_id()));
+    assert!(!cache.insert(response.data.clone().media_id()));
+}
+
+#[test]
+fn test_anime_status() {
+    let response: Response<Anime> = serde_json::from_str(ANIME).unwrap();
+    let mut attributes = response.data.attributes;
+
+    assert_eq!(attributes.status, Some(AiringStatusKind::Finished));
+    assert_eq!(attributes.airing_status().name(), "finished");
+
+    attributes.status = Some(AiringStatusKind::Upcoming);
+    attributes.end_date = None;
+    attributes.tba = Some("Fall 2017".to_owned());
+    assert_eq!(attributes.airing_status().name(), "upcoming");
+
+    attributes.status = None;
+    assert_eq!(attributes.airing_status().name(), "airing");
+
+    let json = ANIME.replace(r#""status": "finished","#, "");
+    let response: Response<Anime> = serde_json::from_str(&json).unwrap();
+
+    assert_eq!(response.data.attributes.status, None);
+    assert_eq!(response.data.attributes.airing_status().name(), "finished");
+}
+
+#[test]
+fn test_cover_image_top_offset_missing() {
+    let response: Response<Anime> = serde_json::from_str(ANIME).unwrap();
+
+    assert_eq!(response.data.attributes.cover_image_top_offset, Some(400));
+
+    let json = ANIME.replace(r#""coverImageTopOffset": 400,"#, "");
+    let response: Response<Anime> = serde_json::from_str(&json).unwrap();
+
+    assert_eq!(response.data.attributes.cover_image_top_offset, None);
+
+    let json = ANIME.replace(r#""coverImageTopOffset": 400,"#, r#""coverImageTopOffset": null,"#);
+    let response: Response<Anime> = serde_json::from_str(&json).unwrap();
+
+    assert_eq!(response.data.attributes.cover_image_top_offset, None);
+}
+
+#[cfg(feature = "chrono")]
+#[test]
+fn test_anime_dates_parsed() {
+    use chrono::{NaiveDate, SecondsFormat};
+
+    let date = |year, month, day| NaiveDate::from_ymd_opt(year, month, day);
+
+    let response: Response<Anime> = serde_json::from_str(ANIME).unwrap();
+    let mut attributes = response.data.attributes;
+
+    assert_eq!(attributes.start_date_parsed(), date(1998, 4, 3));
+    assert_eq!(attributes.end_date_parsed(), date(1999, 4, 24));
+    assert_eq!(
+        attributes.created_at_parsed().unwrap().to_rfc3339_opts(SecondsFormat::Millis, true),
+        "2013-02-20T16:00:13.609Z",
+    );
+    assert_eq!(
+        attributes.updated_at_parsed().unwrap().to_rfc3339_opts(SecondsFormat::Millis, true),
+        "2017-05-22T06:00:10.247Z",
+    );
+
+    // Timestamps with an offset are converted to UTC.
+    attributes.updated_at = Some("2017-05-22T08:00:10.247+02:00".to_owned());
+
+    assert_eq!(
+        attributes.updated_at_parsed().unwrap().to_rfc3339_opts(SecondsFormat::Millis, true),
+        "2017-05-22T06:00:10.247Z",
+    );
+
+    // Empty values, as sometimes given by the API, are not dates.
+    attributes.start_date = Some(String::new());
+    attributes.created_at = Some(String::new());
+
+    assert_eq!(attributes.start_date_parsed(), None);
+    assert_eq!(attributes.created_at_parsed(), None);
+
+    // A date alone is not a full RFC 3339 timestamp, and a timestamp is not a
+    // date alone.
+    attributes.updated_at = Some("1998-04-03".to_owned());
+    attributes.end_date = Some("1999-04-24T00:00:00.000Z".to_owned());
+
+    assert_eq!(attributes.updated_at_parsed(), None);
+    assert_eq!(attributes.end_date_parsed(), None);
+
+    attributes.created_at = None;
+    attributes.start_date = None;
+
+    assert_eq!(attributes.created_at_parsed(), None);
+    assert_eq!(attributes.start_date_parsed(), None);
+}
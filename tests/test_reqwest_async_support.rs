@@ -0,0 +1,234 @@
+#![cfg(feature = "reqwest-async")]
+
+use futures_util::StreamExt;
+use kitsu_io::{Error, KitsuAsyncClient, KitsuAsyncRequester, API_URL};
+use reqwest::Client;
+use std::io::{BufRead, BufReader, Write};
+use std::net::TcpListener;
+use std::sync::mpsc::{self, Receiver};
+use std::thread;
+
+/// Serves each of the given statuses and bodies in turn on a local port, one
+/// per request, returning the base URL of the server and a receiver of the
+/// paths which were requested.
+///
+/// Any `{base_url}` in a body is replaced with the base URL of the server, so
+/// that a page can link to the next page.
+fn serve(responses: Vec<(&'static str, String)>) -> (String, Receiver<String>) {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let base_url = format!("http://{}/api/edge", listener.local_addr().unwrap());
+    let (tx, rx) = mpsc::channel();
+
+    {
+        let base_url = base_url.clone();
+
+        thread::spawn(move || {
+            for (status, body) in responses {
+                let (mut stream, _) = listener.accept().unwrap();
+                let mut reader = BufReader::new(stream.try_clone().unwrap());
+                let mut line = String::new();
+                reader.read_line(&mut line).unwrap();
+
+                // Discard the headers.
+                loop {
+                    let mut header = String::new();
+
+                    if reader.read_line(&mut header).unwrap() <= 2 {
+                        break;
+                    }
+                }
+
+                let body = body.replace("{base_url}", &base_url);
+                let _ = write!(
+                    stream,
+                    "HTTP/1.1 {}\r\nContent-Type: application/vnd.api+json\r\n\
+                     Content-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    status,
+                    body.len(),
+                    body,
+                );
+
+                let _ = tx.send(line.split_whitespace().nth(1).unwrap_or_default().to_owned());
+            }
+        });
+    }
+
+    (base_url, rx)
+}
+
+/// An anime with the given id, as a JSON resource.
+fn anime(id: u64) -> String {
+    let link = r#"{"links": {"self": "", "related": ""}}"#;
+
+    format!(r#"{{
+        "id": "{id}",
+        "type": "anime",
+        "links": {{}},
+        "attributes": {{
+            "canonicalTitle": "Anime {id}",
+            "nsfw": false,
+            "posterImage": {{"original": ""}},
+            "ratingFrequencies": {{}},
+            "showType": "TV",
+            "slug": "anime-{id}",
+            "synopsis": "",
+            "titles": {{}}
+        }},
+        "relationships": {{"episodes": {link}, "genres": {link}}}
+    }}"#, id = id, link = link)
+}
+
+#[test]
+fn test_default_base_url() {
+    assert_eq!(KitsuAsyncClient::new().base_url(), API_URL);
+    assert_eq!(KitsuAsyncClient::default().base_url(), API_URL);
+}
+
+#[tokio::test]
+async fn test_with_base_url() {
+    let (base_url, request) = serve(vec![("200 OK", format!(r#"{{"data": {}}}"#, anime(1)))]);
+    let client = KitsuAsyncClient::new().with_base_url(&format!("{}/", base_url));
+
+    let res = client.get_anime(1).await.unwrap();
+
+    assert_eq!(res.data.id, "1");
+    assert_eq!(request.recv().unwrap(), "/api/edge/anime/1");
+}
+
+#[tokio::test]
+async fn test_search_anime_stream_pages() {
+    let (base_url, request) = serve(vec![
+        ("200 OK", format!(
+            r#"{{"data": [{}, {}], "links": {{"next": "{{base_url}}/anime?page[offset]=2"}}}}"#,
+            anime(1),
+            anime(2),
+        )),
+        ("200 OK", format!(r#"{{"data": [{}]}}"#, anime(3))),
+    ]);
+    let client = KitsuAsyncClient::new().with_base_url(&base_url);
+
+    let anime = client.search_anime_stream(|f| f.limit(2))
+        .collect::<Vec<_>>()
+        .await;
+    let ids = anime.into_iter().map(|anime| anime.unwrap().id).collect::<Vec<_>>();
+
+    assert_eq!(ids, vec!["1", "2", "3"]);
+    assert_eq!(request.iter().collect::<Vec<_>>(), vec![
+        "/api/edge/anime?page[limit]=2",
+        "/api/edge/anime?page[offset]=2",
+    ]);
+}
+
+#[tokio::test]
+async fn test_search_anime_stream_page_error() {
+    let (base_url, _) = serve(vec![
+        ("200 OK", format!(
+            r#"{{"data": [{}], "links": {{"next": "{{base_url}}/anime?page[offset]=1"}}}}"#,
+            anime(1),
+        )),
+        ("500 Internal Server Error", r#"{"errors": [{"status": "500"}]}"#.to_owned()),
+    ]);
+    let client = KitsuAsyncClient::new().with_base_url(&base_url);
+
+    let anime = client.search_anime_stream(|f| f.limit(1))
+        .collect::<Vec<_>>()
+        .await;
+
+    // The first page is yielded, followed by the error of the second page,
+    // which ends the stream.
+    assert_eq!(anime.len(), 2);
+    assert_eq!(anime[0].as_ref().unwrap().id, "1");
+    assert!(anime[1].is_err());
+}
+
+#[tokio::test]
+async fn test_search_anime_stream_foreign_next() {
+    let (base_url, request) = serve(vec![
+        ("200 OK", format!(
+            r#"{{"data": [{}], "links": {{"next": "https://example.com/api/edge/anime"}}}}"#,
+            anime(1),
+        )),
+    ]);
+    let client = KitsuAsyncClient::new().with_base_url(&base_url);
+
+    let anime = client.search_anime_stream(|f| f.limit(1))
+        .collect::<Vec<_>>()
+        .await;
+
+    assert_eq!(anime.len(), 2);
+    assert_eq!(anime[0].as_ref().unwrap().id, "1");
+
+    match anime[1] {
+        Err(Error::ForeignUrl(ref url)) => assert_eq!(url, "https://example.com/api/edge/anime"),
+        ref other => panic!("Expected a foreign URL error, got {:?}", other.as_ref().map(|a| &a.id)),
+    }
+
+    assert_eq!(request.iter().count(), 1);
+}
+
+#[ignore]
+#[tokio::test]
+async fn test_get_anime() {
+    let client = Client::new();
+    let res = client.get_anime(1).await.unwrap();
+
+    assert_eq!(res.data.id, "1");
+}
+
+#[ignore]
+#[tokio::test]
+async fn test_get_manga() {
+    let client = Client::new();
+    let res = client.get_manga(1).await.unwrap();
+
+    assert_eq!(res.data.id, "1");
+}
+
+#[ignore]
+#[tokio::test]
+async fn test_get_user() {
+    let client = Client::new();
+    let res = client.get_user(1).await.unwrap();
+
+    assert_eq!(res.data.id, "1");
+}
+
+#[ignore]
+#[tokio::test]
+async fn test_search_anime() {
+    let client = Client::new();
+    let res = client.search_anime(|f| f.filter("text", "non non biyori")).await.unwrap();
+
+    assert!(!res.data.is_empty());
+}
+
+#[ignore]
+#[tokio::test]
+async fn test_search_anime_stream() {
+    let client = Client::new();
+    let anime = client.search_anime_stream(|f| f.filter("text", "non non biyori").limit(1))
+        .take(2)
+        .collect::<Vec<_>>()
+        .await;
+
+    assert_eq!(anime.len(), 2);
+    assert!(anime.iter().all(|anime| anime.is_ok()));
+}
+
+#[ignore]
+#[tokio::test]
+async fn test_search_manga() {
+    let client = Client::new();
+    let res = client.search_manga(|f| f.filter("text", "orange")).await.unwrap();
+
+    assert!(!res.data.is_empty());
+}
+
+#[ignore]
+#[tokio::test]
+async fn test_search_users() {
+    let client = Client::new();
+    let res = client.search_users(|f| f.filter("name", "vikhyat")).await.unwrap();
+
+    assert!(!res.data.is_empty());
+}
@@ -1,15 +1,36 @@
 #![cfg(feature = "reqwest-support")]
 
 extern crate kitsu_io;
-extern crate reqwest;
+extern crate serde_json;
 
-use kitsu_io::KitsuReqwestRequester;
-use reqwest::Client;
+use kitsu_io::model::Genre;
+use kitsu_io::{Error, KitsuClient};
+use serde_json::Value;
+use std::env;
+
+/// Creates a client which makes requests to the base URL in the
+/// `KITSU_BASE_URL` environment variable if it is set, such as to run the
+/// tests against a mock server, or to the Kitsu API otherwise.
+fn client() -> KitsuClient {
+    match env::var("KITSU_BASE_URL") {
+        Ok(base_url) => KitsuClient::new().with_base_url(&base_url),
+        Err(_) => KitsuClient::new(),
+    }
+}
+
+#[ignore]
+#[test]
+fn test_anime_exists() {
+    let client = client();
+
+    assert!(client.anime_exists(1).unwrap());
+    assert!(!client.anime_exists(u64::MAX).unwrap());
+}
 
 #[ignore]
 #[test]
 fn test_get_anime() {
-    let client = Client::new();
+    let client = client();
     let res = client.get_anime(1).unwrap();
 
     assert_eq!(res.data.id, "1");
@@ -18,7 +39,7 @@ fn test_get_anime() {
 #[ignore]
 #[test]
 fn test_get_manga() {
-    let client = Client::new();
+    let client = client();
     let res = client.get_manga(1).unwrap();
 
     assert_eq!(res.data.id, "1");
@@ -27,35 +48,338 @@ fn test_get_manga() {
 #[ignore]
 #[test]
 fn test_get_user() {
-    let client = Client::new();
+    let client = client();
     let res = client.get_user(1).unwrap();
 
     assert_eq!(res.data.id, "1");
 }
 
+#[ignore]
+#[test]
+fn test_random_anime() {
+    let client = client();
+
+    assert!(client.random_anime().unwrap().is_some());
+}
+
+#[ignore]
+#[test]
+fn test_recently_updated_anime() {
+    let client = client();
+    let res = client.recently_updated_anime(5).unwrap();
+
+    assert_eq!(res.data.len(), 5);
+}
+
 #[ignore]
 #[test]
 fn test_search_anime() {
-    let client = Client::new();
+    let client = client();
     let res = client.search_anime(|f| f.filter("text", "non non biyori")).unwrap();
 
-    assert!(res.data.len() > 0);
+    assert!(!res.data.is_empty());
 }
 
 #[ignore]
 #[test]
 fn test_search_manga() {
-    let client = Client::new();
+    let client = client();
     let res = client.search_manga(|f| f.filter("text", "orange")).unwrap();
 
-    assert!(res.data.len() > 0);
+    assert!(!res.data.is_empty());
 }
 
 #[ignore]
 #[test]
 fn test_search_users() {
-    let client = Client::new();
+    let client = client();
     let res = client.search_users(|f| f.filter("name", "vikhyat")).unwrap();
 
-    assert!(res.data.len() > 0);
+    assert!(!res.data.is_empty());
+}
+
+#[ignore]
+#[test]
+fn test_get_anime_genre_names() {
+    let client = client();
+    let res = client.get_anime_genre_names(1).unwrap();
+
+    assert!(!res.is_empty());
+}
+
+#[ignore]
+#[test]
+fn test_search_anime_fuzzy() {
+    let client = client();
+    let res = client.search_anime_fuzzy("shingeki no kyojin").unwrap();
+
+    assert!(!res.is_empty());
+}
+
+#[ignore]
+#[test]
+fn test_get_anime_episodes() {
+    let client = client();
+    let res = client.get_anime_episodes(1).unwrap();
+
+    assert!(!res.data.is_empty());
+}
+
+#[ignore]
+#[test]
+fn test_get_manga_chapters() {
+    let client = client();
+    let res = client.get_manga_chapters(1).unwrap();
+
+    assert!(!res.data.is_empty());
+}
+
+#[ignore]
+#[test]
+fn test_search_anime_all() {
+    let client = client();
+    let res = client.search_anime_all(|f| f.filter("text", "orange").limit(2))
+        .take(5)
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap();
+
+    assert_eq!(res.len(), 5);
+}
+
+#[ignore]
+#[test]
+fn test_get_anime_streaming_links() {
+    let client = client();
+    let res = client.get_anime_streaming_links(1).unwrap();
+
+    assert!(!res.data.is_empty());
+}
+
+#[ignore]
+#[test]
+fn test_get_anime_categories() {
+    let client = client();
+    let res = client.get_anime_categories(1).unwrap();
+
+    assert!(!res.data.is_empty());
+}
+
+#[ignore]
+#[test]
+fn test_get_categories() {
+    let client = client();
+    let res = client.get_categories(|f| f.filter("slug", "space")).unwrap();
+
+    assert!(!res.data.is_empty());
+}
+
+#[ignore]
+#[test]
+fn test_fetch() {
+    let client = client();
+    let anime = client.get_anime(1).unwrap();
+    let url = &anime.data.relationships.genres.links.related;
+    let res = client.fetch::<Vec<Genre>>(url).unwrap();
+
+    assert!(!res.data.is_empty());
+}
+
+#[test]
+fn test_fetch_foreign_url() {
+    let client = client();
+
+    for url in &[
+        "https://example.com/api/edge/anime/1",
+        "http://kitsu.io/api/edge/anime/1",
+        "https://kitsu.io/api/edge.example.com/anime/1",
+        "https://kitsu.io/anime/1",
+    ] {
+        match client.fetch::<Value>(url) {
+            Err(Error::ForeignUrl(ref given)) => assert_eq!(given, url),
+            other => panic!("Expected a foreign URL error, got {:?}", other),
+        }
+    }
+}
+
+#[ignore]
+#[test]
+fn test_get_trending_anime() {
+    let client = client();
+    let res = client.get_trending_anime().unwrap();
+
+    assert!(!res.data.is_empty());
+}
+
+#[ignore]
+#[test]
+fn test_get_trending_manga() {
+    let client = client();
+    let res = client.get_trending_manga().unwrap();
+
+    assert!(!res.data.is_empty());
+}
+
+#[ignore]
+#[test]
+fn test_get_anime_reviews() {
+    let client = client();
+    let res = client.get_anime_reviews(1).unwrap();
+
+    assert!(!res.data.is_empty());
+}
+
+#[ignore]
+#[test]
+fn test_get_user_library_entries() {
+    let client = client();
+    let res = client.get_user_library_entries(1, |f| f.filter("kind", "anime")).unwrap();
+
+    assert!(!res.data.is_empty());
+}
+
+#[ignore]
+#[test]
+fn test_get_anime_castings() {
+    let client = client();
+    let res = client.get_anime_castings(1).unwrap();
+
+    assert!(!res.data.is_empty());
+}
+
+#[ignore]
+#[test]
+fn test_get_anime_by_slug() {
+    let client = client();
+
+    assert!(client.get_anime_by_slug("cowboy-bebop").unwrap().is_some());
+    assert!(client.get_anime_by_slug("not-an-anime-slug").unwrap().is_none());
+}
+
+#[ignore]
+#[test]
+fn test_get_manga_by_slug() {
+    let client = client();
+
+    assert!(client.get_manga_by_slug("berserk").unwrap().is_some());
+}
+
+#[ignore]
+#[test]
+fn test_get_person() {
+    let client = client();
+    let res = client.get_person(1).unwrap();
+
+    assert!(!res.data.attributes.name.is_empty());
+}
+
+#[ignore]
+#[test]
+fn test_search_people() {
+    let client = client();
+    let res = client.search_people(|f| f.filter("name", "Kouichi Yamadera")).unwrap();
+
+    assert!(!res.data.is_empty());
+}
+
+#[ignore]
+#[test]
+fn test_get_anime_mappings() {
+    let client = client();
+    let res = client.get_anime_mappings(1).unwrap();
+
+    assert!(!res.data.is_empty());
+}
+
+#[ignore]
+#[test]
+fn test_get_anime_media_relationships() {
+    let client = client();
+    let res = client.get_anime_media_relationships(1).unwrap();
+
+    assert!(!res.data.is_empty());
+}
+
+#[ignore]
+#[test]
+fn test_get_anime_installments() {
+    let client = client();
+    let res = client.get_anime_installments(1).unwrap();
+
+    assert!(!res.data.is_empty());
+}
+
+#[ignore]
+#[test]
+fn test_get_anime_productions() {
+    let client = client();
+    let res = client.get_anime_productions(1).unwrap();
+
+    assert!(!res.data.is_empty());
+}
+
+#[ignore]
+#[test]
+fn test_get_anime_reactions() {
+    let client = client();
+    let res = client.get_anime_reactions(1).unwrap();
+
+    assert!(!res.data.is_empty());
+}
+
+#[ignore]
+#[test]
+fn test_get_group() {
+    let client = client();
+    let res = client.get_group(1).unwrap();
+
+    assert_eq!(res.data.id, "1");
+}
+
+#[ignore]
+#[test]
+fn test_search_groups() {
+    let client = client();
+    let res = client.search_groups(|f| f.limit(1)).unwrap();
+
+    assert!(!res.data.is_empty());
+}
+
+#[ignore]
+#[test]
+fn test_get_user_waifu() {
+    let client = client();
+
+    assert!(client.get_user_waifu(1).is_ok());
+}
+
+#[ignore]
+#[test]
+fn test_get_user_library_events() {
+    let client = client();
+
+    assert!(client.get_user_library_events(1, |f| f.limit(1)).is_ok());
+}
+
+#[ignore]
+#[test]
+fn test_get_user_followers() {
+    let client = client();
+
+    assert!(client.get_user_followers(1, |f| f.limit(1)).is_ok());
+}
+
+#[ignore]
+#[test]
+fn test_get_user_following() {
+    let client = client();
+
+    assert!(client.get_user_following(1, |f| f.limit(1)).is_ok());
+}
+
+#[ignore]
+#[test]
+fn test_get_user_posts() {
+    let client = client();
+
+    assert!(client.get_user_posts(1, |f| f.limit(1)).is_ok());
 }